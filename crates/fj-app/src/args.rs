@@ -1,6 +1,7 @@
 use std::{path::PathBuf, str::FromStr as _};
 
 use anyhow::anyhow;
+use fj_export::Unit;
 use fj_host::Parameters;
 use fj_kernel::algorithms::approx::Tolerance;
 use fj_math::Scalar;
@@ -16,6 +17,51 @@ pub struct Args {
     #[arg(short, long, value_name = "PATH")]
     pub export: Option<PathBuf>,
 
+    /// Export to the ASCII variant of the format (STL & PLY only)
+    #[arg(long, requires = "export")]
+    pub ascii: bool,
+
+    /// Factor by which the coordinates of the exported model are multiplied
+    #[arg(long, requires = "export")]
+    pub scale: Option<f64>,
+
+    /// Unit of the exported model, for formats that declare one
+    ///
+    /// One of `micron`, `millimeter`, `centimeter`, `meter`, `inch`, or
+    /// `foot`.
+    #[arg(long, requires = "export", value_parser = parse_unit)]
+    pub unit: Option<Unit>,
+
+    /// Export with the y-axis pointing up, instead of the z-axis
+    #[arg(long, requires = "export")]
+    pub y_up: bool,
+
+    /// Number of digits after the decimal point in exported text formats
+    #[arg(long, requires = "export")]
+    pub precision: Option<usize>,
+
+    /// Check the mesh before exporting, and fail if it has any problems
+    ///
+    /// Problems are holes, non-manifold edges, inconsistent triangle winding,
+    /// zero-area triangles, and duplicate triangles.
+    #[arg(long, requires = "export")]
+    pub check_mesh: bool,
+
+    /// Decimate the exported mesh down to this number of triangles
+    #[arg(long, requires = "export", value_name = "COUNT")]
+    pub max_triangles: Option<usize>,
+
+    /// Decimate the exported mesh, deviating from it by at most this much
+    ///
+    /// The deviation is in millimeters. Face boundaries are preserved.
+    #[arg(long, requires = "export", value_name = "DEVIATION")]
+    pub max_error: Option<f64>,
+
+    /// Write debug geometry from the kernel to this path, whenever the model
+    /// is processed
+    #[arg(long, value_name = "PATH")]
+    pub dump_debug: Option<PathBuf>,
+
     /// Parameters for the model, each in the form `key=value`
     #[arg(short, long, value_parser = parse_parameters)]
     pub parameters: Option<Parameters>,
@@ -58,6 +104,20 @@ fn parse_parameters(input: &str) -> anyhow::Result<Parameters> {
     Ok(parameters)
 }
 
+fn parse_unit(input: &str) -> anyhow::Result<Unit> {
+    let unit = match input {
+        "micron" => Unit::Micron,
+        "millimeter" => Unit::Millimeter,
+        "centimeter" => Unit::Centimeter,
+        "meter" => Unit::Meter,
+        "inch" => Unit::Inch,
+        "foot" => Unit::Foot,
+        _ => return Err(anyhow!("Unknown unit: {input}")),
+    };
+
+    Ok(unit)
+}
+
 fn parse_tolerance(input: &str) -> anyhow::Result<Tolerance> {
     let tolerance = f64::from_str(input)?;
     let tolerance = Scalar::from_f64(tolerance);
@@ -19,8 +19,10 @@ mod path;
 use std::{env, error::Error};
 
 use anyhow::{anyhow, Context};
-use fj_export::export;
+use fj_export::{export_to_path, Encoding, ExportOptions, UpAxis};
 use fj_host::Parameters;
+use fj_interop::{analysis::MeshAnalysis, decimation::Decimation};
+use fj_math::Scalar;
 use fj_operations::shape_processor::ShapeProcessor;
 use fj_window::run::run;
 use path::ModelPath;
@@ -42,6 +44,7 @@ fn main() -> anyhow::Result<()> {
     let parameters = args.parameters.unwrap_or_else(Parameters::empty);
     let shape_processor = ShapeProcessor {
         tolerance: args.tolerance,
+        debug_dump: args.dump_debug,
     };
 
     let model = model_path.map(|m| m.load_model(parameters)).transpose()?;
@@ -50,9 +53,35 @@ fn main() -> anyhow::Result<()> {
         // export only mode. just load model, process, export and exit
 
         let evaluation = model.with_context(no_model_error)?.evaluate()?;
-        let shape = shape_processor.process(&evaluation.shape)?;
+        let (shape, faces) =
+            shape_processor.process_with_brep(&evaluation.shape)?;
 
-        export(&shape.mesh, &export_path)?;
+        if args.check_mesh {
+            let analysis = MeshAnalysis::of(&shape.mesh);
+            if !analysis.is_ok() {
+                return Err(anyhow!("Not exporting model. {analysis}"));
+            }
+        }
+
+        let options = ExportOptions {
+            encoding: if args.ascii {
+                Encoding::Ascii
+            } else {
+                Encoding::Binary
+            },
+            scale: args.scale.unwrap_or(1.),
+            unit: args.unit.unwrap_or_default(),
+            up_axis: if args.y_up { UpAxis::Y } else { UpAxis::Z },
+            precision: args.precision,
+            metadata: Some(evaluation.metadata),
+            decimation: (args.max_triangles.is_some()
+                || args.max_error.is_some())
+            .then_some(Decimation {
+                target_triangles: args.max_triangles,
+                max_error: args.max_error.map(Scalar::from_f64),
+            }),
+        };
+        export_to_path(&shape.mesh, &faces, &export_path, &options)?;
 
         return Ok(());
     }
@@ -0,0 +1,63 @@
+//! Splitting meshes into the shapes they are made of
+
+use std::collections::HashMap;
+
+use fj_interop::mesh::{Index, Mesh, Triangle};
+use fj_math::Point;
+
+/// A triangle of a mesh, with the indices of its vertices
+#[derive(Clone, Copy)]
+pub(crate) struct IndexedTriangle {
+    pub triangle: Triangle,
+    pub indices: [Index; 3],
+}
+
+/// Split a mesh into its connected components
+///
+/// The shapes in a group are disjoint, so each of them becomes a separate
+/// component of the mesh. Components are ordered by their first triangle.
+pub(crate) fn components(mesh: &Mesh<Point<3>>) -> Vec<Vec<IndexedTriangle>> {
+    let indices = mesh.indices().collect::<Vec<_>>();
+    let triangles = mesh
+        .triangles()
+        .zip(indices.chunks(3))
+        .map(|(triangle, indices)| IndexedTriangle {
+            triangle,
+            indices: [indices[0], indices[1], indices[2]],
+        })
+        .collect::<Vec<_>>();
+
+    // Each vertex starts out as its own component. Vertices of the same
+    // triangle have their components merged.
+    let mut component_of_vertex =
+        (0..mesh.vertices().count()).collect::<Vec<_>>();
+    let root = |component_of_vertex: &[usize], mut i: usize| {
+        while component_of_vertex[i] != i {
+            i = component_of_vertex[i];
+        }
+        i
+    };
+
+    for triangle in &triangles {
+        let [a, b, c] = triangle
+            .indices
+            .map(|i| root(&component_of_vertex, i as usize));
+        component_of_vertex[a] = c;
+        component_of_vertex[b] = c;
+    }
+
+    let mut components_by_root = HashMap::new();
+    let mut components: Vec<Vec<IndexedTriangle>> = Vec::new();
+
+    for triangle in triangles {
+        let root = root(&component_of_vertex, triangle.indices[0] as usize);
+
+        let i = *components_by_root.entry(root).or_insert_with(|| {
+            components.push(Vec::new());
+            components.len() - 1
+        });
+        components[i].push(triangle);
+    }
+
+    components
+}
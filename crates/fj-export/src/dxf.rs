@@ -0,0 +1,123 @@
+//! Export of 2D shapes to DXF
+
+use std::{
+    fmt,
+    io::{self, Write},
+};
+
+use fj_kernel::objects::FaceSet;
+use fj_math::Scalar;
+
+use crate::{
+    sketch::{faces_2d, Edge2d},
+    Error, ExportOptions, Unit,
+};
+
+/// Write the edges of a 2D shape as DXF
+///
+/// Lines and arcs are exported exactly, as `LINE`, `ARC`, and `CIRCLE`
+/// entities. Their unit is declared in the header.
+pub(crate) fn write_dxf(
+    faces: &FaceSet,
+    writer: &mut dyn Write,
+    options: &ExportOptions,
+) -> Result<(), Error> {
+    let faces = faces_2d(faces, options)?;
+
+    let mut dxf = DxfWriter { writer, options };
+
+    dxf.group(0, "SECTION")?;
+    dxf.group(2, "HEADER")?;
+    dxf.group(9, "$ACADVER")?;
+    dxf.group(1, "AC1009")?;
+    dxf.group(9, "$INSUNITS")?;
+    dxf.group(
+        70,
+        match options.unit {
+            Unit::Micron => 13,
+            Unit::Millimeter => 4,
+            Unit::Centimeter => 5,
+            Unit::Meter => 6,
+            Unit::Inch => 1,
+            Unit::Foot => 2,
+        },
+    )?;
+    dxf.group(0, "ENDSEC")?;
+
+    dxf.group(0, "SECTION")?;
+    dxf.group(2, "ENTITIES")?;
+
+    for edge in faces.iter().flat_map(|face| face.cycles.iter().flatten()) {
+        match edge {
+            Edge2d::Line { start, end } => {
+                dxf.entity("LINE")?;
+                dxf.point([10, 20, 30], start.u, start.v)?;
+                dxf.point([11, 21, 31], end.u, end.v)?;
+            }
+            Edge2d::Arc {
+                start,
+                end,
+                center,
+                radius,
+                angles,
+            } => {
+                if start == end {
+                    dxf.entity("CIRCLE")?;
+                    dxf.point([10, 20, 30], center.u, center.v)?;
+                    dxf.number(40, *radius)?;
+                    continue;
+                }
+
+                // DXF arcs are always counter-clockwise.
+                let [a, b] = *angles;
+                let [start_angle, end_angle] =
+                    if a < b { [a, b] } else { [b, a] }.map(|angle| {
+                        angle.into_f64().to_degrees().rem_euclid(360.)
+                    });
+
+                dxf.entity("ARC")?;
+                dxf.point([10, 20, 30], center.u, center.v)?;
+                dxf.number(40, *radius)?;
+                dxf.number(50, start_angle)?;
+                dxf.number(51, end_angle)?;
+            }
+        }
+    }
+
+    dxf.group(0, "ENDSEC")?;
+    dxf.group(0, "EOF")?;
+
+    Ok(())
+}
+
+struct DxfWriter<'r> {
+    writer: &'r mut dyn Write,
+    options: &'r ExportOptions,
+}
+
+impl DxfWriter<'_> {
+    fn group(&mut self, code: u16, value: impl fmt::Display) -> io::Result<()> {
+        writeln!(self.writer, "{code}\n{value}")
+    }
+
+    fn number(&mut self, code: u16, value: impl Into<f64>) -> io::Result<()> {
+        let value = self.options.number(value);
+        self.group(code, value)
+    }
+
+    fn entity(&mut self, kind: &str) -> io::Result<()> {
+        self.group(0, kind)?;
+        self.group(8, "0")
+    }
+
+    fn point(
+        &mut self,
+        [x, y, z]: [u16; 3],
+        u: Scalar,
+        v: Scalar,
+    ) -> io::Result<()> {
+        self.number(x, u)?;
+        self.number(y, v)?;
+        self.number(z, 0.)
+    }
+}
@@ -0,0 +1,500 @@
+//! Export of meshes to glTF 2.0
+
+use std::{
+    collections::HashMap, f64::consts::FRAC_1_SQRT_2, fs::File, io::Write,
+    path::Path,
+};
+
+use fj_interop::mesh::{Color, Mesh};
+use fj_math::Point;
+use serde_json::{json, Value};
+
+use crate::{components::components, Error};
+
+/// Write a mesh as glTF
+///
+/// If `binary` is set, a single GLB file is written. Otherwise, the buffer
+/// that contains the geometry is written to the file at `bin_path`, or
+/// embedded into the glTF file as a data URI, if no path is given.
+///
+/// Each shape of a group becomes a separate node, with a primitive for each of
+/// its colors. glTF uses meters and a y-axis that points up, so those nodes
+/// are children of a node that converts from millimeters and a z-axis that
+/// points up.
+pub(crate) fn write_gltf(
+    mesh: &Mesh<Point<3>>,
+    file: &mut dyn Write,
+    name: &str,
+    binary: bool,
+    bin_path: Option<&Path>,
+) -> Result<(), Error> {
+    let mut buffer = Buffer::default();
+    let mut materials = Materials::default();
+    let mut meshes = Vec::new();
+    let mut nodes = Vec::new();
+
+    for (i, component) in components(mesh).into_iter().enumerate() {
+        let mut colors = Vec::new();
+        let mut triangles_by_color = HashMap::new();
+        for triangle in component {
+            triangles_by_color
+                .entry(triangle.triangle.color)
+                .or_insert_with(|| {
+                    colors.push(triangle.triangle.color);
+                    Vec::new()
+                })
+                .push(triangle.triangle);
+        }
+
+        let primitives = colors
+            .into_iter()
+            .map(|color| {
+                let triangles = &triangles_by_color[&color];
+
+                // Vertices are shared between triangles, as long as they have
+                // the same normal, so creases between faces stay sharp.
+                let mut vertices = Vec::new();
+                let mut indices = Vec::new();
+                let mut indices_by_vertex = HashMap::new();
+
+                for triangle in triangles {
+                    for (point, normal) in triangle
+                        .inner
+                        .points()
+                        .into_iter()
+                        .zip(triangle.normals)
+                    {
+                        let position =
+                            point.coords.components.map(|s| s.into_f32());
+                        let normal = normal.components.map(|s| s.into_f32());
+                        let key =
+                            [position, normal].map(|v| v.map(f32::to_bits));
+
+                        let index = *indices_by_vertex
+                            .entry(key)
+                            .or_insert_with(|| {
+                                vertices.push((position, normal));
+                                vertices.len() as u32 - 1
+                            });
+                        indices.push(index);
+                    }
+                }
+
+                let positions =
+                    vertices.iter().map(|(position, _)| *position).collect();
+                let normals =
+                    vertices.iter().map(|(_, normal)| *normal).collect();
+
+                json!({
+                    "attributes": {
+                        "POSITION": buffer.add_vec3(positions, true),
+                        "NORMAL": buffer.add_vec3(normals, false),
+                    },
+                    "indices": buffer.add_indices(indices),
+                    "material": materials.get(color),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        meshes.push(json!({ "primitives": primitives }));
+        nodes.push(json!({
+            "name": format!("{name} {}", i + 1),
+            "mesh": i,
+        }));
+    }
+
+    // The root node comes first, and the other nodes are its children.
+    let children = (1..=nodes.len()).collect::<Vec<_>>();
+    nodes.insert(
+        0,
+        json!({
+            "name": name,
+            "rotation": [-FRAC_1_SQRT_2, 0., 0., FRAC_1_SQRT_2],
+            "scale": [0.001, 0.001, 0.001],
+            "children": children,
+        }),
+    );
+
+    let mut gltf = json!({
+        "asset": {
+            "version": "2.0",
+            "generator": "Fornjot",
+        },
+        "scene": 0,
+        "scenes": [{ "nodes": [0] }],
+        "nodes": nodes,
+        "meshes": meshes,
+        "materials": materials.materials,
+        "accessors": buffer.accessors,
+        "bufferViews": buffer.views,
+    });
+
+    if !buffer.data.is_empty() {
+        let mut descriptor = json!({ "byteLength": buffer.data.len() });
+
+        if !binary {
+            descriptor["uri"] = match bin_path {
+                Some(bin_path) => {
+                    File::create(bin_path)?.write_all(&buffer.data)?;
+                    bin_path
+                        .file_name()
+                        .map(|bin_name| bin_name.to_string_lossy())
+                        .unwrap_or_default()
+                        .into()
+                }
+                None => format!(
+                    "data:application/octet-stream;base64,{}",
+                    base64(&buffer.data)
+                )
+                .into(),
+            };
+        }
+
+        gltf["buffers"] = json!([descriptor]);
+    }
+
+    if binary {
+        let mut json = gltf.to_string().into_bytes();
+        pad(&mut json, b' ');
+        let mut bin = buffer.data;
+        pad(&mut bin, 0);
+
+        let mut length = 12 + 8 + json.len();
+        if !bin.is_empty() {
+            length += 8 + bin.len();
+        }
+
+        file.write_all(b"glTF")?;
+        file.write_all(&2u32.to_le_bytes())?;
+        file.write_all(&chunk_length(length)?)?;
+
+        file.write_all(&chunk_length(json.len())?)?;
+        file.write_all(b"JSON")?;
+        file.write_all(&json)?;
+
+        if !bin.is_empty() {
+            file.write_all(&chunk_length(bin.len())?)?;
+            file.write_all(b"BIN\0")?;
+            file.write_all(&bin)?;
+        }
+    } else {
+        file.write_all(gltf.to_string().as_bytes())?;
+    }
+
+    Ok(())
+}
+
+/// The binary data of the geometry, and the accessors that describe it
+#[derive(Default)]
+struct Buffer {
+    data: Vec<u8>,
+    views: Vec<Value>,
+    accessors: Vec<Value>,
+}
+
+impl Buffer {
+    const ARRAY_BUFFER: u32 = 34962;
+    const ELEMENT_ARRAY_BUFFER: u32 = 34963;
+
+    const FLOAT: u32 = 5126;
+    const UNSIGNED_INT: u32 = 5125;
+
+    fn add_vec3(&mut self, vectors: Vec<[f32; 3]>, bounds: bool) -> usize {
+        let view = self.add_view(
+            vectors.iter().flatten().flat_map(|c| c.to_le_bytes()),
+            Self::ARRAY_BUFFER,
+        );
+
+        let mut accessor = json!({
+            "bufferView": view,
+            "componentType": Self::FLOAT,
+            "count": vectors.len(),
+            "type": "VEC3",
+        });
+
+        // Bounds are required for positions.
+        if bounds {
+            let [mut min, mut max] =
+                [[f32::INFINITY; 3], [f32::NEG_INFINITY; 3]];
+            for vector in &vectors {
+                for (i, &c) in vector.iter().enumerate() {
+                    min[i] = min[i].min(c);
+                    max[i] = max[i].max(c);
+                }
+            }
+
+            accessor["min"] = json!(min);
+            accessor["max"] = json!(max);
+        }
+
+        self.accessors.push(accessor);
+        self.accessors.len() - 1
+    }
+
+    fn add_indices(&mut self, indices: Vec<u32>) -> usize {
+        let view = self.add_view(
+            indices.iter().flat_map(|i| i.to_le_bytes()),
+            Self::ELEMENT_ARRAY_BUFFER,
+        );
+
+        self.accessors.push(json!({
+            "bufferView": view,
+            "componentType": Self::UNSIGNED_INT,
+            "count": indices.len(),
+            "type": "SCALAR",
+        }));
+        self.accessors.len() - 1
+    }
+
+    fn add_view(
+        &mut self,
+        bytes: impl IntoIterator<Item = u8>,
+        target: u32,
+    ) -> usize {
+        let offset = self.data.len();
+        self.data.extend(bytes);
+
+        self.views.push(json!({
+            "buffer": 0,
+            "byteOffset": offset,
+            "byteLength": self.data.len() - offset,
+            "target": target,
+        }));
+        self.views.len() - 1
+    }
+}
+
+/// The materials of the mesh, one for each color
+#[derive(Default)]
+struct Materials {
+    materials: Vec<Value>,
+    by_color: HashMap<Color, usize>,
+}
+
+impl Materials {
+    fn get(&mut self, color: Color) -> usize {
+        *self.by_color.entry(color).or_insert_with(|| {
+            let Color([r, g, b, a]) = color;
+            let name = format!("color_{r:02x}{g:02x}{b:02x}{a:02x}");
+
+            // glTF expects colors in linear space.
+            let [r, g, b] = [r, g, b].map(|c| {
+                let c = f64::from(c) / 255.;
+                if c <= 0.04045 {
+                    c / 12.92
+                } else {
+                    ((c + 0.055) / 1.055).powf(2.4)
+                }
+            });
+            let a = f64::from(a) / 255.;
+
+            self.materials.push(json!({
+                "name": name,
+                "pbrMetallicRoughness": {
+                    "baseColorFactor": [r, g, b, a],
+                    "metallicFactor": 0.,
+                    "roughnessFactor": 1.,
+                },
+                "alphaMode": if a < 1. { "BLEND" } else { "OPAQUE" },
+            }));
+            self.materials.len() - 1
+        })
+    }
+}
+
+/// Pad data to a multiple of 4 bytes, as required by GLB chunks
+fn pad(data: &mut Vec<u8>, padding: u8) {
+    while data.len() % 4 != 0 {
+        data.push(padding);
+    }
+}
+
+/// Encode data as Base64, as used in data URIs
+fn base64(data: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] =
+        b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut encoded = String::with_capacity((data.len() + 2) / 3 * 4);
+    for chunk in data.chunks(3) {
+        let bytes = [0, 1, 2].map(|i| chunk.get(i).copied().unwrap_or(0));
+        let bits = u32::from_be_bytes([0, bytes[0], bytes[1], bytes[2]]);
+
+        // Each group of 3 bytes becomes 4 characters. Missing bytes at the
+        // end are padded with `=`.
+        for i in 0..4 {
+            if i <= chunk.len() {
+                let index = (bits >> (18 - 6 * i)) & 0x3f;
+                encoded.push(ALPHABET[index as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+
+    encoded
+}
+
+fn chunk_length(length: usize) -> Result<[u8; 4], Error> {
+    let length: u32 =
+        length.try_into().map_err(|_| Error::InvalidTriangleCount)?;
+    Ok(length.to_le_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::mesh::Mesh;
+    use fj_kernel::objects::FaceSet;
+    use fj_math::Point;
+    use serde_json::Value;
+
+    use crate::{export_to_writer, tests::cubes, ExportOptions, Format};
+
+    use super::base64;
+
+    #[test]
+    fn glb_round_trip() {
+        let (mesh, faces) = cubes();
+        let (json, bin) = export_glb(&mesh, &faces);
+
+        // The root node, and a node for each cube
+        assert_eq!(json["nodes"].as_array().unwrap().len(), 3);
+        assert_eq!(json["meshes"].as_array().unwrap().len(), 2);
+        assert_eq!(json["materials"].as_array().unwrap().len(), 2);
+
+        let mut expected = mesh
+            .triangles()
+            .map(|triangle| {
+                triangle.inner.points().map(|point| {
+                    point.coords.components.map(|s| s.into_f32().to_bits())
+                })
+            })
+            .collect::<Vec<_>>();
+        let mut triangles = triangles(&json, &bin);
+        expected.sort();
+        triangles.sort();
+        assert_eq!(triangles, expected);
+    }
+
+    #[test]
+    fn gltf_embeds_glb_buffer() {
+        let (mesh, faces) = cubes();
+        let (glb_json, bin) = export_glb(&mesh, &faces);
+
+        let mut gltf = Vec::new();
+        export_to_writer(
+            &mesh,
+            &faces,
+            &mut gltf,
+            Format::Gltf,
+            &ExportOptions::default(),
+        )
+        .unwrap();
+        let mut gltf_json: Value = serde_json::from_slice(&gltf).unwrap();
+
+        // Without a path to write it to, the buffer is embedded as a data
+        // URI. Apart from that, both files are the same.
+        let length = gltf_json["buffers"][0]["byteLength"].as_u64().unwrap();
+        let uri = gltf_json["buffers"][0]["uri"].take();
+        assert_eq!(
+            uri.as_str().unwrap(),
+            format!(
+                "data:application/octet-stream;base64,{}",
+                base64(&bin[..length as usize])
+            )
+        );
+
+        gltf_json["buffers"][0]
+            .as_object_mut()
+            .unwrap()
+            .remove("uri");
+        assert_eq!(gltf_json, glb_json);
+    }
+
+    #[test]
+    fn base64_encoding() {
+        // The test vectors of RFC 4648
+        let vectors = [
+            ("", ""),
+            ("f", "Zg=="),
+            ("fo", "Zm8="),
+            ("foo", "Zm9v"),
+            ("foob", "Zm9vYg=="),
+            ("fooba", "Zm9vYmE="),
+            ("foobar", "Zm9vYmFy"),
+        ];
+
+        for (data, encoded) in vectors {
+            assert_eq!(base64(data.as_bytes()), encoded);
+        }
+    }
+
+    /// Export as GLB, and return the JSON and binary chunks
+    fn export_glb(mesh: &Mesh<Point<3>>, faces: &FaceSet) -> (Value, Vec<u8>) {
+        let mut glb = Vec::new();
+        export_to_writer(
+            mesh,
+            faces,
+            &mut glb,
+            Format::Glb,
+            &ExportOptions::default(),
+        )
+        .unwrap();
+
+        let u32_at = |offset: usize| {
+            u32::from_le_bytes(glb[offset..offset + 4].try_into().unwrap())
+                as usize
+        };
+
+        assert_eq!(&glb[0..4], b"glTF");
+        assert_eq!(u32_at(4), 2);
+        assert_eq!(u32_at(8), glb.len());
+
+        let json_length = u32_at(12);
+        assert_eq!(&glb[16..20], b"JSON");
+        let json = serde_json::from_slice(&glb[20..20 + json_length]).unwrap();
+
+        let bin_start = 20 + json_length;
+        let bin_length = u32_at(bin_start);
+        assert_eq!(&glb[bin_start + 4..bin_start + 8], b"BIN\0");
+        assert_eq!(bin_start + 8 + bin_length, glb.len());
+        let bin = glb[bin_start + 8..].to_vec();
+
+        (json, bin)
+    }
+
+    /// Read the triangles of all meshes from the buffer
+    ///
+    /// The coordinates are returned as bits, so the triangles can be sorted.
+    fn triangles(json: &Value, bin: &[u8]) -> Vec<[[u32; 3]; 3]> {
+        let words = |accessor: &Value| {
+            let accessor =
+                &json["accessors"][accessor.as_u64().unwrap() as usize];
+            let view = &json["bufferViews"]
+                [accessor["bufferView"].as_u64().unwrap() as usize];
+            let offset = view["byteOffset"].as_u64().unwrap() as usize;
+            let length = view["byteLength"].as_u64().unwrap() as usize;
+
+            bin[offset..offset + length]
+                .chunks(4)
+                .map(|word| u32::from_le_bytes(word.try_into().unwrap()))
+                .collect::<Vec<_>>()
+        };
+
+        let mut triangles = Vec::new();
+        for mesh in json["meshes"].as_array().unwrap() {
+            for primitive in mesh["primitives"].as_array().unwrap() {
+                let positions = words(&primitive["attributes"]["POSITION"]);
+                let indices = words(&primitive["indices"]);
+
+                for triangle in indices.chunks(3) {
+                    triangles.push([0, 1, 2].map(|i| {
+                        let index = triangle[i] as usize * 3;
+                        [0, 1, 2].map(|j| positions[index + j])
+                    }));
+                }
+            }
+        }
+
+        triangles
+    }
+}
@@ -14,60 +14,155 @@
 
 #![warn(missing_docs)]
 
-use std::{fs::File, path::Path};
+mod components;
+mod dxf;
+mod gltf;
+mod obj;
+mod options;
+mod ply;
+mod sketch;
+mod step;
+mod svg;
+mod three_mf;
+
+pub use self::options::{Encoding, ExportOptions, Format, Unit, UpAxis};
+
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+};
 
 use thiserror::Error;
 
 use fj_interop::mesh::Mesh;
+use fj_kernel::objects::FaceSet;
 use fj_math::{Point, Triangle};
 
-/// Export the provided mesh to the file at the given path.
+/// Export the provided shape to the file at the given path.
 ///
 /// This function will create a file if it does not exist, and will truncate it if it does.
 ///
-/// Currently 3MF & STL file types are supported. The case insensitive file extension of
-/// the provided path is used to switch between supported types.
-pub fn export(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
-    match path.extension() {
-        Some(extension) if extension.to_ascii_uppercase() == "3MF" => {
-            export_3mf(mesh, path)
-        }
-        Some(extension) if extension.to_ascii_uppercase() == "STL" => {
-            export_stl(mesh, path)
-        }
-        Some(extension) => Err(Error::InvalidExtension(
-            extension.to_string_lossy().into_owned(),
-        )),
-        None => Err(Error::NoExtension),
-    }
+/// Currently 3MF, STL, OBJ, PLY, glTF, GLB, DXF, SVG & STEP file types are supported. The case
+/// insensitive file extension of the provided path is used to switch between supported types.
+///
+/// Mesh formats (3MF, STL, OBJ, PLY, glTF & GLB) are exported from the triangle mesh. Other
+/// formats are exported from the faces of the shape, which keeps their exact geometry. DXF & SVG
+/// only support 2D shapes, while STEP only supports closed solids.
+///
+/// This uses the default [`ExportOptions`]. Use [`export_to_path`] or [`export_to_writer`] to
+/// export with other options.
+pub fn export(
+    mesh: &Mesh<Point<3>>,
+    faces: &FaceSet,
+    path: &Path,
+) -> Result<(), Error> {
+    export_to_path(mesh, faces, path, &ExportOptions::default())
 }
 
-fn export_3mf(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
-    let vertices = mesh.vertices().map(Into::into).collect();
-
-    let indices: Vec<_> = mesh.indices().collect();
-    let triangles = indices
-        .chunks(3)
-        .map(|triangle| {
-            [
-                triangle[0] as usize,
-                triangle[1] as usize,
-                triangle[2] as usize,
-            ]
-        })
-        .collect();
+/// Export the provided shape to the file at the given path, using the given options.
+///
+/// The format is chosen based on the file extension, as described in [`Format::from_path`]. Some
+/// formats keep part of their data in a separate file, which is written next to the given path:
+/// the materials of OBJ are written to an MTL file, and the geometry of glTF to a BIN file.
+pub fn export_to_path(
+    mesh: &Mesh<Point<3>>,
+    faces: &FaceSet,
+    path: &Path,
+    options: &ExportOptions,
+) -> Result<(), Error> {
+    let format = Format::from_path(path)?;
 
-    let mesh = threemf::TriangleMesh {
-        vertices,
-        triangles,
-    };
+    let mut file = BufWriter::new(File::create(path)?);
+    write(mesh, faces, &mut file, format, Some(path), options)?;
+    file.flush()?;
 
-    threemf::write(path, &mesh)?;
+    Ok(())
+}
+
+/// Export the provided shape to the given writer, in the given format, using the given options.
+///
+/// Formats that keep part of their data in a separate file are written without it: OBJ is
+/// written without materials, and glTF embeds its geometry in the glTF file.
+pub fn export_to_writer(
+    mesh: &Mesh<Point<3>>,
+    faces: &FaceSet,
+    writer: impl Write,
+    format: Format,
+    options: &ExportOptions,
+) -> Result<(), Error> {
+    let mut writer = BufWriter::new(writer);
+    write(mesh, faces, &mut writer, format, None, options)?;
+    writer.flush()?;
 
     Ok(())
 }
 
-fn export_stl(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
+fn write(
+    mesh: &Mesh<Point<3>>,
+    faces: &FaceSet,
+    writer: &mut dyn Write,
+    format: Format,
+    path: Option<&Path>,
+    options: &ExportOptions,
+) -> Result<(), Error> {
+    let name = match (&options.metadata, path.and_then(Path::file_stem)) {
+        (Some(metadata), _) => metadata.name.clone(),
+        (None, Some(name)) => name.to_string_lossy().into_owned(),
+        (None, None) => String::from("model"),
+    };
+
+    match format {
+        Format::ThreeMf => {
+            let options = options.in_unit();
+            three_mf::write_3mf(&options.mesh(mesh), writer, &name, &options)
+        }
+        Format::Stl => write_stl(&options.mesh(mesh), writer, &name, options),
+        Format::Obj => {
+            let mtl_path = path.map(|path| path.with_extension("mtl"));
+            obj::write_obj(
+                &options.mesh(mesh),
+                writer,
+                mtl_path.as_deref(),
+                options,
+            )
+        }
+        Format::Ply => ply::write_ply(&options.mesh(mesh), writer, options),
+        Format::Gltf | Format::Glb => {
+            // glTF has its own convention for the up axis, which the exporter
+            // takes care of.
+            let options = ExportOptions {
+                up_axis: UpAxis::Z,
+                ..options.clone()
+            };
+
+            let binary = format == Format::Glb;
+            let bin_path = path
+                .filter(|_| !binary)
+                .map(|path| path.with_extension("bin"));
+
+            gltf::write_gltf(
+                &options.mesh(mesh),
+                writer,
+                &name,
+                binary,
+                bin_path.as_deref(),
+            )
+        }
+        Format::Dxf => dxf::write_dxf(faces, writer, &options.in_unit()),
+        Format::Svg => svg::write_svg(faces, writer, options),
+        Format::Step => {
+            step::write_step(faces, writer, &name, &options.in_unit())
+        }
+    }
+}
+
+fn write_stl(
+    mesh: &Mesh<Point<3>>,
+    mut writer: &mut dyn Write,
+    name: &str,
+    options: &ExportOptions,
+) -> Result<(), Error> {
     let points = mesh
         .triangles()
         .map(|triangle| triangle.inner.points())
@@ -94,11 +189,19 @@ fn export_stl(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
         })
         .collect::<Vec<_>>();
 
-    let mut file = File::create(path)?;
+    if options.encoding == Encoding::Ascii {
+        return write_ascii_stl(&triangles, writer, name, options);
+    }
+
+    // Readers may mistake files that start with `solid` for ASCII STL, so the
+    // header must not start with that.
+    let mut header = [0u8; 80];
+    let identification = b"Binary STL exported by Fornjot";
+    header[..identification.len()].copy_from_slice(identification);
 
     let binary_stl_file = stl::BinaryStlFile {
         header: stl::BinaryStlHeader {
-            header: [0u8; 80],
+            header,
             num_triangles: triangles
                 .len()
                 .try_into()
@@ -107,7 +210,36 @@ fn export_stl(mesh: &Mesh<Point<3>>, path: &Path) -> Result<(), Error> {
         triangles,
     };
 
-    stl::write_stl(&mut file, &binary_stl_file)?;
+    stl::write_stl(&mut writer, &binary_stl_file)?;
+
+    Ok(())
+}
+
+fn write_ascii_stl(
+    triangles: &[stl::Triangle],
+    writer: &mut dyn Write,
+    name: &str,
+    options: &ExportOptions,
+) -> Result<(), Error> {
+    // STL requires numbers in scientific notation.
+    let number = |value: f32| match options.precision {
+        Some(precision) => format!("{value:.precision$e}"),
+        None => format!("{value:e}"),
+    };
+
+    writeln!(writer, "solid {name}")?;
+    for triangle in triangles {
+        let [nx, ny, nz] = triangle.normal.map(number);
+        writeln!(writer, "  facet normal {nx} {ny} {nz}")?;
+        writeln!(writer, "    outer loop")?;
+        for vertex in [triangle.v1, triangle.v2, triangle.v3] {
+            let [x, y, z] = vertex.map(number);
+            writeln!(writer, "      vertex {x} {y} {z}")?;
+        }
+        writeln!(writer, "    endloop")?;
+        writeln!(writer, "  endfacet")?;
+    }
+    writeln!(writer, "endsolid {name}")?;
 
     Ok(())
 }
@@ -127,11 +259,59 @@ pub enum Error {
     #[error("I/O error whilst exporting to file")]
     Io(#[from] std::io::Error),
 
+    /// Shape is not 2D, but the format only supports 2D shapes
+    #[error("shape is not 2D, but the format only supports 2D shapes")]
+    Not2d,
+
+    /// Shape is not made up of closed solids, but the format only supports those
+    #[error("shape is not made up of closed solids, but the format only supports those")]
+    NotClosed,
+
+    /// Shape contains geometry that the format doesn't support
+    #[error("format doesn't support {0}")]
+    UnsupportedGeometry(String),
+
     /// Maximum triangle count exceeded
     #[error("maximum triangle count exceeded")]
     InvalidTriangleCount,
 
-    /// Threemf error whilst exporting to 3MF file
-    #[error("threemf error whilst exporting to 3MF file")]
-    ThreeMF(#[from] threemf::Error),
+    /// ZIP error whilst exporting to 3MF file
+    #[error("ZIP error whilst exporting to 3MF file")]
+    Zip(#[from] zip::result::ZipError),
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::mesh::Mesh;
+    use fj_kernel::objects::FaceSet;
+    use fj_math::Point;
+    use fj_operations::shape_processor::ShapeProcessor;
+
+    /// Two unit cubes next to each other, in different colors
+    pub fn cubes() -> (Mesh<Point<3>>, FaceSet) {
+        let cube = |x: f64, color| -> fj::Shape {
+            let square = fj::Sketch::from_points(vec![
+                [x, 0.],
+                [x + 1., 0.],
+                [x + 1., 1.],
+                [x, 1.],
+            ])
+            .with_color(color);
+            fj::Sweep::from_path(square.into(), [0., 0., 1.]).into()
+        };
+        let group = fj::Group {
+            a: cube(0., [255, 0, 0, 255]),
+            b: cube(2., [0, 0, 255, 255]),
+        };
+
+        let processor = ShapeProcessor {
+            tolerance: None,
+            debug_dump: None,
+        };
+        let (shape, faces) = processor
+            .process_with_brep(&group.into())
+            .expect("Failed to process cubes");
+
+        (shape.mesh, faces)
+    }
 }
@@ -0,0 +1,102 @@
+//! Export of meshes to Wavefront OBJ
+
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+};
+
+use fj_interop::mesh::{Color, Mesh};
+use fj_math::Point;
+
+use crate::{Error, ExportOptions};
+
+/// Write a mesh as OBJ, with its colors in an MTL file at the given path
+///
+/// The MTL file contains a material for each color of the mesh. If no path is
+/// given, the mesh is written without materials.
+pub(crate) fn write_obj(
+    mesh: &Mesh<Point<3>>,
+    obj: &mut dyn Write,
+    mtl_path: Option<&Path>,
+    options: &ExportOptions,
+) -> Result<(), Error> {
+    if let Some(mtl_path) = mtl_path {
+        let mut colors = Vec::new();
+        for triangle in mesh.triangles() {
+            if !colors.contains(&triangle.color) {
+                colors.push(triangle.color);
+            }
+        }
+
+        let mut mtl = BufWriter::new(File::create(mtl_path)?);
+        for &color in &colors {
+            let Color([r, g, b, a]) = color;
+            let [r, g, b, a] =
+                [r, g, b, a].map(|c| options.number(f64::from(c) / 255.));
+
+            writeln!(mtl, "newmtl {}", material(color))?;
+            writeln!(mtl, "Kd {r} {g} {b}")?;
+            writeln!(mtl, "d {a}")?;
+            writeln!(mtl)?;
+        }
+        mtl.flush()?;
+
+        if let Some(mtl_name) = mtl_path.file_name() {
+            writeln!(obj, "mtllib {}", mtl_name.to_string_lossy())?;
+        }
+    }
+
+    for vertex in mesh.vertices() {
+        let [x, y, z] = vertex.coords.components.map(|s| options.number(s));
+        writeln!(obj, "v {x} {y} {z}")?;
+    }
+
+    // Normals are shared between the corners of the triangles that have the
+    // same one, which is the case for all corners of a flat face.
+    let mut normals = Vec::new();
+    let mut normal_indices = HashMap::new();
+    let triangle_normals = mesh
+        .triangles()
+        .map(|triangle| {
+            triangle.normals.map(|normal| {
+                let key = normal.components.map(|s| s.into_f64().to_bits());
+                *normal_indices.entry(key).or_insert_with(|| {
+                    normals.push(normal);
+                    normals.len()
+                })
+            })
+        })
+        .collect::<Vec<_>>();
+
+    for normal in normals {
+        let [x, y, z] = normal.components.map(|s| options.number(s));
+        writeln!(obj, "vn {x} {y} {z}")?;
+    }
+
+    let indices = mesh.indices().collect::<Vec<_>>();
+    let mut current_color = None;
+
+    for ((triangle, indices), normals) in mesh
+        .triangles()
+        .zip(indices.chunks(3))
+        .zip(triangle_normals)
+    {
+        if mtl_path.is_some() && current_color != Some(triangle.color) {
+            writeln!(obj, "usemtl {}", material(triangle.color))?;
+            current_color = Some(triangle.color);
+        }
+
+        // OBJ indices start at 1. The indices of the normals already do.
+        let [a, b, c] = [indices[0], indices[1], indices[2]].map(|i| i + 1);
+        let [na, nb, nc] = normals;
+        writeln!(obj, "f {a}//{na} {b}//{nb} {c}//{nc}")?;
+    }
+
+    Ok(())
+}
+
+fn material(Color([r, g, b, a]): Color) -> String {
+    format!("color_{r:02x}{g:02x}{b:02x}{a:02x}")
+}
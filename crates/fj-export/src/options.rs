@@ -0,0 +1,298 @@
+//! Options that control how shapes are exported
+
+use std::{borrow::Cow, fmt, path::Path};
+
+use fj::models::Metadata;
+use fj_interop::{
+    decimation::Decimation,
+    mesh::{Mesh, Polyline, Triangle},
+};
+use fj_math::{Point, Scalar, Vector};
+
+use crate::Error;
+
+/// A file format that shapes can be exported to
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Format {
+    /// 3D Manufacturing Format
+    ThreeMf,
+
+    /// STL, in the encoding chosen by [`ExportOptions::encoding`]
+    Stl,
+
+    /// Wavefront OBJ
+    Obj,
+
+    /// PLY, in the encoding chosen by [`ExportOptions::encoding`]
+    Ply,
+
+    /// glTF 2.0, with its geometry in a separate buffer
+    Gltf,
+
+    /// glTF 2.0, as a single binary file
+    Glb,
+
+    /// DXF, for 2D shapes only
+    Dxf,
+
+    /// SVG, for 2D shapes only
+    Svg,
+
+    /// STEP (AP214), for closed solids only
+    Step,
+}
+
+impl Format {
+    /// Determine the format from the extension of a path
+    ///
+    /// The extension is case insensitive.
+    pub fn from_path(path: &Path) -> Result<Self, Error> {
+        let extension = path.extension().ok_or(Error::NoExtension)?;
+
+        let format = match extension.to_ascii_uppercase().to_str() {
+            Some("3MF") => Self::ThreeMf,
+            Some("STL") => Self::Stl,
+            Some("OBJ") => Self::Obj,
+            Some("PLY") => Self::Ply,
+            Some("GLTF") => Self::Gltf,
+            Some("GLB") => Self::Glb,
+            Some("DXF") => Self::Dxf,
+            Some("SVG") => Self::Svg,
+            Some("STEP" | "STP") => Self::Step,
+            _ => {
+                return Err(Error::InvalidExtension(
+                    extension.to_string_lossy().into_owned(),
+                ))
+            }
+        };
+
+        Ok(format)
+    }
+}
+
+/// Options that control how shapes are exported
+///
+/// Not all options apply to all formats. Options that a format doesn't
+/// support are ignored.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ExportOptions {
+    /// The encoding of formats that come in binary and ASCII variants
+    pub encoding: Encoding,
+
+    /// The factor by which all coordinates are multiplied
+    ///
+    /// This is applied on top of the conversion to [`ExportOptions::unit`].
+    pub scale: f64,
+
+    /// The unit that formats which declare their unit are exported in
+    ///
+    /// Models are in millimeters. 3MF, DXF, and STEP declare their unit, so
+    /// their coordinates are converted to this unit. Other formats are
+    /// always exported in millimeters, except for glTF, which uses meters as
+    /// required by its specification.
+    pub unit: Unit,
+
+    /// The axis that points up in the exported file
+    ///
+    /// glTF always uses a y-axis that points up, as required by its
+    /// specification. This option is ignored for it, as well as for 2D
+    /// formats.
+    pub up_axis: UpAxis,
+
+    /// The number of digits after the decimal point in text formats
+    ///
+    /// If this is `None`, numbers are written with as many digits as it
+    /// takes to represent them exactly.
+    pub precision: Option<usize>,
+
+    /// Metadata about the model, for formats that support it
+    ///
+    /// Currently, only 3MF does. The name of the model is also used to name
+    /// what the other formats export, if they support names.
+    pub metadata: Option<Metadata>,
+
+    /// The decimation that is applied to meshes before they are exported
+    ///
+    /// The maximum error of the decimation is in millimeters, regardless of
+    /// [`ExportOptions::unit`] and [`ExportOptions::scale`]. If this is
+    /// `None`, meshes are exported as they are.
+    pub decimation: Option<Decimation>,
+}
+
+impl ExportOptions {
+    /// Convert the options to apply the conversion to the configured unit
+    pub(crate) fn in_unit(&self) -> Self {
+        Self {
+            scale: self.scale / self.unit.millimeters(),
+            ..self.clone()
+        }
+    }
+
+    /// Apply the scale and up axis to a point
+    pub(crate) fn point(&self, point: Point<3>) -> Point<3> {
+        Point {
+            coords: self.vector(point.coords),
+        }
+    }
+
+    /// Apply the scale and up axis to a vector
+    pub(crate) fn vector(&self, vector: Vector<3>) -> Vector<3> {
+        self.direction(vector) * self.scale
+    }
+
+    /// Apply the up axis to a direction, which is not scaled
+    pub(crate) fn direction(&self, direction: Vector<3>) -> Vector<3> {
+        match self.up_axis {
+            UpAxis::Z => direction,
+            UpAxis::Y => {
+                // A rotation around the x-axis, which turns the z-axis into
+                // the y-axis.
+                let [x, y, z] = direction.components;
+                Vector::from([x, z, -y])
+            }
+        }
+    }
+
+    /// Apply the scale to a length
+    pub(crate) fn length(&self, length: Scalar) -> Scalar {
+        length * self.scale
+    }
+
+    /// Apply the decimation, scale, and up axis to a mesh
+    ///
+    /// Returns the mesh as it is, if there's nothing to apply.
+    pub(crate) fn mesh<'r>(
+        &self,
+        mesh: &'r Mesh<Point<3>>,
+    ) -> Cow<'r, Mesh<Point<3>>> {
+        let mesh = match self.decimation {
+            Some(decimation) => Cow::Owned(mesh.decimate(decimation)),
+            None => Cow::Borrowed(mesh),
+        };
+
+        if self.scale == 1. && self.up_axis == UpAxis::Z {
+            return mesh;
+        }
+
+        let mut transformed = Mesh::new();
+        for triangle in mesh.triangles() {
+            transformed.push_mesh_triangle(Triangle {
+                inner: triangle
+                    .inner
+                    .points()
+                    .map(|point| self.point(point))
+                    .into(),
+                normals: triangle.normals.map(|normal| self.direction(normal)),
+                ..triangle
+            });
+        }
+        for polyline in mesh.polylines() {
+            transformed.push_polyline(Polyline {
+                points: polyline
+                    .points
+                    .iter()
+                    .map(|&point| self.point(point))
+                    .collect(),
+                ..polyline.clone()
+            });
+        }
+
+        Cow::Owned(transformed)
+    }
+
+    /// Format a number with the configured precision
+    pub(crate) fn number(&self, value: impl Into<f64>) -> Number {
+        Number {
+            value: value.into(),
+            precision: self.precision,
+        }
+    }
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        Self {
+            encoding: Encoding::default(),
+            scale: 1.,
+            unit: Unit::default(),
+            up_axis: UpAxis::default(),
+            precision: None,
+            metadata: None,
+            decimation: None,
+        }
+    }
+}
+
+/// The encoding of formats that come in binary and ASCII variants
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Encoding {
+    /// Binary encoding, which results in smaller files
+    #[default]
+    Binary,
+
+    /// ASCII encoding, which results in human-readable files
+    Ascii,
+}
+
+/// A unit of length
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Unit {
+    /// Micrometers
+    Micron,
+
+    /// Millimeters, which is what models use
+    #[default]
+    Millimeter,
+
+    /// Centimeters
+    Centimeter,
+
+    /// Meters
+    Meter,
+
+    /// Inches
+    Inch,
+
+    /// Feet
+    Foot,
+}
+
+impl Unit {
+    /// The length of the unit in millimeters
+    pub fn millimeters(&self) -> f64 {
+        match self {
+            Self::Micron => 0.001,
+            Self::Millimeter => 1.,
+            Self::Centimeter => 10.,
+            Self::Meter => 1000.,
+            Self::Inch => 25.4,
+            Self::Foot => 304.8,
+        }
+    }
+}
+
+/// The axis that points up
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum UpAxis {
+    /// The z-axis points up, as it does in Fornjot
+    #[default]
+    Z,
+
+    /// The y-axis points up, as it does in many game engines and 3D viewers
+    Y,
+}
+
+/// A number, formatted with a configured precision
+pub(crate) struct Number {
+    value: f64,
+    precision: Option<usize>,
+}
+
+impl fmt::Display for Number {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.precision {
+            Some(precision) => write!(f, "{:.*}", precision, self.value),
+            None => write!(f, "{}", self.value),
+        }
+    }
+}
@@ -0,0 +1,105 @@
+//! Export of meshes to PLY
+
+use std::{collections::HashMap, io::Write};
+
+use fj_interop::{
+    ext::ArrayExt,
+    mesh::{Color, Index, Mesh},
+};
+use fj_math::Point;
+
+use crate::{Encoding, Error, ExportOptions};
+
+/// Write a mesh as PLY, with a normal and a color for each vertex
+///
+/// PLY has no notion of triangle colors, so each vertex takes the color of the
+/// triangles it belongs to. Vertices that are shared by triangles of different
+/// colors or with different normals are duplicated, one for each combination.
+pub(crate) fn write_ply(
+    mesh: &Mesh<Point<3>>,
+    ply: &mut dyn Write,
+    options: &ExportOptions,
+) -> Result<(), Error> {
+    let points = mesh.vertices().collect::<Vec<_>>();
+    let indices = mesh.indices().collect::<Vec<_>>();
+
+    let mut vertices = Vec::new();
+    let mut indices_by_vertex = HashMap::new();
+    let mut faces = Vec::new();
+
+    for (triangle, indices) in mesh.triangles().zip(indices.chunks(3)) {
+        let corners = [indices[0], indices[1], indices[2]].zip_ext(
+            triangle
+                .normals
+                .map(|normal| normal.components.map(|s| s.into_f32())),
+        );
+        let face = corners.map(|(index, normal)| {
+            let key = (index, normal.map(f32::to_bits), triangle.color);
+            *indices_by_vertex.entry(key).or_insert_with(|| {
+                vertices.push((points[index as usize], normal, triangle.color));
+                vertices.len() as Index - 1
+            })
+        });
+        faces.push(face);
+    }
+
+    let format = match options.encoding {
+        Encoding::Binary => "binary_little_endian",
+        Encoding::Ascii => "ascii",
+    };
+
+    writeln!(ply, "ply")?;
+    writeln!(ply, "format {format} 1.0")?;
+    writeln!(ply, "comment Exported by Fornjot")?;
+    writeln!(ply, "element vertex {}", vertices.len())?;
+    for property in ["x", "y", "z"] {
+        writeln!(ply, "property float {property}")?;
+    }
+    for property in ["nx", "ny", "nz"] {
+        writeln!(ply, "property float {property}")?;
+    }
+    for property in ["red", "green", "blue", "alpha"] {
+        writeln!(ply, "property uchar {property}")?;
+    }
+    writeln!(ply, "element face {}", faces.len())?;
+    writeln!(ply, "property list uchar uint vertex_indices")?;
+    writeln!(ply, "end_header")?;
+
+    for (point, normal, Color(color)) in vertices {
+        match options.encoding {
+            Encoding::Binary => {
+                for coord in point.coords.components.map(|s| s.into_f32()) {
+                    ply.write_all(&coord.to_le_bytes())?;
+                }
+                for coord in normal {
+                    ply.write_all(&coord.to_le_bytes())?;
+                }
+                ply.write_all(&color)?;
+            }
+            Encoding::Ascii => {
+                let [x, y, z] =
+                    point.coords.components.map(|s| options.number(s));
+                let [nx, ny, nz] = normal.map(|c| options.number(c));
+                let [r, g, b, a] = color;
+                writeln!(ply, "{x} {y} {z} {nx} {ny} {nz} {r} {g} {b} {a}")?;
+            }
+        }
+    }
+
+    for face in faces {
+        match options.encoding {
+            Encoding::Binary => {
+                ply.write_all(&[3])?;
+                for index in face {
+                    ply.write_all(&index.to_le_bytes())?;
+                }
+            }
+            Encoding::Ascii => {
+                let [a, b, c] = face;
+                writeln!(ply, "3 {a} {b} {c}")?;
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,107 @@
+//! Access to the exact geometry of 2D shapes
+
+use fj_interop::mesh::Color;
+use fj_kernel::{
+    geometry::{
+        path::{GlobalPath, SurfacePath},
+        surface::SurfaceGeometry,
+    },
+    objects::{Cycle, FaceSet},
+};
+use fj_math::{Point, Scalar, Vector};
+
+use crate::{Error, ExportOptions};
+
+/// A face of a 2D shape
+pub(crate) struct Face2d {
+    pub color: Color,
+
+    /// The cycles that bound the face, starting with the exterior
+    pub cycles: Vec<Vec<Edge2d>>,
+}
+
+/// An edge of a 2D shape, in the direction of its cycle
+pub(crate) enum Edge2d {
+    Line {
+        start: Point<2>,
+        end: Point<2>,
+    },
+
+    /// A circular arc, which is a full circle, if it starts where it ends
+    Arc {
+        start: Point<2>,
+        end: Point<2>,
+        center: Point<2>,
+        radius: Scalar,
+
+        /// The angles of the start and end, in radians
+        ///
+        /// The arc is counter-clockwise, if the end angle is larger than the
+        /// start angle.
+        angles: [Scalar; 2],
+    },
+}
+
+/// Access the faces of a 2D shape, scaled according to the export options
+///
+/// Returns an error, if any of the faces is not located in the xy-plane,
+/// which is where all 2D shapes are located.
+pub(crate) fn faces_2d(
+    faces: &FaceSet,
+    options: &ExportOptions,
+) -> Result<Vec<Face2d>, Error> {
+    let xy_plane = SurfaceGeometry {
+        u: GlobalPath::x_axis(),
+        v: Vector::unit_y(),
+    };
+
+    faces
+        .into_iter()
+        .map(|face| {
+            if face.surface().geometry() != xy_plane {
+                return Err(Error::Not2d);
+            }
+
+            Ok(Face2d {
+                color: face.color(),
+                cycles: face
+                    .all_cycles()
+                    .map(|cycle| edges(cycle, options.scale))
+                    .collect(),
+            })
+        })
+        .collect()
+}
+
+fn edges(cycle: &Cycle, scale: f64) -> Vec<Edge2d> {
+    cycle
+        .half_edges()
+        .map(|half_edge| {
+            let [start, end] = half_edge
+                .surface_vertices()
+                .map(|vertex| vertex.position() * scale);
+
+            match half_edge.curve().path() {
+                SurfacePath::Line(_) => Edge2d::Line { start, end },
+                SurfacePath::Circle(circle) => {
+                    // The circle may be mirrored, in which case its
+                    // coordinates run clockwise.
+                    let direction = circle.a().cross2d(&circle.b()).sign();
+                    let offset = circle.a().v.atan2(circle.a().u);
+
+                    let angles = half_edge
+                        .boundary()
+                        .map(|point| offset + point.t * direction.to_scalar());
+
+                    Edge2d::Arc {
+                        start,
+                        end,
+                        center: circle.center() * scale,
+                        radius: circle.radius() * scale,
+                        angles,
+                    }
+                }
+            }
+        })
+        .collect()
+}
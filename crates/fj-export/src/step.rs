@@ -0,0 +1,692 @@
+//! Export of B-reps to STEP (AP214)
+
+use std::{collections::BTreeMap, io::Write};
+
+use fj_kernel::{
+    geometry::{
+        path::{GlobalPath, SurfacePath},
+        surface::SurfaceGeometry,
+    },
+    objects::{Face, FaceSet, GlobalVertex, HalfEdge, Handedness},
+    storage::Handle,
+};
+use fj_math::{Point, Scalar, Vector};
+
+use crate::{Error, ExportOptions, Unit};
+
+/// Write the faces of a shape as STEP
+///
+/// The faces are grouped into closed shells, each of which becomes a
+/// `MANIFOLD_SOLID_BREP`. Lines, circles, planes, and the surfaces swept from
+/// circles are exported exactly.
+pub(crate) fn write_step(
+    faces: &FaceSet,
+    file: &mut dyn Write,
+    name: &str,
+    options: &ExportOptions,
+) -> Result<(), Error> {
+    let name = name.replace('\'', "''");
+
+    let mut step = StepWriter {
+        options,
+        entities: Vec::new(),
+        vertices: BTreeMap::new(),
+        edges: BTreeMap::new(),
+    };
+
+    let context = step.context(&name);
+    let mut items = Vec::new();
+    for shell in shells(faces)? {
+        let faces = shell
+            .into_iter()
+            .map(|face| step.face(face))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let shell = step.add(format!("CLOSED_SHELL('',({}))", refs(&faces)));
+        items.push(step.add(format!("MANIFOLD_SOLID_BREP('',#{shell})")));
+    }
+    let representation = step.add(format!(
+        "ADVANCED_BREP_SHAPE_REPRESENTATION('',({}),#{})",
+        refs(&items),
+        context.representation_context,
+    ));
+    step.add(format!(
+        "SHAPE_DEFINITION_REPRESENTATION(#{},#{representation})",
+        context.product_definition_shape,
+    ));
+
+    writeln!(file, "ISO-10303-21;")?;
+    writeln!(file, "HEADER;")?;
+    writeln!(file, "FILE_DESCRIPTION(('{name}'),'2;1');")?;
+    writeln!(
+        file,
+        "FILE_NAME('{name}','',(''),(''),'Fornjot','Fornjot','');"
+    )?;
+    writeln!(
+        file,
+        "FILE_SCHEMA(('AUTOMOTIVE_DESIGN {{ 1 0 10303 214 1 1 1 1 }}'));"
+    )?;
+    writeln!(file, "ENDSEC;")?;
+    writeln!(file, "DATA;")?;
+    for (i, entity) in step.entities.iter().enumerate() {
+        writeln!(file, "#{}={entity};", i + 1)?;
+    }
+    writeln!(file, "ENDSEC;")?;
+    writeln!(file, "END-ISO-10303-21;")?;
+
+    Ok(())
+}
+
+/// Group faces into closed shells
+///
+/// Faces that share an edge belong to the same shell. Every edge must be
+/// shared by exactly two faces, or the shells are not closed.
+fn shells(faces: &FaceSet) -> Result<Vec<Vec<&Handle<Face>>>, Error> {
+    let faces = faces.into_iter().collect::<Vec<_>>();
+
+    let mut faces_of_edges: BTreeMap<[Point<3>; 2], Vec<usize>> =
+        BTreeMap::new();
+    for (i, face) in faces.iter().enumerate() {
+        for cycle in face.all_cycles() {
+            for half_edge in cycle.half_edges() {
+                faces_of_edges
+                    .entry(edge_key(half_edge))
+                    .or_default()
+                    .push(i);
+            }
+        }
+    }
+
+    // Each face starts out as its own shell. Faces that share an edge have
+    // their shells merged.
+    let mut shell_of_face = (0..faces.len()).collect::<Vec<_>>();
+    let root = |shell_of_face: &[usize], mut i: usize| {
+        while shell_of_face[i] != i {
+            i = shell_of_face[i];
+        }
+        i
+    };
+
+    for faces_of_edge in faces_of_edges.values() {
+        let [a, b] = faces_of_edge.as_slice() else {
+            return Err(Error::NotClosed);
+        };
+        let [a, b] = [*a, *b].map(|i| root(&shell_of_face, i));
+        shell_of_face[a] = b;
+    }
+
+    let mut shells: BTreeMap<usize, Vec<&Handle<Face>>> = BTreeMap::new();
+    for (i, face) in faces.into_iter().enumerate() {
+        shells
+            .entry(root(&shell_of_face, i))
+            .or_default()
+            .push(face);
+    }
+
+    Ok(shells.into_values().collect())
+}
+
+/// Collects the entities of a STEP file
+struct StepWriter<'r> {
+    options: &'r ExportOptions,
+    entities: Vec<String>,
+    vertices: BTreeMap<Point<3>, usize>,
+    edges: BTreeMap<[Point<3>; 2], usize>,
+}
+
+/// The entities that describe the model as a product
+struct Context {
+    representation_context: usize,
+    product_definition_shape: usize,
+}
+
+impl StepWriter<'_> {
+    /// Add an entity, returning its id
+    fn add(&mut self, entity: String) -> usize {
+        self.entities.push(entity);
+        self.entities.len()
+    }
+
+    fn context(&mut self, name: &str) -> Context {
+        let application_context = self.add(
+            "APPLICATION_CONTEXT('core data for automotive mechanical design \
+            processes')"
+                .into(),
+        );
+        self.add(format!(
+            "APPLICATION_PROTOCOL_DEFINITION('international standard',\
+            'automotive_design',2000,#{application_context})"
+        ));
+        let product_context = self.add(format!(
+            "PRODUCT_CONTEXT('',#{application_context},'mechanical')"
+        ));
+        let product = self.add(format!(
+            "PRODUCT('{name}','{name}','',(#{product_context}))"
+        ));
+        self.add(format!(
+            "PRODUCT_RELATED_PRODUCT_CATEGORY('part',$,(#{product}))"
+        ));
+        let formation =
+            self.add(format!("PRODUCT_DEFINITION_FORMATION('','',#{product})"));
+        let definition_context = self.add(format!(
+            "PRODUCT_DEFINITION_CONTEXT('part definition',\
+            #{application_context},'design')"
+        ));
+        let product_definition = self.add(format!(
+            "PRODUCT_DEFINITION('design','',#{formation},\
+            #{definition_context})"
+        ));
+        let product_definition_shape = self.add(format!(
+            "PRODUCT_DEFINITION_SHAPE('','',#{product_definition})"
+        ));
+
+        let length = self.length_unit();
+        let angle = self
+            .add("(NAMED_UNIT(*)PLANE_ANGLE_UNIT()SI_UNIT($,.RADIAN.))".into());
+        let solid_angle = self.add(
+            "(NAMED_UNIT(*)SI_UNIT($,.STERADIAN.)SOLID_ANGLE_UNIT())".into(),
+        );
+        let uncertainty = self.add(format!(
+            "UNCERTAINTY_MEASURE_WITH_UNIT(LENGTH_MEASURE(1.E-07),#{length},\
+            'distance_accuracy_value','confusion accuracy')"
+        ));
+        let representation_context = self.add(format!(
+            "(GEOMETRIC_REPRESENTATION_CONTEXT(3)\
+            GLOBAL_UNCERTAINTY_ASSIGNED_CONTEXT((#{uncertainty}))\
+            GLOBAL_UNIT_ASSIGNED_CONTEXT((#{length},#{angle},#{solid_angle}))\
+            REPRESENTATION_CONTEXT('',''))"
+        ));
+
+        Context {
+            representation_context,
+            product_definition_shape,
+        }
+    }
+
+    fn length_unit(&mut self) -> usize {
+        let prefix = match self.options.unit {
+            Unit::Micron => ".MICRO.",
+            Unit::Millimeter => ".MILLI.",
+            Unit::Centimeter => ".CENTI.",
+            Unit::Meter => "$",
+            Unit::Inch | Unit::Foot => {
+                // Imperial units are defined by their length in millimeters.
+                let millimeter = self.add(
+                    "(LENGTH_UNIT()NAMED_UNIT(*)SI_UNIT(.MILLI.,.METRE.))"
+                        .into(),
+                );
+                let measure = self.add(format!(
+                    "LENGTH_MEASURE_WITH_UNIT(LENGTH_MEASURE({}),#{millimeter})",
+                    real(Scalar::from(self.options.unit.millimeters()), None)
+                ));
+                let exponents = self
+                    .add("DIMENSIONAL_EXPONENTS(1.,0.,0.,0.,0.,0.,0.)".into());
+                let name = match self.options.unit {
+                    Unit::Inch => "INCH",
+                    _ => "FOOT",
+                };
+
+                return self.add(format!(
+                    "(CONVERSION_BASED_UNIT('{name}',#{measure})LENGTH_UNIT()\
+                    NAMED_UNIT(#{exponents}))"
+                ));
+            }
+        };
+
+        self.add(format!(
+            "(LENGTH_UNIT()NAMED_UNIT(*)SI_UNIT({prefix},.METRE.))"
+        ))
+    }
+
+    fn face(&mut self, face: &Face) -> Result<usize, Error> {
+        let surface = face.surface().geometry();
+
+        let mut bounds = Vec::new();
+        for (i, cycle) in face.all_cycles().enumerate() {
+            let edges = cycle
+                .half_edges()
+                .map(|half_edge| self.oriented_edge(half_edge, &surface))
+                .collect::<Result<Vec<_>, _>>()?;
+            let edge_loop =
+                self.add(format!("EDGE_LOOP('',({}))", refs(&edges)));
+
+            let kind = if i == 0 {
+                "FACE_OUTER_BOUND"
+            } else {
+                "FACE_BOUND"
+            };
+            bounds.push(self.add(format!("{kind}('',#{edge_loop},.T.)")));
+        }
+
+        // The front side of the face is where its exterior is wound
+        // counter-clockwise. Which side that is depends on the coordinate
+        // system of the surface.
+        let (surface, normal_is_u_cross_v) = self.surface(&surface)?;
+        let same_sense = normal_is_u_cross_v
+            == (face.coord_handedness() == Handedness::RightHanded);
+
+        Ok(self.add(format!(
+            "ADVANCED_FACE('',({}),#{surface},{})",
+            refs(&bounds),
+            logical(same_sense)
+        )))
+    }
+
+    /// Add a surface, and whether its normal is the cross product of the
+    /// directions of its coordinate system
+    fn surface(
+        &mut self,
+        surface: &SurfaceGeometry,
+    ) -> Result<(usize, bool), Error> {
+        match surface.u {
+            GlobalPath::Line(line) => {
+                let axis = line.direction().cross(&surface.v);
+                let placement =
+                    self.placement(line.origin(), axis, line.direction());
+
+                Ok((self.add(format!("PLANE('',#{placement})")), true))
+            }
+            GlobalPath::Circle(circle) => {
+                let axis = circle.a().cross(&circle.b()).normalize();
+                let alignment = surface.v.normalize().dot(&axis);
+
+                if (alignment.abs() - Scalar::ONE).abs() < Scalar::from(1e-9) {
+                    // The normal of a cylindrical surface always points away
+                    // from its axis.
+                    let placement =
+                        self.placement(circle.center(), axis, circle.a());
+                    let surface = self.add(format!(
+                        "CYLINDRICAL_SURFACE('',#{placement},{})",
+                        self.length(circle.radius())
+                    ));
+
+                    Ok((surface, alignment > Scalar::ZERO))
+                } else {
+                    let curve = self.circle([
+                        circle.center(),
+                        circle.center() + circle.a(),
+                        circle.center() + circle.b(),
+                    ]);
+                    let direction = self.direction(surface.v);
+                    let vector = self.add(format!(
+                        "VECTOR('',#{direction},{})",
+                        self.length(surface.v.magnitude())
+                    ));
+                    let surface = self.add(format!(
+                        "SURFACE_OF_LINEAR_EXTRUSION('',#{curve},#{vector})"
+                    ));
+
+                    Ok((surface, true))
+                }
+            }
+            GlobalPath::Helix(_) => Err(Error::UnsupportedGeometry(
+                "surfaces swept along a helix".into(),
+            )),
+        }
+    }
+
+    fn oriented_edge(
+        &mut self,
+        half_edge: &HalfEdge,
+        surface: &SurfaceGeometry,
+    ) -> Result<usize, Error> {
+        // Each edge is shared by two half-edges, which run in opposite
+        // directions. The edge is created for the first one, so the second
+        // one is oriented against it.
+        let global_edge = edge_key(half_edge);
+        if let Some(edge) = self.edges.get(&global_edge) {
+            return Ok(self.add(format!("ORIENTED_EDGE('',*,*,#{edge},.F.)")));
+        }
+
+        let [start, end] = half_edge
+            .surface_vertices()
+            .map(|vertex| vertex.global_form().clone());
+        let start_vertex = self.vertex(&start);
+        let end_vertex = self.vertex(&end);
+
+        let (curve, same_sense) =
+            self.curve(half_edge, surface, [start, end])?;
+        let edge = self.add(format!(
+            "EDGE_CURVE('',#{start_vertex},#{end_vertex},#{curve},{})",
+            logical(same_sense)
+        ));
+        self.edges.insert(global_edge, edge);
+
+        Ok(self.add(format!("ORIENTED_EDGE('',*,*,#{edge},.T.)")))
+    }
+
+    /// Add the curve of a half-edge, and whether the half-edge runs in the
+    /// direction of the curve
+    fn curve(
+        &mut self,
+        half_edge: &HalfEdge,
+        surface: &SurfaceGeometry,
+        [start, end]: [Handle<GlobalVertex>; 2],
+    ) -> Result<(usize, bool), Error> {
+        let boundary = half_edge.boundary().map(|point| point.t);
+
+        let circle = match (half_edge.curve().path(), surface.u) {
+            (SurfacePath::Circle(circle), GlobalPath::Line(_)) => {
+                let circle = [
+                    surface.point_from_surface_coords(circle.center()),
+                    surface.point_from_surface_coords(
+                        circle.center() + circle.a(),
+                    ),
+                    surface.point_from_surface_coords(
+                        circle.center() + circle.b(),
+                    ),
+                ];
+                Some((circle, boundary))
+            }
+            (SurfacePath::Line(line), GlobalPath::Circle(circle))
+                if line.direction().v == Scalar::ZERO =>
+            {
+                // The half-edge runs along the circle that the surface is
+                // swept from.
+                let origin = line.origin();
+                let center = circle.center() + surface.v * origin.v;
+                let circle = [center, center + circle.a(), center + circle.b()];
+
+                let direction = line.direction().u;
+                Some((circle, boundary.map(|t| origin.u + t * direction)))
+            }
+            (SurfacePath::Line(_), GlobalPath::Line(_)) => None,
+            (SurfacePath::Line(line), GlobalPath::Circle(_))
+                if line.direction().u == Scalar::ZERO =>
+            {
+                None
+            }
+            _ => {
+                return Err(Error::UnsupportedGeometry(
+                    "curves that are not lines or circles".into(),
+                ))
+            }
+        };
+
+        match circle {
+            Some((circle, [a, b])) => Ok((self.circle(circle), a < b)),
+            None => {
+                let [start, end] = [start, end].map(|vertex| vertex.position());
+                let point = self.point(start);
+                let direction = self.direction(end - start);
+                let vector = self.add(format!(
+                    "VECTOR('',#{direction},{})",
+                    self.length((end - start).magnitude())
+                ));
+
+                Ok((self.add(format!("LINE('',#{point},#{vector})")), true))
+            }
+        }
+    }
+
+    /// Add a circle, defined by its center and the points at the angles 0
+    /// and a quarter turn
+    fn circle(&mut self, [center, a, b]: [Point<3>; 3]) -> usize {
+        let [a, b] = [a - center, b - center];
+        let placement = self.placement(center, a.cross(&b), a);
+
+        let radius = self.length(a.magnitude());
+        self.add(format!("CIRCLE('',#{placement},{radius})"))
+    }
+
+    fn vertex(&mut self, vertex: &Handle<GlobalVertex>) -> usize {
+        if let Some(vertex) = self.vertices.get(&vertex.position()) {
+            return *vertex;
+        }
+
+        let point = self.point(vertex.position());
+        let id = self.add(format!("VERTEX_POINT('',#{point})"));
+        self.vertices.insert(vertex.position(), id);
+
+        id
+    }
+
+    fn placement(
+        &mut self,
+        location: Point<3>,
+        axis: Vector<3>,
+        reference: Vector<3>,
+    ) -> usize {
+        let location = self.point(location);
+        let axis = self.direction(axis);
+        let reference = self.direction(reference);
+
+        self.add(format!(
+            "AXIS2_PLACEMENT_3D('',#{location},#{axis},#{reference})"
+        ))
+    }
+
+    fn point(&mut self, point: Point<3>) -> usize {
+        let [x, y, z] = self
+            .options
+            .point(point)
+            .coords
+            .components
+            .map(|s| real(s, self.options.precision));
+        self.add(format!("CARTESIAN_POINT('',({x},{y},{z}))"))
+    }
+
+    fn direction(&mut self, direction: Vector<3>) -> usize {
+        let [x, y, z] = self
+            .options
+            .direction(direction)
+            .normalize()
+            .components
+            .map(|s| real(s, self.options.precision));
+        self.add(format!("DIRECTION('',({x},{y},{z}))"))
+    }
+
+    fn length(&self, length: Scalar) -> String {
+        real(self.options.length(length), self.options.precision)
+    }
+}
+
+/// Identify the edge that a half-edge refers to
+///
+/// Faces that share an edge don't necessarily share the objects that
+/// represent it. A sweep, for example, creates separate global edges and
+/// vertices for each of its faces. Like [`Shell::is_watertight`], edges are
+/// identified by the positions of their vertices instead.
+///
+/// [`Shell::is_watertight`]: fj_kernel::objects::Shell::is_watertight
+fn edge_key(half_edge: &HalfEdge) -> [Point<3>; 2] {
+    half_edge
+        .global_form()
+        .vertices()
+        .access_in_normalized_order()
+        .map(|vertex| vertex.position())
+}
+
+fn refs(ids: &[usize]) -> String {
+    ids.iter()
+        .map(|id| format!("#{id}"))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn logical(value: bool) -> &'static str {
+    if value {
+        ".T."
+    } else {
+        ".F."
+    }
+}
+
+/// Format a real number, as required by STEP
+///
+/// STEP requires a decimal point in every real number, and an uppercase
+/// exponent.
+fn real(value: Scalar, precision: Option<usize>) -> String {
+    let value = match precision {
+        Some(precision) => format!("{:.*}", precision, value.into_f64()),
+        None => format!("{:?}", value.into_f64()),
+    };
+
+    let (mantissa, exponent) = match value.split_once('e') {
+        Some((mantissa, exponent)) => (mantissa, format!("E{exponent}")),
+        None => (value.as_str(), String::new()),
+    };
+    let point = if mantissa.contains('.') { "" } else { "." };
+
+    format!("{mantissa}{point}{exponent}")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, BTreeSet};
+
+    use fj_operations::shape_processor::ShapeProcessor;
+
+    use crate::{
+        export_to_writer, tests::cubes, Error, ExportOptions, Format, Unit,
+    };
+
+    #[test]
+    fn cubes_are_exported_as_solids() {
+        let (mesh, faces) = cubes();
+
+        for unit in [Unit::Millimeter, Unit::Inch] {
+            let options = ExportOptions {
+                unit,
+                ..ExportOptions::default()
+            };
+            let mut step = Vec::new();
+            export_to_writer(&mesh, &faces, &mut step, Format::Step, &options)
+                .unwrap();
+            let entities = entities(&String::from_utf8(step).unwrap());
+
+            assert_eq!(count(&entities, "MANIFOLD_SOLID_BREP"), 2);
+            assert_eq!(count(&entities, "ADVANCED_FACE"), 12);
+            assert_eq!(count(&entities, "PLANE"), 12);
+            assert_eq!(count(&entities, "EDGE_CURVE"), 24);
+            assert_eq!(count(&entities, "ORIENTED_EDGE"), 48);
+            assert_eq!(count(&entities, "VERTEX_POINT"), 16);
+
+            // The vertices are in the unit of the file.
+            let vertices = entities
+                .values()
+                .filter_map(|entity| entity.strip_prefix("VERTEX_POINT('',#"))
+                .map(|point| {
+                    let point = &entities
+                        [&point.trim_end_matches(')').parse().unwrap()];
+                    coordinates(point)
+                        .map(|c| (c * unit.millimeters()).round() as i64)
+                })
+                .collect::<BTreeSet<_>>();
+            let expected = [0, 1, 2, 3]
+                .into_iter()
+                .flat_map(|x| [[x, 0], [x, 1]])
+                .flat_map(|[x, y]| [[x, y, 0], [x, y, 1]])
+                .collect::<BTreeSet<_>>();
+            assert_eq!(vertices, expected);
+        }
+    }
+
+    #[test]
+    fn cylinder_is_exported_exactly() {
+        let circle = fj::Sketch::from_circle(fj::Circle::from_radius(1.));
+        let cylinder = fj::Sweep::from_path(circle.into(), [0., 0., 2.]);
+
+        let processor = ShapeProcessor {
+            tolerance: None,
+            debug_dump: None,
+        };
+        let (shape, faces) =
+            processor.process_with_brep(&cylinder.into()).unwrap();
+
+        let mut step = Vec::new();
+        export_to_writer(
+            &shape.mesh,
+            &faces,
+            &mut step,
+            Format::Step,
+            &ExportOptions::default(),
+        )
+        .unwrap();
+        let entities = entities(&String::from_utf8(step).unwrap());
+
+        assert_eq!(count(&entities, "MANIFOLD_SOLID_BREP"), 1);
+        assert_eq!(count(&entities, "PLANE"), 2);
+        assert_eq!(count(&entities, "CYLINDRICAL_SURFACE"), 1);
+        assert_eq!(count(&entities, "CIRCLE"), 2);
+    }
+
+    #[test]
+    fn sketches_are_not_exported() {
+        let square = fj::Sketch::from_points(vec![
+            [0., 0.],
+            [1., 0.],
+            [1., 1.],
+            [0., 1.],
+        ]);
+
+        let processor = ShapeProcessor {
+            tolerance: None,
+            debug_dump: None,
+        };
+        let (shape, faces) =
+            processor.process_with_brep(&square.into()).unwrap();
+
+        let result = export_to_writer(
+            &shape.mesh,
+            &faces,
+            Vec::new(),
+            Format::Step,
+            &ExportOptions::default(),
+        );
+        assert!(matches!(result, Err(Error::NotClosed)));
+    }
+
+    /// Parse the entities of the data section of a STEP file
+    ///
+    /// Also checks that all references between entities are valid.
+    fn entities(step: &str) -> BTreeMap<usize, String> {
+        let (_, data) = step.split_once("DATA;\n").unwrap();
+        let (data, _) = data.split_once("ENDSEC;\n").unwrap();
+
+        let entities = data
+            .lines()
+            .map(|line| {
+                let (id, entity) = line
+                    .strip_prefix('#')
+                    .and_then(|line| line.strip_suffix(';'))
+                    .and_then(|line| line.split_once('='))
+                    .unwrap();
+                (id.parse().unwrap(), entity.to_string())
+            })
+            .collect::<BTreeMap<usize, _>>();
+
+        for entity in entities.values() {
+            for reference in entity.split('#').skip(1) {
+                let id = reference
+                    .split(|c: char| !c.is_ascii_digit())
+                    .next()
+                    .unwrap();
+                assert!(
+                    entities.contains_key(&id.parse().unwrap()),
+                    "`{entity}` refers to missing entity #{id}"
+                );
+            }
+        }
+
+        entities
+    }
+
+    fn count(entities: &BTreeMap<usize, String>, kind: &str) -> usize {
+        entities
+            .values()
+            .filter(|entity| entity.starts_with(&format!("{kind}(")))
+            .count()
+    }
+
+    fn coordinates(point: &str) -> [f64; 3] {
+        let coordinates = point
+            .strip_prefix("CARTESIAN_POINT('',(")
+            .and_then(|point| point.strip_suffix("))"))
+            .unwrap()
+            .split(',')
+            .map(|c| c.parse().unwrap())
+            .collect::<Vec<_>>();
+        coordinates.try_into().unwrap()
+    }
+}
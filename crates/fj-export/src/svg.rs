@@ -0,0 +1,164 @@
+//! Export of 2D shapes to SVG
+
+use std::{
+    f64::consts::{FRAC_PI_2, TAU},
+    io::Write,
+};
+
+use fj_kernel::objects::FaceSet;
+use fj_math::{Aabb, Point};
+
+use crate::{
+    sketch::{faces_2d, Edge2d},
+    Error, ExportOptions,
+};
+
+/// Write the faces of a 2D shape as SVG
+///
+/// Each face becomes a path, filled with the color of the face. Lines and arcs
+/// are exported exactly. The document is sized in millimeters, and the y-axis
+/// is flipped to point up, like it does in the model.
+pub(crate) fn write_svg(
+    faces: &FaceSet,
+    file: &mut dyn Write,
+    options: &ExportOptions,
+) -> Result<(), Error> {
+    let faces = faces_2d(faces, options)?;
+
+    let points = faces
+        .iter()
+        .flat_map(|face| face.cycles.iter().flatten())
+        .flat_map(extreme_points)
+        .collect::<Vec<_>>();
+    let aabb = if points.is_empty() {
+        Aabb::default()
+    } else {
+        Aabb::<2>::from_points(points)
+    };
+
+    let [min_x, min_y] = aabb.min.coords.components.map(|s| s.into_f64());
+    let [max_x, max_y] = aabb.max.coords.components.map(|s| s.into_f64());
+    let [width, height] = [max_x - min_x, max_y - min_y];
+    let number = |value: f64| options.number(value);
+
+    // SVG's y-axis points down. Flipping it within the bounding box keeps the
+    // view box the same.
+    let point = |point: Point<2>| {
+        let x = number(point.u.into_f64());
+        let y = number(min_y + max_y - point.v.into_f64());
+        format!("{x} {y}")
+    };
+
+    let [min_x, min_y, width, height] =
+        [min_x, min_y, width, height].map(number);
+    writeln!(
+        file,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" \
+        width=\"{width}mm\" height=\"{height}mm\" \
+        viewBox=\"{min_x} {min_y} {width} {height}\">"
+    )?;
+
+    for face in &faces {
+        write!(file, "  <path d=\"")?;
+
+        for cycle in &face.cycles {
+            let Some(first) = cycle.first() else {
+                continue;
+            };
+            let start = match first {
+                Edge2d::Line { start, .. } | Edge2d::Arc { start, .. } => {
+                    *start
+                }
+            };
+            write!(file, "M {} ", point(start))?;
+
+            for edge in cycle {
+                match edge {
+                    Edge2d::Line { end, .. } => {
+                        write!(file, "L {} ", point(*end))?;
+                    }
+                    Edge2d::Arc {
+                        start,
+                        end,
+                        center,
+                        radius,
+                        angles: [a, b],
+                    } => {
+                        let radius = number(radius.into_f64());
+
+                        // Counter-clockwise arcs in the model are clockwise in
+                        // the document, which SVG considers the positive
+                        // direction.
+                        let sweep = u8::from(a < b);
+
+                        if start == end {
+                            // An arc can't start and end at the same point,
+                            // so full circles are split in two.
+                            let middle = *center - (*start - *center);
+                            for end in [middle, *end] {
+                                write!(
+                                    file,
+                                    "A {radius} {radius} 0 0 {sweep} {} ",
+                                    point(end)
+                                )?;
+                            }
+                        } else {
+                            let angle = (*b - *a).abs().into_f64();
+                            let large_arc = u8::from(angle > TAU / 2.);
+                            write!(
+                                file,
+                                "A {radius} {radius} 0 {large_arc} {sweep} {} ",
+                                point(*end)
+                            )?;
+                        }
+                    }
+                }
+            }
+
+            write!(file, "Z ")?;
+        }
+
+        let [r, g, b, a] = face.color.0;
+        writeln!(
+            file,
+            "\" fill=\"#{r:02x}{g:02x}{b:02x}\" fill-opacity=\"{}\" \
+            fill-rule=\"evenodd\" />",
+            f64::from(a) / 255.,
+        )?;
+    }
+
+    writeln!(file, "</svg>")?;
+
+    Ok(())
+}
+
+/// The points of an edge that are relevant to its bounding box
+///
+/// Besides the endpoints, those are the points where arcs are furthest along
+/// the x- or y-axis.
+fn extreme_points(edge: &Edge2d) -> Vec<Point<2>> {
+    match edge {
+        Edge2d::Line { start, end } => vec![*start, *end],
+        Edge2d::Arc {
+            start,
+            end,
+            center,
+            radius,
+            angles: [a, b],
+        } => {
+            let [min, max] = if a < b { [*a, *b] } else { [*b, *a] }
+                .map(|angle| angle.into_f64());
+            let radius = radius.into_f64();
+
+            let mut points = vec![*start, *end];
+            let first = (min / FRAC_PI_2).ceil() as i64;
+            let last = (max / FRAC_PI_2).floor() as i64;
+            for i in first..=last {
+                let (sin, cos) = (i as f64 * FRAC_PI_2).sin_cos();
+                points.push(*center + [radius * cos, radius * sin]);
+            }
+
+            points
+        }
+    }
+}
@@ -0,0 +1,284 @@
+//! Export of meshes to 3MF
+
+use std::{
+    collections::HashMap,
+    io::{Cursor, Write},
+};
+
+use fj_interop::mesh::{Color, Index, Mesh};
+use fj_math::Point;
+use zip::{write::FileOptions, CompressionMethod, ZipWriter};
+
+use crate::{components::components, Error, ExportOptions, Unit};
+
+/// Write a mesh as a 3MF package
+///
+/// Each shape of a group becomes a separate object. The colors of the
+/// triangles are written as a color group, which all objects refer to. If
+/// metadata is configured, it is written into the model.
+///
+/// A 3MF package is a ZIP archive. ZIP archives can't be written in a single
+/// pass, so the package is assembled in memory first.
+pub(crate) fn write_3mf(
+    mesh: &Mesh<Point<3>>,
+    writer: &mut dyn Write,
+    name: &str,
+    options: &ExportOptions,
+) -> Result<(), Error> {
+    let mut archive = ZipWriter::new(Cursor::new(Vec::new()));
+    let file_options =
+        FileOptions::default().compression_method(CompressionMethod::Deflated);
+
+    archive.start_file("[Content_Types].xml", file_options)?;
+    write!(
+        archive,
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+        <Types xmlns=\"http://schemas.openxmlformats.org/package/2006/content-types\">\
+        <Default Extension=\"rels\" \
+        ContentType=\"application/vnd.openxmlformats-package.relationships+xml\"/>\
+        <Default Extension=\"model\" \
+        ContentType=\"application/vnd.ms-package.3dmanufacturing-3dmodel+xml\"/>\
+        </Types>"
+    )?;
+
+    archive.start_file("_rels/.rels", file_options)?;
+    write!(
+        archive,
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+        <Relationships xmlns=\"http://schemas.openxmlformats.org/package/2006/relationships\">\
+        <Relationship Target=\"/3D/3dmodel.model\" Id=\"rel0\" \
+        Type=\"http://schemas.microsoft.com/3dmanufacturing/2013/01/3dmodel\"/>\
+        </Relationships>"
+    )?;
+
+    archive.start_file("3D/3dmodel.model", file_options)?;
+    write_model(mesh, &mut archive, name, options)?;
+
+    let package = archive.finish()?.into_inner();
+    writer.write_all(&package)?;
+
+    Ok(())
+}
+
+fn write_model(
+    mesh: &Mesh<Point<3>>,
+    model: &mut dyn Write,
+    name: &str,
+    options: &ExportOptions,
+) -> Result<(), Error> {
+    // The color group is the first resource. The objects come after it.
+    const COLOR_GROUP_ID: usize = 1;
+
+    let unit = match options.unit {
+        Unit::Micron => "micron",
+        Unit::Millimeter => "millimeter",
+        Unit::Centimeter => "centimeter",
+        Unit::Meter => "meter",
+        Unit::Inch => "inch",
+        Unit::Foot => "foot",
+    };
+
+    writeln!(model, "<?xml version=\"1.0\" encoding=\"UTF-8\"?>")?;
+    writeln!(
+        model,
+        "<model unit=\"{unit}\" xml:lang=\"en-US\" \
+        xmlns=\"http://schemas.microsoft.com/3dmanufacturing/core/2015/02\" \
+        xmlns:m=\"http://schemas.microsoft.com/3dmanufacturing/material/2015/02\" \
+        xmlns:fj=\"https://www.fornjot.app/\">"
+    )?;
+
+    writeln!(model, "<metadata name=\"Application\">Fornjot</metadata>")?;
+    if let Some(metadata) = &options.metadata {
+        // 3MF doesn't define metadata for the version, so it gets a name in
+        // a namespace of its own.
+        let description = metadata
+            .description
+            .as_ref()
+            .or(metadata.short_description.as_ref());
+        let entries = [
+            ("Title", Some(&metadata.name)),
+            ("fj:Version", Some(&metadata.version)),
+            ("Description", description),
+            ("LicenseTerms", metadata.license.as_ref()),
+        ];
+
+        for (name, value) in entries {
+            if let Some(value) = value {
+                writeln!(
+                    model,
+                    "<metadata name=\"{name}\">{}</metadata>",
+                    escape(value)
+                )?;
+            }
+        }
+    }
+
+    writeln!(model, "<resources>")?;
+
+    let mut colors = Vec::new();
+    let mut indices_by_color = HashMap::new();
+    for triangle in mesh.triangles() {
+        indices_by_color.entry(triangle.color).or_insert_with(|| {
+            colors.push(triangle.color);
+            colors.len() - 1
+        });
+    }
+
+    if !colors.is_empty() {
+        writeln!(model, "<m:colorgroup id=\"{COLOR_GROUP_ID}\">")?;
+        for Color([r, g, b, a]) in colors {
+            writeln!(
+                model,
+                "<m:color color=\"#{r:02X}{g:02X}{b:02X}{a:02X}\"/>"
+            )?;
+        }
+        writeln!(model, "</m:colorgroup>")?;
+    }
+
+    let points = mesh.vertices().collect::<Vec<_>>();
+    let components = components(mesh);
+
+    for (i, component) in components.iter().enumerate() {
+        let id = COLOR_GROUP_ID + 1 + i;
+        let object_name = escape(&format!("{name} {}", i + 1));
+
+        // Each object has its own vertices, so the indices of the mesh need
+        // to be mapped to indices within the object.
+        let mut vertices = Vec::new();
+        let mut local_indices: HashMap<Index, usize> = HashMap::new();
+        let triangles = component
+            .iter()
+            .map(|triangle| {
+                let indices = triangle.indices.map(|index| {
+                    *local_indices.entry(index).or_insert_with(|| {
+                        vertices.push(points[index as usize]);
+                        vertices.len() - 1
+                    })
+                });
+                (indices, indices_by_color[&triangle.triangle.color])
+            })
+            .collect::<Vec<_>>();
+
+        // Objects must have a default property, if their triangles have
+        // properties.
+        let pindex = triangles.first().map(|(_, color)| *color).unwrap_or(0);
+
+        writeln!(
+            model,
+            "<object id=\"{id}\" type=\"model\" name=\"{object_name}\" \
+            pid=\"{COLOR_GROUP_ID}\" pindex=\"{pindex}\">"
+        )?;
+        writeln!(model, "<mesh>")?;
+
+        writeln!(model, "<vertices>")?;
+        for vertex in vertices {
+            let [x, y, z] = vertex.coords.components.map(|s| options.number(s));
+            writeln!(model, "<vertex x=\"{x}\" y=\"{y}\" z=\"{z}\"/>")?;
+        }
+        writeln!(model, "</vertices>")?;
+
+        writeln!(model, "<triangles>")?;
+        for ([v1, v2, v3], color) in triangles {
+            writeln!(
+                model,
+                "<triangle v1=\"{v1}\" v2=\"{v2}\" v3=\"{v3}\" p1=\"{color}\"/>"
+            )?;
+        }
+        writeln!(model, "</triangles>")?;
+
+        writeln!(model, "</mesh>")?;
+        writeln!(model, "</object>")?;
+    }
+
+    writeln!(model, "</resources>")?;
+
+    writeln!(model, "<build>")?;
+    for i in 0..components.len() {
+        let id = COLOR_GROUP_ID + 1 + i;
+        writeln!(model, "<item objectid=\"{id}\"/>")?;
+    }
+    writeln!(model, "</build>")?;
+
+    writeln!(model, "</model>")?;
+
+    Ok(())
+}
+
+/// Escape text for use in XML
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Read};
+
+    use crate::{export_to_writer, tests::cubes, ExportOptions, Format, Unit};
+
+    #[test]
+    fn round_trip() {
+        let (mesh, faces) = cubes();
+        let expected =
+            mesh.triangles()
+                .map(|triangle| {
+                    triangle.inner.points().map(|point| {
+                        point.coords.components.map(|s| s.into_f64())
+                    })
+                })
+                .collect::<Vec<_>>();
+
+        // The reader converts units back to millimeters.
+        for unit in [Unit::Millimeter, Unit::Inch] {
+            let options = ExportOptions {
+                unit,
+                ..ExportOptions::default()
+            };
+            let mut package = Vec::new();
+            export_to_writer(
+                &mesh,
+                &faces,
+                &mut package,
+                Format::ThreeMf,
+                &options,
+            )
+            .unwrap();
+
+            let imported = fj::Mesh::parse_3mf(&package).unwrap();
+            assert_eq!(imported.triangles().len(), expected.len());
+            for triangle in &expected {
+                let is_imported = imported.triangles().iter().any(|imported| {
+                    imported
+                        .iter()
+                        .flatten()
+                        .zip(triangle.iter().flatten())
+                        .all(|(a, b)| (a - b).abs() < 1e-9)
+                });
+                assert!(is_imported, "{triangle:?} is missing from import");
+            }
+
+            // Each cube is an object, and each has its own color.
+            let mut archive =
+                zip::ZipArchive::new(Cursor::new(package)).unwrap();
+            let mut model = String::new();
+            archive
+                .by_name("3D/3dmodel.model")
+                .unwrap()
+                .read_to_string(&mut model)
+                .unwrap();
+            assert_eq!(model.matches("<object ").count(), 2);
+            assert_eq!(model.matches("<item ").count(), 2);
+            assert_eq!(model.matches("<m:color ").count(), 2);
+        }
+    }
+}
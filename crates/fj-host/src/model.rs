@@ -102,7 +102,7 @@ impl Model {
         // I don't know of a way to fix this. We should take this as motivation
         // to switch to a better technique:
         // https://github.com/hannobraun/Fornjot/issues/71
-        let shape = unsafe {
+        let (shape, metadata) = unsafe {
             let lib = libloading::Library::new(&self.lib_path)
                 .map_err(Error::LoadingLibrary)?;
 
@@ -149,20 +149,22 @@ impl Model {
 
             let mut host = Host::new(&self.parameters);
 
-            match init(&mut abi::Host::from(&mut host)) {
-                abi::ffi_safe::Result::Ok(_metadata) => {}
-                abi::ffi_safe::Result::Err(e) => {
-                    return Err(Error::InitializeModel(e.into()));
-                }
-            }
+            let metadata: fj::models::Metadata =
+                match init(&mut abi::Host::from(&mut host)) {
+                    abi::ffi_safe::Result::Ok(metadata) => metadata.into(),
+                    abi::ffi_safe::Result::Err(e) => {
+                        return Err(Error::InitializeModel(e.into()));
+                    }
+                };
 
             let model = host.take_model().ok_or(Error::NoModelRegistered)?;
 
-            model.shape(&host).map_err(Error::Shape)?
+            (model.shape(&host).map_err(Error::Shape)?, metadata)
         };
 
         Ok(Evaluation {
             shape,
+            metadata,
             compile_time: seconds_taken.into(),
         })
     }
@@ -176,6 +178,9 @@ pub struct Evaluation {
     /// The shape
     pub shape: fj::Shape,
 
+    /// The metadata of the model
+    pub metadata: fj::models::Metadata,
+
     /// The time it took to compile the shape, from the Cargo output
     pub compile_time: String,
 }
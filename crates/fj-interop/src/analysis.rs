@@ -0,0 +1,285 @@
+//! Analysis of triangle meshes
+//!
+//! See [`MeshAnalysis`].
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+};
+
+use fj_math::{Point, Scalar, Segment, Triangle};
+
+use crate::mesh::{Index, Mesh};
+
+/// The ratio of area to squared length of the longest edge, below which a
+/// triangle is considered to have no area
+const MIN_RELATIVE_AREA: f64 = 1e-12;
+
+/// The result of analyzing a triangle mesh
+///
+/// A mesh that represents a closed solid, as is required for 3D printing, has
+/// none of the problems that are reported here. Use [`MeshAnalysis::is_ok`] to
+/// check for that.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct MeshAnalysis {
+    /// Edges that are only part of one triangle
+    ///
+    /// The mesh has holes in it, where these edges are.
+    pub boundary_edges: Vec<Segment<3>>,
+
+    /// Edges that are part of more than two triangles
+    pub non_manifold_edges: Vec<Segment<3>>,
+
+    /// Edges whose two triangles have an inconsistent winding
+    ///
+    /// The triangles of a consistently wound mesh run along each of their
+    /// shared edges in opposite directions.
+    pub inconsistently_wound_edges: Vec<Segment<3>>,
+
+    /// Triangles that don't span any meaningful area
+    ///
+    /// A triangle has no meaningful area, if its area is tiny compared to the
+    /// length of its edges. Such triangles are sliver-thin, and can't be
+    /// processed reliably by other software.
+    pub zero_area_triangles: Vec<Triangle<3>>,
+
+    /// Triangles that have the same points as another triangle
+    ///
+    /// Only the additional triangles are listed, not the first of them.
+    pub duplicate_triangles: Vec<Triangle<3>>,
+}
+
+impl MeshAnalysis {
+    /// Analyze the provided mesh
+    pub fn of(mesh: &Mesh<Point<3>>) -> Self {
+        let vertices = mesh.vertices().collect::<Vec<_>>();
+        let indices = mesh.indices().collect::<Vec<_>>();
+
+        let mut analysis = Self::default();
+
+        // The directions in which the triangles run along each edge. The key
+        // is the edge with its indices in ascending order. The value is
+        // `true` for each triangle that runs along the edge in that order.
+        let mut edges: BTreeMap<[Index; 2], Vec<bool>> = BTreeMap::new();
+        let mut triangles = BTreeSet::new();
+
+        for (triangle, indices) in mesh.triangles().zip(indices.chunks(3)) {
+            let [a, b, c] = [indices[0], indices[1], indices[2]];
+
+            for [start, end] in [[a, b], [b, c], [c, a]] {
+                let ascending = start < end;
+                let key = if ascending {
+                    [start, end]
+                } else {
+                    [end, start]
+                };
+
+                edges.entry(key).or_default().push(ascending);
+            }
+
+            let mut key = [a, b, c];
+            key.sort();
+            if !triangles.insert(key) {
+                analysis.duplicate_triangles.push(triangle.inner);
+            }
+
+            if has_zero_area(triangle.inner) {
+                analysis.zero_area_triangles.push(triangle.inner);
+            }
+        }
+
+        for ([a, b], directions) in edges {
+            let segment =
+                Segment::from_points([a, b].map(|i| vertices[i as usize]));
+
+            match directions.as_slice() {
+                [_] => analysis.boundary_edges.push(segment),
+                [a, b] if a == b => {
+                    analysis.inconsistently_wound_edges.push(segment)
+                }
+                [_, _] => {}
+                _ => analysis.non_manifold_edges.push(segment),
+            }
+        }
+
+        analysis
+    }
+
+    /// Indicate whether the mesh is free of any problems
+    pub fn is_ok(&self) -> bool {
+        self.is_watertight()
+            && self.inconsistently_wound_edges.is_empty()
+            && self.zero_area_triangles.is_empty()
+            && self.duplicate_triangles.is_empty()
+    }
+
+    /// Indicate whether the mesh is watertight
+    ///
+    /// A watertight mesh has no holes, and each of its edges is shared by
+    /// exactly two triangles.
+    pub fn is_watertight(&self) -> bool {
+        self.boundary_edges.is_empty() && self.non_manifold_edges.is_empty()
+    }
+}
+
+impl fmt::Display for MeshAnalysis {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_ok() {
+            return write!(f, "Mesh has no problems");
+        }
+
+        write!(f, "Mesh has problems:")?;
+
+        let edges = [
+            ("boundary edges", &self.boundary_edges),
+            ("non-manifold edges", &self.non_manifold_edges),
+            (
+                "inconsistently wound edges",
+                &self.inconsistently_wound_edges,
+            ),
+        ];
+        for (problem, edges) in edges {
+            if let Some(edge) = edges.first() {
+                let [a, b] = edge.points();
+                write!(
+                    f,
+                    "\n- {} {problem}, first from {a:?} to {b:?}",
+                    edges.len()
+                )?;
+            }
+        }
+
+        let triangles = [
+            ("zero-area triangles", &self.zero_area_triangles),
+            ("duplicate triangles", &self.duplicate_triangles),
+        ];
+        for (problem, triangles) in triangles {
+            if let Some(triangle) = triangles.first() {
+                let [a, b, c] = triangle.points();
+                write!(
+                    f,
+                    "\n- {} {problem}, first at {a:?}, {b:?}, {c:?}",
+                    triangles.len()
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn has_zero_area(triangle: Triangle<3>) -> bool {
+    let [a, b, c] = triangle.points();
+
+    let longest_edge = [b - a, c - b, a - c]
+        .into_iter()
+        .map(|edge| edge.magnitude())
+        .fold(Scalar::ZERO, Scalar::max);
+
+    triangle.area()
+        < longest_edge * longest_edge * Scalar::from_f64(MIN_RELATIVE_AREA)
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Point;
+
+    use crate::mesh::{Color, Mesh};
+
+    use super::MeshAnalysis;
+
+    #[test]
+    fn closed_mesh() {
+        let analysis = MeshAnalysis::of(&mesh(&tetrahedron()));
+
+        assert_eq!(analysis, MeshAnalysis::default());
+        assert!(analysis.is_ok());
+    }
+
+    #[test]
+    fn boundary_edges() {
+        let [a, b, c, d] = tetrahedron();
+        let analysis = MeshAnalysis::of(&mesh(&[a, b, c]));
+
+        // The edges of the missing triangle are open.
+        let mut edges = analysis
+            .boundary_edges
+            .iter()
+            .map(|edge| {
+                let mut points = edge.points();
+                points.sort();
+                points
+            })
+            .collect::<Vec<_>>();
+        edges.sort();
+        let [d0, d1, d2] = d.map(Point::from);
+        let mut expected = vec![[d0, d1], [d1, d2], [d0, d2]];
+        for edge in &mut expected {
+            edge.sort();
+        }
+        expected.sort();
+        assert_eq!(edges, expected);
+
+        assert!(!analysis.is_watertight());
+        assert!(!analysis.is_ok());
+    }
+
+    #[test]
+    fn non_manifold_edges() {
+        let mut triangles = tetrahedron().to_vec();
+        triangles.push([[0., 0., 0.], [1., 0., 0.], [0., -1., 1.]]);
+        let analysis = MeshAnalysis::of(&mesh(&triangles));
+
+        assert_eq!(analysis.non_manifold_edges.len(), 1);
+        assert_eq!(analysis.boundary_edges.len(), 2);
+        assert!(!analysis.is_watertight());
+    }
+
+    #[test]
+    fn inconsistently_wound_edges() {
+        let [a, b, c, [d0, d1, d2]] = tetrahedron();
+        let analysis = MeshAnalysis::of(&mesh(&[a, b, c, [d0, d2, d1]]));
+
+        // The mesh is closed, but the flipped triangle runs along each of its
+        // edges in the same direction as its neighbor.
+        assert!(analysis.is_watertight());
+        assert_eq!(analysis.inconsistently_wound_edges.len(), 3);
+        assert!(!analysis.is_ok());
+    }
+
+    #[test]
+    fn zero_area_triangles() {
+        let sliver = [[0., 0., 0.], [1., 0., 0.], [0.5, 1e-13, 0.]];
+        let analysis = MeshAnalysis::of(&mesh(&[sliver]));
+
+        assert_eq!(analysis.zero_area_triangles.len(), 1);
+    }
+
+    #[test]
+    fn duplicate_triangles() {
+        let mut triangles = tetrahedron().to_vec();
+        triangles.push(triangles[0]);
+        let analysis = MeshAnalysis::of(&mesh(&triangles));
+
+        assert_eq!(analysis.duplicate_triangles.len(), 1);
+        assert_eq!(
+            analysis.duplicate_triangles[0].points(),
+            triangles[0].map(Point::from)
+        );
+    }
+
+    /// The triangles of a tetrahedron, with their normals pointing outward
+    fn tetrahedron() -> [[[f64; 3]; 3]; 4] {
+        let [a, b, c, d] =
+            [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.], [0., 0., 1.]];
+        [[a, c, b], [a, b, d], [a, d, c], [b, c, d]]
+    }
+
+    fn mesh(triangles: &[[[f64; 3]; 3]]) -> Mesh<Point<3>> {
+        let mut mesh = Mesh::new();
+        for &triangle in triangles {
+            mesh.push_triangle(triangle, Color::default());
+        }
+        mesh
+    }
+}
@@ -0,0 +1,116 @@
+//! Bounding volume hierarchy of a triangle mesh
+//!
+//! See [`MeshBvh`].
+
+use fj_math::{Aabb, Bvh, Point, Scalar, Vector};
+
+use crate::mesh::{Mesh, Triangle};
+
+/// A bounding volume hierarchy of the triangles of a mesh
+///
+/// Speeds up queries that only concern a small part of a mesh, like casting a
+/// ray for picking, or checking two meshes for interference. Building it takes
+/// longer than a single query that looks at all triangles, so it should be
+/// built once and reused.
+#[derive(Clone, Debug, Default)]
+pub struct MeshBvh {
+    bvh: Bvh,
+    triangles: Vec<Triangle>,
+}
+
+impl MeshBvh {
+    /// Build a BVH of the triangles of a mesh
+    pub fn new(mesh: &Mesh<Point<3>>) -> Self {
+        let triangles = mesh.triangles().collect::<Vec<_>>();
+        let bvh =
+            Bvh::new(triangles.iter().map(|triangle| {
+                Aabb::<3>::from_points(triangle.inner.points())
+            }));
+
+        Self { bvh, triangles }
+    }
+
+    /// Access the BVH of the triangles
+    ///
+    /// The items of the BVH are the indices of the triangles, as they are
+    /// passed to [`MeshBvh::triangle`].
+    pub fn bvh(&self) -> &Bvh {
+        &self.bvh
+    }
+
+    /// Access a triangle by its index
+    pub fn triangle(&self, index: usize) -> &Triangle {
+        &self.triangles[index]
+    }
+
+    /// Cast a ray against the mesh
+    ///
+    /// Returns the closest triangle that the ray hits, and the distance along
+    /// the ray, in multiples of `dir`, at which it hits it.
+    pub fn cast_ray(
+        &self,
+        origin: Point<3>,
+        dir: Vector<3>,
+        max_toi: f64,
+    ) -> Option<(&Triangle, Scalar)> {
+        self.bvh
+            .cast_ray(origin, dir, max_toi, |index| {
+                self.triangles[index]
+                    .inner
+                    .cast_local_ray(origin, dir, max_toi, true)
+            })
+            .map(|(index, toi)| (&self.triangles[index], toi))
+    }
+
+    /// Iterate over the triangles whose AABBs contain the point
+    pub fn near_point(
+        &self,
+        point: impl Into<Point<3>>,
+    ) -> impl Iterator<Item = &Triangle> + '_ {
+        self.bvh
+            .containing(point)
+            .into_iter()
+            .map(|index| &self.triangles[index])
+    }
+
+    /// Iterate over the triangles whose AABBs overlap the provided AABB
+    pub fn overlapping(
+        &self,
+        aabb: &Aabb<3>,
+    ) -> impl Iterator<Item = &Triangle> + '_ {
+        self.bvh
+            .overlapping(aabb)
+            .into_iter()
+            .map(|index| &self.triangles[index])
+    }
+
+    /// Iterate over the pairs of triangles from two meshes, whose AABBs
+    /// overlap
+    ///
+    /// Only those pairs of triangles can intersect, so this narrows down the
+    /// triangles that an interference check needs to look at.
+    pub fn overlapping_pairs<'r>(
+        &'r self,
+        other: &'r Self,
+    ) -> impl Iterator<Item = [&'r Triangle; 2]> + 'r {
+        self.bvh
+            .overlapping_pairs(&other.bvh)
+            .into_iter()
+            .map(|[a, b]| [&self.triangles[a], &other.triangles[b]])
+    }
+
+    /// Determine whether the mesh contains a triangle
+    ///
+    /// Like [`Mesh::contains_triangle`], this returns true, if a triangle with
+    /// any combination of the provided points is part of the mesh.
+    pub fn contains_triangle(
+        &self,
+        triangle: impl Into<fj_math::Triangle<3>>,
+    ) -> bool {
+        let triangle = triangle.into().normalize();
+        let aabb = Aabb::<3>::from_points(triangle.points());
+
+        self.overlapping(&aabb)
+            .any(|other| other.inner.normalize() == triangle)
+    }
+}
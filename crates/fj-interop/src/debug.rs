@@ -4,13 +4,20 @@
 //! ecosystem. The types in here aren't very useful in themselves, but they
 //! define an interface that other crates use to communicate between each other.
 
+use std::{fmt, io};
+
 use fj_math::{Point, Segment};
 
+use crate::mesh::FaceId;
+
 /// Debug info from the CAD kernel that can be visualized
 #[derive(Clone, Debug, Default)]
 pub struct DebugInfo {
     /// Rays being used during face triangulation
     pub triangle_edge_checks: Vec<TriangleEdgeCheck>,
+
+    /// Geometry that algorithms emitted, to visualize what they're doing
+    pub geometry: Vec<DebugGeometry>,
 }
 
 impl DebugInfo {
@@ -26,6 +33,162 @@ impl DebugInfo {
     /// allocations.
     pub fn clear(&mut self) {
         self.triangle_edge_checks.clear();
+        self.geometry.clear();
+    }
+
+    /// Add a primitive to the debug geometry
+    pub fn push(
+        &mut self,
+        category: DebugCategory,
+        primitive: impl Into<DebugPrimitive>,
+    ) {
+        self.geometry.push(DebugGeometry {
+            category,
+            primitive: primitive.into(),
+        });
+    }
+
+    /// Iterate over the debug geometry of a category
+    pub fn geometry_of(
+        &self,
+        category: DebugCategory,
+    ) -> impl Iterator<Item = &DebugPrimitive> + '_ {
+        self.geometry
+            .iter()
+            .filter(move |geometry| geometry.category == category)
+            .map(|geometry| &geometry.primitive)
+    }
+
+    /// Write the debug info in a human-readable format, one item per line
+    pub fn write_to(&self, mut writer: impl io::Write) -> io::Result<()> {
+        for check in &self.triangle_edge_checks {
+            write!(writer, "triangle edge check at {:?}", check.origin)?;
+            for hit in &check.hits {
+                write!(writer, ", hit {hit:?}")?;
+            }
+            writeln!(writer)?;
+        }
+
+        for geometry in &self.geometry {
+            writeln!(writer, "{}: {}", geometry.category, geometry.primitive)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A primitive that an algorithm emitted as debug geometry
+#[derive(Clone, Debug)]
+pub struct DebugGeometry {
+    /// The category that the primitive belongs to
+    pub category: DebugCategory,
+
+    /// The primitive
+    pub primitive: DebugPrimitive,
+}
+
+/// The category of debug geometry
+///
+/// Categories correspond to the algorithms that emit the geometry, so the
+/// geometry of the algorithm that is being debugged can be singled out.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum DebugCategory {
+    /// Emitted by the approximation of curves, edges, and faces
+    Approximation,
+
+    /// Emitted by the triangulation of faces
+    Triangulation,
+
+    /// Emitted by intersection tests
+    Intersection,
+
+    /// Emitted by sweeping
+    Sweep,
+}
+
+impl DebugCategory {
+    /// All categories
+    pub const ALL: [Self; 4] = [
+        Self::Approximation,
+        Self::Triangulation,
+        Self::Intersection,
+        Self::Sweep,
+    ];
+}
+
+impl fmt::Display for DebugCategory {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Self::Approximation => "approximation",
+            Self::Triangulation => "triangulation",
+            Self::Intersection => "intersection",
+            Self::Sweep => "sweep",
+        };
+
+        write!(f, "{name}")
+    }
+}
+
+/// A primitive of debug geometry
+#[derive(Clone, Debug)]
+pub enum DebugPrimitive {
+    /// A point
+    Point(Point<3>),
+
+    /// A line segment
+    Segment(Segment<3>),
+
+    /// A sequence of connected line segments
+    Polyline(Vec<Point<3>>),
+
+    /// A text label at a position
+    Label {
+        /// The position that the label refers to
+        position: Point<3>,
+
+        /// The text of the label
+        text: String,
+    },
+
+    /// A face, to be highlighted
+    Face(FaceId),
+}
+
+impl fmt::Display for DebugPrimitive {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Point(point) => write!(f, "point {point:?}"),
+            Self::Segment(segment) => write!(f, "segment {segment:?}"),
+            Self::Polyline(points) => write!(f, "polyline {points:?}"),
+            Self::Label { position, text } => {
+                write!(f, "label {text:?} at {position:?}")
+            }
+            Self::Face(FaceId(id)) => write!(f, "face {id}"),
+        }
+    }
+}
+
+impl From<Point<3>> for DebugPrimitive {
+    fn from(point: Point<3>) -> Self {
+        Self::Point(point)
+    }
+}
+
+impl From<Segment<3>> for DebugPrimitive {
+    fn from(segment: Segment<3>) -> Self {
+        Self::Segment(segment)
+    }
+}
+
+impl From<Vec<Point<3>>> for DebugPrimitive {
+    fn from(points: Vec<Point<3>>) -> Self {
+        Self::Polyline(points)
+    }
+}
+
+impl From<FaceId> for DebugPrimitive {
+    fn from(face: FaceId) -> Self {
+        Self::Face(face)
     }
 }
 
@@ -0,0 +1,792 @@
+//! Decimation of triangle meshes
+//!
+//! See [`Decimation`].
+
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, BTreeSet, BinaryHeap, HashMap},
+    ops,
+};
+
+use fj_math::{Aabb, Point, Scalar, Triangle, Vector};
+
+use crate::mesh::{self, Color, FaceId, Mesh, Polyline};
+
+/// Limits for the decimation of a mesh
+///
+/// Decimation repeatedly removes the vertex whose removal changes the shape of
+/// the mesh the least, by merging it into one of its neighbors. It stops once
+/// the mesh has been reduced to the target number of triangles, or once the
+/// next removal would introduce an error larger than the maximum error.
+///
+/// If neither limit is set, only vertices whose removal doesn't change the
+/// shape of the mesh are removed, like those within flat regions.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Decimation {
+    /// The number of triangles that decimation stops at
+    pub target_triangles: Option<usize>,
+
+    /// The maximum error that decimation may introduce
+    ///
+    /// The error is estimated from the distance of the decimated mesh to the
+    /// planes of the original triangles. If this is `None`, but
+    /// `target_triangles` is set, decimation continues regardless of the error,
+    /// until the target is reached.
+    pub max_error: Option<Scalar>,
+}
+
+impl Mesh<Point<3>> {
+    /// Decimate the mesh, reducing the number of its triangles
+    ///
+    /// Triangles keep their color and face. The boundaries between faces, or
+    /// between triangles of different colors, are preserved: Vertices on such
+    /// a boundary are only merged with neighbors along the boundary, and
+    /// vertices where boundaries meet are not removed at all.
+    ///
+    /// See [`Decimation`] for how far the mesh is decimated.
+    pub fn decimate(&self, decimation: Decimation) -> Self {
+        let mut decimator = Decimator::new(self);
+        decimator.run(decimation);
+        decimator.into_mesh(self)
+    }
+}
+
+/// A triangle of a mesh that is being decimated
+struct DecimatedTriangle {
+    vertices: [usize; 3],
+    normals: [Vector<3>; 3],
+    color: Color,
+    face: Option<FaceId>,
+    removed: bool,
+}
+
+impl DecimatedTriangle {
+    /// Triangles of different regions are separated by a preserved boundary
+    fn region(&self) -> (Option<FaceId>, Color) {
+        (self.face, self.color)
+    }
+}
+
+struct Decimator {
+    positions: Vec<Point<3>>,
+    triangles: Vec<DecimatedTriangle>,
+    triangles_of_vertex: Vec<Vec<usize>>,
+    quadrics: Vec<Quadric>,
+
+    /// The vertex that each removed vertex has been merged into
+    merged_into: Vec<Option<usize>>,
+
+    /// Incremented whenever the neighborhood of a vertex changes
+    ///
+    /// Used to detect outdated collapse candidates.
+    stamps: Vec<u32>,
+
+    num_triangles: usize,
+}
+
+impl Decimator {
+    fn new(mesh: &Mesh<Point<3>>) -> Self {
+        let positions = mesh.vertices().collect::<Vec<_>>();
+        let indices = mesh.indices().collect::<Vec<_>>();
+
+        let triangles = mesh
+            .triangles()
+            .zip(indices.chunks(3))
+            .map(|(triangle, indices)| DecimatedTriangle {
+                vertices: [indices[0], indices[1], indices[2]]
+                    .map(|index| index as usize),
+                normals: triangle.normals,
+                color: triangle.color,
+                face: triangle.face,
+                removed: false,
+            })
+            .collect::<Vec<_>>();
+
+        let mut triangles_of_vertex = vec![Vec::new(); positions.len()];
+        for (i, triangle) in triangles.iter().enumerate() {
+            for vertex in triangle.vertices {
+                triangles_of_vertex[vertex].push(i);
+            }
+        }
+
+        let num_triangles = triangles.len();
+        let mut decimator = Self {
+            quadrics: vec![Quadric::default(); positions.len()],
+            merged_into: vec![None; positions.len()],
+            stamps: vec![0; positions.len()],
+            positions,
+            triangles,
+            triangles_of_vertex,
+            num_triangles,
+        };
+        decimator.init_quadrics();
+
+        decimator
+    }
+
+    /// Compute the initial quadric of each vertex
+    ///
+    /// The quadric of a vertex measures the squared distance to the planes of
+    /// its triangles. Edges on boundaries get additional planes perpendicular
+    /// to their triangles, which keep the boundaries in place.
+    fn init_quadrics(&mut self) {
+        for triangle in &self.triangles {
+            let Some(normal) = self.normal(triangle.vertices) else {
+                continue;
+            };
+
+            let [a, b, c] = triangle.vertices;
+            let plane = Quadric::from_plane(normal, self.positions[a]);
+
+            for vertex in [a, b, c] {
+                self.quadrics[vertex] += plane;
+            }
+        }
+
+        for u in 0..self.positions.len() {
+            for v in self.boundary_neighbors(u) {
+                // Each boundary edge is visited from both of its vertices.
+                // Only handle it once.
+                if v < u {
+                    continue;
+                }
+
+                for &t in &self.triangles_of_vertex[u] {
+                    let triangle = &self.triangles[t];
+                    if !triangle.vertices.contains(&v) {
+                        continue;
+                    }
+                    let Some(normal) = self.normal(triangle.vertices) else {
+                        continue;
+                    };
+
+                    let edge = self.positions[v] - self.positions[u];
+                    let perpendicular = edge.cross(&normal);
+                    if perpendicular.magnitude() == Scalar::ZERO {
+                        continue;
+                    }
+
+                    let plane = Quadric::from_plane(
+                        perpendicular.normalize(),
+                        self.positions[u],
+                    );
+                    self.quadrics[u] += plane;
+                    self.quadrics[v] += plane;
+                }
+            }
+        }
+    }
+
+    fn run(&mut self, decimation: Decimation) {
+        let max_error = match decimation {
+            Decimation {
+                max_error: Some(max_error),
+                ..
+            } => max_error.into_f64(),
+            Decimation {
+                target_triangles: Some(_),
+                max_error: None,
+            } => f64::INFINITY,
+            Decimation {
+                target_triangles: None,
+                max_error: None,
+            } => 0.,
+        };
+
+        // Rounding errors prevent even the removal of vertices within flat
+        // regions from being free. Allow for errors that are negligible
+        // compared to the size of the mesh.
+        let size = Aabb::<3>::from_points(self.positions.iter().copied())
+            .size()
+            .magnitude()
+            .into_f64();
+        let max_error = f64::max(max_error, size * 1e-9);
+        let max_cost = max_error * max_error;
+
+        let target_triangles = decimation.target_triangles.unwrap_or(0);
+
+        let mut candidates = BinaryHeap::new();
+        for u in 0..self.positions.len() {
+            candidates.extend(self.candidate(u));
+        }
+
+        while let Some(candidate) = candidates.pop() {
+            if self.num_triangles <= target_triangles {
+                break;
+            }
+
+            let Candidate { cost, u, v, stamp } = candidate;
+            if self.merged_into[u].is_some() || stamp != self.stamps[u] {
+                continue;
+            }
+            if cost > max_cost {
+                break;
+            }
+
+            self.collapse(u, v);
+
+            let mut affected = self.neighbors(v);
+            affected.insert(v);
+            for vertex in affected {
+                self.stamps[vertex] += 1;
+                candidates.extend(self.candidate(vertex));
+            }
+        }
+    }
+
+    /// Find the cheapest way to remove a vertex, if it can be removed
+    fn candidate(&self, u: usize) -> Option<Candidate> {
+        if self.merged_into[u].is_some() {
+            return None;
+        }
+
+        // Vertices on a boundary may only move along it. Vertices where
+        // boundaries meet or end must stay in place.
+        let boundary_neighbors = self.boundary_neighbors(u);
+        let targets = match boundary_neighbors.len() {
+            0 => self.neighbors(u),
+            2 => boundary_neighbors,
+            _ => return None,
+        };
+
+        targets
+            .into_iter()
+            .filter(|&v| self.can_collapse(u, v))
+            .map(|v| Candidate {
+                cost: self.quadrics[u].cost(self.positions[v]),
+                u,
+                v,
+                stamp: self.stamps[u],
+            })
+            .max()
+    }
+
+    /// Determine whether merging `u` into `v` keeps the mesh intact
+    fn can_collapse(&self, u: usize, v: usize) -> bool {
+        // If `u` and `v` have neighbors in common that don't form a triangle
+        // with them, merging them would connect those neighbors by more than
+        // two triangles.
+        let mut opposite = BTreeSet::new();
+        for &t in &self.triangles_of_vertex[u] {
+            let triangle = &self.triangles[t];
+            if triangle.vertices.contains(&v) {
+                opposite.extend(
+                    triangle
+                        .vertices
+                        .into_iter()
+                        .filter(|&vertex| vertex != u && vertex != v),
+                );
+            }
+        }
+        if opposite.is_empty() {
+            return false;
+        }
+        let common = self
+            .neighbors(u)
+            .intersection(&self.neighbors(v))
+            .copied()
+            .collect::<BTreeSet<_>>();
+        if common != opposite {
+            return false;
+        }
+
+        // The triangles that remain must not collapse or flip over.
+        for &t in &self.triangles_of_vertex[u] {
+            let triangle = &self.triangles[t];
+            if triangle.vertices.contains(&v) {
+                continue;
+            }
+
+            let Some(before) = self.normal(triangle.vertices) else {
+                continue;
+            };
+            let after = self.normal(triangle.vertices.map(|vertex| {
+                if vertex == u {
+                    v
+                } else {
+                    vertex
+                }
+            }));
+
+            match after {
+                Some(after) if after.dot(&before) > Scalar::ZERO => {}
+                _ => return false,
+            }
+        }
+
+        true
+    }
+
+    /// Merge vertex `u` into vertex `v`
+    fn collapse(&mut self, u: usize, v: usize) {
+        let triangles = std::mem::take(&mut self.triangles_of_vertex[u]);
+
+        // The triangles that contain both vertices are removed. Their normals
+        // at `v` are used for the remaining triangles of the same region,
+        // which now have a corner at `v` instead of `u`.
+        let mut normals_at_v = BTreeMap::new();
+        for &t in &triangles {
+            let triangle = &mut self.triangles[t];
+            if !triangle.vertices.contains(&v) {
+                continue;
+            }
+
+            triangle.removed = true;
+            self.num_triangles -= 1;
+
+            for (vertex, normal) in
+                triangle.vertices.into_iter().zip(triangle.normals)
+            {
+                if vertex == v {
+                    normals_at_v.insert(triangle.region(), normal);
+                }
+            }
+            for vertex in triangle.vertices {
+                if vertex != u {
+                    self.triangles_of_vertex[vertex]
+                        .retain(|&other| other != t);
+                }
+            }
+        }
+
+        for t in triangles {
+            let triangle = &mut self.triangles[t];
+            if triangle.removed {
+                continue;
+            }
+
+            let normal_at_v = normals_at_v.get(&triangle.region()).copied();
+            for (vertex, normal) in
+                triangle.vertices.iter_mut().zip(&mut triangle.normals)
+            {
+                if *vertex == u {
+                    *vertex = v;
+                    if let Some(normal_at_v) = normal_at_v {
+                        *normal = normal_at_v;
+                    }
+                }
+            }
+
+            self.triangles_of_vertex[v].push(t);
+        }
+
+        let quadric = self.quadrics[u];
+        self.quadrics[v] += quadric;
+        self.merged_into[u] = Some(v);
+    }
+
+    fn into_mesh(self, original: &Mesh<Point<3>>) -> Mesh<Point<3>> {
+        let mut mesh = Mesh::new();
+
+        for triangle in &self.triangles {
+            if triangle.removed {
+                continue;
+            }
+
+            let points = triangle.vertices.map(|vertex| self.positions[vertex]);
+            if let Ok(inner) = Triangle::from_points(points) {
+                mesh.push_mesh_triangle(mesh::Triangle {
+                    inner,
+                    normals: triangle.normals,
+                    color: triangle.color,
+                    face: triangle.face,
+                });
+            }
+        }
+
+        // The points of the polylines are vertices of the mesh. They follow
+        // the vertices they have been merged into.
+        let vertices_by_position = self
+            .positions
+            .iter()
+            .enumerate()
+            .map(|(i, &position)| (position, i))
+            .collect::<HashMap<_, _>>();
+
+        for polyline in original.polylines() {
+            let mut points: Vec<Point<3>> = Vec::new();
+
+            for &point in &polyline.points {
+                let point = match vertices_by_position.get(&point) {
+                    Some(&vertex) => self.positions[self.resolve(vertex)],
+                    None => point,
+                };
+
+                if points.last() != Some(&point) {
+                    points.push(point);
+                }
+            }
+
+            if points.len() >= 2 {
+                mesh.push_polyline(Polyline {
+                    points,
+                    ..polyline.clone()
+                });
+            }
+        }
+
+        mesh
+    }
+
+    /// Find the vertex that a vertex has ended up as
+    fn resolve(&self, mut vertex: usize) -> usize {
+        while let Some(merged_into) = self.merged_into[vertex] {
+            vertex = merged_into;
+        }
+        vertex
+    }
+
+    fn neighbors(&self, u: usize) -> BTreeSet<usize> {
+        self.triangles_of_vertex[u]
+            .iter()
+            .flat_map(|&t| self.triangles[t].vertices)
+            .filter(|&vertex| vertex != u)
+            .collect()
+    }
+
+    /// Find the neighbors that `u` shares a boundary edge with
+    ///
+    /// An edge is on a boundary, if it isn't shared by exactly two triangles
+    /// of the same region.
+    fn boundary_neighbors(&self, u: usize) -> BTreeSet<usize> {
+        let mut regions_by_neighbor = BTreeMap::new();
+        for &t in &self.triangles_of_vertex[u] {
+            let triangle = &self.triangles[t];
+
+            for vertex in triangle.vertices {
+                if vertex != u {
+                    regions_by_neighbor
+                        .entry(vertex)
+                        .or_insert_with(Vec::new)
+                        .push(triangle.region());
+                }
+            }
+        }
+
+        regions_by_neighbor
+            .into_iter()
+            .filter(|(_, regions)| match regions.as_slice() {
+                [a, b] => a != b,
+                _ => true,
+            })
+            .map(|(vertex, _)| vertex)
+            .collect()
+    }
+
+    /// Compute the normal of a triangle, if it has an area
+    fn normal(&self, vertices: [usize; 3]) -> Option<Vector<3>> {
+        let [a, b, c] = vertices.map(|vertex| self.positions[vertex]);
+        let normal = (b - a).cross(&(c - a));
+
+        if normal.magnitude() == Scalar::ZERO {
+            return None;
+        }
+
+        Some(normal.normalize())
+    }
+}
+
+/// A possible removal of vertex `u`, by merging it into vertex `v`
+struct Candidate {
+    cost: f64,
+    u: usize,
+    v: usize,
+    stamp: u32,
+}
+
+// Candidates are ordered in reverse order of their cost, so the cheapest comes
+// first in a `BinaryHeap`.
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.cost.total_cmp(&self.cost)
+    }
+}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Candidate {}
+
+/// The sum of squared distances to a number of planes
+///
+/// Stores the upper half of the symmetric 4x4 matrix that is built from the
+/// plane equations.
+#[derive(Clone, Copy, Default)]
+struct Quadric([f64; 10]);
+
+impl Quadric {
+    fn from_plane(normal: Vector<3>, point: Point<3>) -> Self {
+        let [a, b, c] = normal.components.map(Scalar::into_f64);
+        let d = -normal.dot(&point.coords).into_f64();
+
+        Self([
+            a * a,
+            a * b,
+            a * c,
+            a * d,
+            b * b,
+            b * c,
+            b * d,
+            c * c,
+            c * d,
+            d * d,
+        ])
+    }
+
+    fn cost(&self, point: Point<3>) -> f64 {
+        let [x, y, z] = point.coords.components.map(Scalar::into_f64);
+        let q = self.0;
+
+        q[0] * x * x
+            + 2. * q[1] * x * y
+            + 2. * q[2] * x * z
+            + 2. * q[3] * x
+            + q[4] * y * y
+            + 2. * q[5] * y * z
+            + 2. * q[6] * y
+            + q[7] * z * z
+            + 2. * q[8] * z
+            + q[9]
+    }
+}
+
+impl ops::AddAssign for Quadric {
+    fn add_assign(&mut self, other: Self) {
+        for (a, b) in self.0.iter_mut().zip(other.0) {
+            *a += b;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar, Triangle};
+
+    use crate::{
+        analysis::MeshAnalysis,
+        mesh::{self, Color, EdgeId, FaceId, Mesh, Polyline},
+    };
+
+    use super::Decimation;
+
+    #[test]
+    fn minimal_mesh_is_kept() {
+        let [a, b, c, d] =
+            [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.], [0., 0., 1.]];
+        let mut tetrahedron = Mesh::new();
+        for triangle in [[a, c, b], [a, b, d], [a, d, c], [b, c, d]] {
+            tetrahedron.push_triangle(triangle, Color::default());
+        }
+
+        for decimation in [
+            Decimation::default(),
+            Decimation {
+                target_triangles: None,
+                max_error: Some(Scalar::from(0.01)),
+            },
+        ] {
+            let decimated = tetrahedron.decimate(decimation);
+            assert_eq!(decimated.triangles().count(), 4);
+            assert!(MeshAnalysis::of(&decimated).is_ok());
+        }
+    }
+
+    #[test]
+    fn flat_grid_is_reduced_to_its_corners() {
+        let mesh = grid(10, |_| 0., |_| FaceId(0));
+        let decimated = mesh.decimate(Decimation::default());
+
+        // Only the corners are left, as removing them would change the
+        // outline of the grid.
+        assert_eq!(decimated.triangles().count(), 2);
+        let corners = [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.], [1., 1., 0.]]
+            .map(Point::from);
+        for point in decimated.vertices() {
+            assert!(corners.contains(&point), "{point:?} is not a corner");
+        }
+        assert_eq!(area(&decimated), Scalar::ONE);
+    }
+
+    #[test]
+    fn decimation_stops_at_target() {
+        let mesh = grid(10, |[x, y]| x * y, |_| FaceId(0));
+        let decimated = mesh.decimate(Decimation {
+            target_triangles: Some(50),
+            max_error: None,
+        });
+
+        // Each collapse removes up to two triangles.
+        let num_triangles = decimated.triangles().count();
+        assert!((49..=50).contains(&num_triangles), "{num_triangles}");
+        assert!(MeshAnalysis::of(&decimated)
+            .inconsistently_wound_edges
+            .is_empty());
+    }
+
+    #[test]
+    fn error_stays_within_bound() {
+        let height = |[x, y]: [f64; 2]| (x * x + y * y) / 2.;
+        let mesh = grid(20, height, |_| FaceId(0));
+
+        for max_error in [0.005, 0.02] {
+            let decimated = mesh.decimate(Decimation {
+                target_triangles: None,
+                max_error: Some(Scalar::from(max_error)),
+            });
+            assert!(decimated.triangles().count() < mesh.triangles().count());
+
+            // The grid is a height field, and stays one. Compare the heights
+            // of the original vertices to the decimated mesh.
+            for point in mesh.vertices() {
+                let [x, y, z] = point.coords.components.map(Scalar::into_f64);
+                let decimated_z = decimated
+                    .triangles()
+                    .find_map(|triangle| height_at(triangle.inner, [x, y]))
+                    .unwrap();
+
+                assert!(
+                    (z - decimated_z).abs() <= max_error,
+                    "Error at {point:?} exceeds {max_error}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn boundaries_between_faces_are_preserved() {
+        let color = |face: FaceId| Color([0, 0, face.0 as u8, 255]);
+        let face = |[x, _]: [f64; 2]| FaceId(u64::from(x >= 0.5));
+
+        let mut mesh = grid(10, |_| 0., face);
+        mesh = recolor(&mesh, color);
+        mesh.push_polyline(Polyline {
+            points: (0..=10)
+                .map(|i| Point::from([0.5, f64::from(i) / 10., 0.]))
+                .collect(),
+            half_edge: EdgeId(0),
+            global_edge: EdgeId(0),
+            face: FaceId(0),
+        });
+
+        let decimated = mesh.decimate(Decimation::default());
+
+        // Each face is reduced to its corners, and keeps its color.
+        assert_eq!(decimated.triangles().count(), 4);
+        for triangle in decimated.triangles() {
+            let face = triangle.face.unwrap();
+            assert_eq!(triangle.color, color(face));
+
+            for point in triangle.inner.points() {
+                let x = point.x.into_f64();
+                match face {
+                    FaceId(0) => assert!(x <= 0.5),
+                    _ => assert!(x >= 0.5),
+                }
+            }
+        }
+        for face in [FaceId(0), FaceId(1)] {
+            let triangles = decimated.triangles_of_face(face);
+            let area = triangles.fold(Scalar::ZERO, |area, triangle| {
+                area + triangle.inner.area()
+            });
+            assert_eq!(area, Scalar::from(0.5));
+        }
+
+        // The polyline along the boundary follows its vertices.
+        let polylines = decimated.polylines().collect::<Vec<_>>();
+        assert_eq!(
+            polylines[0].points,
+            [[0.5, 0., 0.], [0.5, 1., 0.]].map(Point::from)
+        );
+    }
+
+    /// A grid of `n` by `n` cells over the unit square
+    ///
+    /// The triangles of each cell are assigned to a face, depending on the
+    /// center of the cell.
+    fn grid(
+        n: u32,
+        height: impl Fn([f64; 2]) -> f64,
+        face: impl Fn([f64; 2]) -> FaceId,
+    ) -> Mesh<Point<3>> {
+        let point = |i: u32, j: u32| {
+            let [x, y] = [i, j].map(|c| f64::from(c) / f64::from(n));
+            Point::from([x, y, height([x, y])])
+        };
+
+        let mut mesh = Mesh::new();
+        for i in 0..n {
+            for j in 0..n {
+                let center =
+                    [i, j].map(|c| (f64::from(c) + 0.5) / f64::from(n));
+                let [a, b, c, d] = [
+                    point(i, j),
+                    point(i + 1, j),
+                    point(i + 1, j + 1),
+                    point(i, j + 1),
+                ];
+
+                for points in [[a, b, c], [a, c, d]] {
+                    let inner = Triangle::from_points(points).unwrap();
+                    mesh.push_mesh_triangle(mesh::Triangle {
+                        inner,
+                        normals: [inner.normal(); 3],
+                        color: Color::default(),
+                        face: Some(face(center)),
+                    });
+                }
+            }
+        }
+
+        mesh
+    }
+
+    fn recolor(
+        mesh: &Mesh<Point<3>>,
+        color: impl Fn(FaceId) -> Color,
+    ) -> Mesh<Point<3>> {
+        let mut recolored = Mesh::new();
+        for triangle in mesh.triangles() {
+            recolored.push_mesh_triangle(mesh::Triangle {
+                color: color(triangle.face.unwrap()),
+                ..triangle
+            });
+        }
+        recolored
+    }
+
+    fn area(mesh: &Mesh<Point<3>>) -> Scalar {
+        mesh.triangles()
+            .fold(Scalar::ZERO, |area, triangle| area + triangle.inner.area())
+    }
+
+    /// The height of a triangle above a point, if the point is within it
+    fn height_at(triangle: Triangle<3>, [x, y]: [f64; 2]) -> Option<f64> {
+        let [a, b, c] = triangle
+            .points()
+            .map(|point| point.coords.components.map(Scalar::into_f64));
+
+        // Barycentric coordinates of the point, within the projection of the
+        // triangle onto the xy-plane
+        let det = (b[1] - c[1]) * (a[0] - c[0]) + (c[0] - b[0]) * (a[1] - c[1]);
+        let u = ((b[1] - c[1]) * (x - c[0]) + (c[0] - b[0]) * (y - c[1])) / det;
+        let v = ((c[1] - a[1]) * (x - c[0]) + (a[0] - c[0]) * (y - c[1])) / det;
+        let w = 1. - u - v;
+
+        let epsilon = 1e-12;
+        if u < -epsilon || v < -epsilon || w < -epsilon {
+            return None;
+        }
+
+        Some(u * a[2] + v * b[2] + w * c[2])
+    }
+}
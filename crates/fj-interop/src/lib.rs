@@ -14,7 +14,10 @@
 
 #![warn(missing_docs)]
 
+pub mod analysis;
+pub mod bvh;
 pub mod debug;
+pub mod decimation;
 pub mod ext;
 pub mod mesh;
 pub mod processed_shape;
@@ -2,7 +2,7 @@
 
 use std::{collections::HashMap, hash::Hash};
 
-use fj_math::Point;
+use fj_math::{Point, Vector};
 
 /// A triangle mesh
 #[derive(Clone, Debug)]
@@ -12,6 +12,7 @@ pub struct Mesh<V> {
 
     indices_by_vertex: HashMap<V, Index>,
     triangles: Vec<Triangle>,
+    polylines: Vec<Polyline>,
 }
 
 impl<V> Mesh<V>
@@ -39,6 +40,11 @@ where
     ///
     /// Returns true, if a triangle with any combination of the provided points
     /// is part of the mesh.
+    ///
+    /// This looks at all triangles of the mesh. If you need to check for many
+    /// triangles, use [`MeshBvh::contains_triangle`] instead.
+    ///
+    /// [`MeshBvh::contains_triangle`]: crate::bvh::MeshBvh::contains_triangle
     pub fn contains_triangle(
         &self,
         triangle: impl Into<fj_math::Triangle<3>>,
@@ -69,26 +75,69 @@ where
     pub fn triangles(&self) -> impl Iterator<Item = Triangle> + '_ {
         self.triangles.iter().copied()
     }
+
+    /// Access the triangles that approximate the face with the given ID
+    pub fn triangles_of_face(
+        &self,
+        face: FaceId,
+    ) -> impl Iterator<Item = Triangle> + '_ {
+        self.triangles()
+            .filter(move |triangle| triangle.face == Some(face))
+    }
+
+    /// Access the polylines that approximate the edges of the mesh
+    pub fn polylines(&self) -> impl Iterator<Item = &Polyline> + '_ {
+        self.polylines.iter()
+    }
 }
 
 impl Mesh<Point<3>> {
     /// Add a triangle to the mesh
+    ///
+    /// The triangle is shaded flat, meaning the normal at each of its corners
+    /// is the normal of the triangle itself.
     pub fn push_triangle(
         &mut self,
         triangle: impl Into<fj_math::Triangle<3>>,
         color: Color,
     ) {
         let triangle = triangle.into();
+        let normal = triangle.normal();
 
-        for point in triangle.points() {
-            self.push_vertex(point);
-        }
+        self.push_triangle_with_normals(triangle, [normal; 3], color);
+    }
 
-        self.triangles.push(Triangle {
-            inner: triangle,
+    /// Add a triangle to the mesh, with a normal for each of its corners
+    ///
+    /// The normals are expected to be normalized. They can differ from the
+    /// normal of the triangle, if the triangle approximates a curved surface.
+    pub fn push_triangle_with_normals(
+        &mut self,
+        triangle: impl Into<fj_math::Triangle<3>>,
+        normals: [Vector<3>; 3],
+        color: Color,
+    ) {
+        self.push_mesh_triangle(Triangle {
+            inner: triangle.into(),
+            normals,
             color,
+            face: None,
         });
     }
+
+    /// Add a triangle to the mesh, including all of its attributes
+    pub fn push_mesh_triangle(&mut self, triangle: Triangle) {
+        for point in triangle.inner.points() {
+            self.push_vertex(point);
+        }
+
+        self.triangles.push(triangle);
+    }
+
+    /// Add a polyline that approximates an edge to the mesh
+    pub fn push_polyline(&mut self, polyline: Polyline) {
+        self.polylines.push(polyline);
+    }
 }
 
 // This needs to be a manual implementation. Deriving `Default` would require
@@ -100,6 +149,7 @@ impl<V> Default for Mesh<V> {
             indices: Vec::default(),
             indices_by_vertex: HashMap::default(),
             triangles: Vec::default(),
+            polylines: Vec::default(),
         }
     }
 }
@@ -109,16 +159,61 @@ pub type Index = u32;
 
 /// A triangle
 ///
-/// Extension of [`fj_math::Triangle`] that also includes a color.
+/// Extension of [`fj_math::Triangle`] that also includes normals, a color, and
+/// the face it approximates.
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
 pub struct Triangle {
     /// The points of the triangle
     pub inner: fj_math::Triangle<3>,
 
+    /// The normals at the corners of the triangle
+    ///
+    /// The order of the normals matches the order of the points.
+    pub normals: [Vector<3>; 3],
+
     /// The color of the triangle
     pub color: Color,
+
+    /// The face that the triangle approximates
+    ///
+    /// This is `None`, if the triangle doesn't come from a face, for example
+    /// if it was part of an imported mesh.
+    pub face: Option<FaceId>,
 }
 
+/// A polyline that approximates an edge of a face
+///
+/// Each edge is shared by two faces, and is approximated once for each of
+/// them. Both polylines refer to the same global edge.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Polyline {
+    /// The points of the polyline, from the start to the end of the edge
+    pub points: Vec<Point<3>>,
+
+    /// The half-edge that the polyline approximates
+    pub half_edge: EdgeId,
+
+    /// The global edge that the half-edge refers to
+    pub global_edge: EdgeId,
+
+    /// The face that the half-edge bounds
+    pub face: FaceId,
+}
+
+/// Identifies a face of the shape that a mesh approximates
+///
+/// IDs are unique within a processed shape. They are not preserved, if the
+/// shape is processed again.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct FaceId(pub u64);
+
+/// Identifies a half-edge or global edge of the shape that a mesh approximates
+///
+/// IDs are unique within a processed shape. They are not preserved, if the
+/// shape is processed again.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct EdgeId(pub u64);
+
 /// RGBA color
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub struct Color(pub [u8; 4]);
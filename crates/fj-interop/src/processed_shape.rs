@@ -1,8 +1,14 @@
 //! A processed shape
 
-use fj_math::{Aabb, Point};
+use std::collections::BTreeMap;
 
-use crate::{debug::DebugInfo, mesh::Mesh};
+use fj_math::{Aabb, Point, Scalar};
+
+use crate::{
+    bvh::MeshBvh,
+    debug::DebugInfo,
+    mesh::{Color, FaceId, Mesh},
+};
 
 /// A processed shape
 #[derive(Clone, Debug)]
@@ -13,6 +19,50 @@ pub struct ProcessedShape {
     /// The triangle mesh that approximates the original shape
     pub mesh: Mesh<Point<3>>,
 
+    /// A bounding volume hierarchy of the triangles of `mesh`
+    pub bvh: MeshBvh,
+
+    /// The faces of the shape
+    ///
+    /// The triangles and polylines of `mesh` refer to these faces by their ID.
+    pub faces: BTreeMap<FaceId, FaceInfo>,
+
     /// The debug info generated while processing the shape
     pub debug_info: DebugInfo,
 }
+
+impl ProcessedShape {
+    /// Access the information about the face with the given ID
+    pub fn face(&self, id: FaceId) -> Option<&FaceInfo> {
+        self.faces.get(&id)
+    }
+}
+
+/// Information about a face of a processed shape
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FaceInfo {
+    /// The color of the face
+    pub color: Color,
+
+    /// The kind of surface that the face is defined in
+    pub surface: SurfaceKind,
+
+    /// The area of the face
+    ///
+    /// This is computed from the triangles that approximate the face, so it is
+    /// only as accurate as the approximation.
+    pub area: Scalar,
+}
+
+/// The kind of surface that a face is defined in
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum SurfaceKind {
+    /// A plane
+    Plane,
+
+    /// A cylindrical surface, swept from a circle
+    Cylinder,
+
+    /// A helical surface, swept from a helix
+    Helical,
+}
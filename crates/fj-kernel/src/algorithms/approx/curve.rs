@@ -11,6 +11,8 @@
 
 use std::collections::BTreeMap;
 
+use fj_math::Point;
+
 use crate::{
     geometry::path::{GlobalPath, SurfacePath},
     objects::{Curve, GlobalCurve},
@@ -63,7 +65,10 @@ fn approx_global_curve(
     // `GlobalPath` grow APIs that are better suited to implementing this code
     // in a more abstract way.
     let points = match (curve.path(), curve.surface().geometry().u) {
-        (SurfacePath::Circle(_), GlobalPath::Circle(_)) => {
+        (
+            SurfacePath::Circle(_),
+            GlobalPath::Circle(_) | GlobalPath::Helix(_),
+        ) => {
             todo!(
                 "Approximating a circle on a curved surface not supported yet."
             )
@@ -102,6 +107,7 @@ fn approx_global_curve(
                     [curve.path().point_from_path_coords(point_curve).u]
                 }));
 
+            let tolerance = tolerance_along_u(curve, line.origin(), tolerance);
             let approx_u = (curve.surface().geometry().u, range_u)
                 .approx_with_cache(tolerance, &mut ());
 
@@ -129,6 +135,38 @@ fn approx_global_curve(
     GlobalCurveApprox { points }
 }
 
+/// Compute the tolerance for approximating the u-axis of a curve's surface
+///
+/// If the u-axis is a helix, the v-axis rotates along with it. Any point that
+/// is not on the u-axis then moves along a helix too, whose radius can be
+/// larger than that of the u-axis. The approximation of the u-axis needs to be
+/// finer in that case, to stay within the tolerance at that point.
+fn tolerance_along_u(
+    curve: &Curve,
+    point: Point<2>,
+    tolerance: impl Into<Tolerance>,
+) -> Tolerance {
+    let tolerance = tolerance.into();
+
+    let GlobalPath::Helix(helix) = curve.surface().geometry().u else {
+        return tolerance;
+    };
+
+    let circle = helix.circle();
+    let axis = circle.a().cross(&circle.b()).normalize();
+
+    let offset = curve.surface().geometry().point_from_surface_coords(point)
+        - circle.center();
+    let radius = (offset - axis * offset.dot(&axis)).magnitude();
+
+    if radius <= helix.radius() {
+        return tolerance;
+    }
+
+    Tolerance::from_scalar(tolerance.inner() * helix.radius() / radius)
+        .expect("Scaling down positive tolerance can't make it invalid")
+}
+
 /// An approximation of a [`Curve`]
 #[derive(Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub struct CurveApprox {
@@ -165,13 +203,24 @@ impl CurveCache {
     }
 
     /// Insert the approximation of a [`GlobalCurve`]
+    ///
+    /// The two half-edges of an edge refer to the same global curve, but
+    /// traverse it in opposite directions. Approximations are stored in the
+    /// same direction, regardless of the direction of the range, so both get
+    /// the same points.
     pub fn insert(
         &mut self,
         handle: Handle<GlobalCurve>,
         range: RangeOnPath,
         approx: GlobalCurveApprox,
     ) -> GlobalCurveApprox {
-        self.inner.insert((handle.id(), range), approx.clone());
+        let (range, stored) = if range.is_reversed() {
+            (range.reverse(), approx.clone().reverse())
+        } else {
+            (range, approx.clone())
+        };
+
+        self.inner.insert((handle.id(), range), stored);
         approx
     }
 
@@ -181,6 +230,12 @@ impl CurveCache {
         handle: Handle<GlobalCurve>,
         range: RangeOnPath,
     ) -> Option<GlobalCurveApprox> {
+        if range.is_reversed() {
+            return self
+                .get(handle, range.reverse())
+                .map(GlobalCurveApprox::reverse);
+        }
+
         self.inner.get(&(handle.id(), range)).cloned()
     }
 }
@@ -192,6 +247,15 @@ pub struct GlobalCurveApprox {
     pub points: Vec<ApproxPoint<1>>,
 }
 
+impl GlobalCurveApprox {
+    /// Reverse the order of the points
+    #[must_use]
+    pub fn reverse(mut self) -> Self {
+        self.points.reverse();
+        self
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::f64::consts::TAU;
@@ -207,7 +271,7 @@ mod tests {
         services::Services,
     };
 
-    use super::CurveApprox;
+    use super::{CurveApprox, CurveCache};
 
     #[test]
     fn approx_line_on_flat_surface() {
@@ -321,4 +385,41 @@ mod tests {
             .collect::<Vec<_>>();
         assert_eq!(approx.points, expected_approx);
     }
+
+    #[test]
+    fn approx_reversed_range_from_cache() {
+        let mut services = Services::new();
+
+        let surface = services.objects.surfaces.xz_plane();
+        let mut curve = PartialCurve {
+            surface: Partial::from(surface),
+            ..Default::default()
+        };
+        curve.update_as_circle_from_radius(1.);
+        let curve = curve
+            .build(&mut services.objects)
+            .insert(&mut services.objects);
+
+        let range = RangeOnPath::from([[0.], [TAU]]);
+        let tolerance = 1.;
+
+        let mut cache = CurveCache::new();
+        let approx = (&curve, range).approx_with_cache(tolerance, &mut cache);
+        let approx_reversed =
+            (&curve, range.reverse()).approx_with_cache(tolerance, &mut cache);
+
+        let points = approx
+            .points
+            .iter()
+            .map(|point| point.global_form)
+            .collect::<Vec<_>>();
+        let mut points_reversed = approx_reversed
+            .points
+            .iter()
+            .map(|point| point.global_form)
+            .collect::<Vec<_>>();
+        points_reversed.reverse();
+
+        assert_eq!(points, points_reversed);
+    }
 }
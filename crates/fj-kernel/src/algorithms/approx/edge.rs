@@ -5,7 +5,10 @@
 //! approximations are usually used to build cycle approximations, and this way,
 //! the caller doesn't have to call with duplicate vertices.
 
-use crate::{objects::HalfEdge, storage::Handle};
+use crate::{
+    objects::HalfEdge,
+    storage::{Handle, ObjectId},
+};
 
 use super::{
     curve::{CurveApprox, CurveCache},
@@ -36,6 +39,8 @@ impl Approx for &Handle<HalfEdge> {
         HalfEdgeApprox {
             first,
             curve_approx,
+            half_edge: self.id(),
+            global_edge: self.global_form().id(),
         }
     }
 }
@@ -48,6 +53,12 @@ pub struct HalfEdgeApprox {
 
     /// The approximation of the edge's curve
     pub curve_approx: CurveApprox,
+
+    /// The ID of the approximated half-edge
+    pub half_edge: ObjectId,
+
+    /// The ID of the global form of the approximated half-edge
+    pub global_edge: ObjectId,
 }
 
 impl HalfEdgeApprox {
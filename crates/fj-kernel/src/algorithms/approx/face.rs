@@ -7,7 +7,9 @@ use std::collections::BTreeSet;
 use fj_interop::mesh::Color;
 
 use crate::{
+    geometry::surface::SurfaceGeometry,
     objects::{Face, FaceSet, Handedness},
+    storage::{Handle, ObjectId},
     validate::ValidationConfig,
 };
 
@@ -63,7 +65,7 @@ impl Approx for &FaceSet {
     }
 }
 
-impl Approx for &Face {
+impl Approx for &Handle<Face> {
     type Approximation = FaceApprox;
     type Cache = CurveCache;
 
@@ -100,6 +102,8 @@ impl Approx for &Face {
             interiors,
             color: self.color(),
             coord_handedness: self.coord_handedness(),
+            surface: self.surface().geometry(),
+            face: self.id(),
         }
     }
 }
@@ -118,6 +122,12 @@ pub struct FaceApprox {
 
     /// The handedness of the approximated face's front-side coordinate system
     pub coord_handedness: Handedness,
+
+    /// The geometry of the surface that the approximated face is defined in
+    pub surface: SurfaceGeometry,
+
+    /// The ID of the approximated face
+    pub face: ObjectId,
 }
 
 impl FaceApprox {
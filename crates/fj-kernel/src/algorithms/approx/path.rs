@@ -30,7 +30,7 @@
 
 use std::iter;
 
-use fj_math::{Circle, Point, Scalar, Sign};
+use fj_math::{Circle, Helix, Point, Scalar, Sign};
 
 use crate::geometry::path::{GlobalPath, SurfacePath};
 
@@ -71,6 +71,9 @@ impl Approx for (GlobalPath, RangeOnPath) {
             GlobalPath::Circle(circle) => {
                 approx_circle(&circle, range, tolerance.into())
             }
+            GlobalPath::Helix(helix) => {
+                approx_helix(&helix, range, tolerance.into())
+            }
             GlobalPath::Line(_) => vec![],
         }
     }
@@ -83,6 +86,21 @@ pub struct RangeOnPath {
     pub boundary: [Point<1>; 2],
 }
 
+impl RangeOnPath {
+    /// Indicate whether the range goes in the negative direction of the path
+    pub fn is_reversed(&self) -> bool {
+        let [a, b] = self.boundary;
+        a > b
+    }
+
+    /// Create a new instance that covers the same range in reverse
+    #[must_use]
+    pub fn reverse(self) -> Self {
+        let [a, b] = self.boundary;
+        Self { boundary: [b, a] }
+    }
+}
+
 impl<T> From<[T; 2]> for RangeOnPath
 where
     T: Into<Point<1>>,
@@ -115,6 +133,31 @@ fn approx_circle<const D: usize>(
     points
 }
 
+/// Approximate a helix
+///
+/// `tolerance` specifies how much the approximation is allowed to deviate
+/// from the helix.
+fn approx_helix(
+    helix: &Helix,
+    range: impl Into<RangeOnPath>,
+    tolerance: Tolerance,
+) -> Vec<(Point<1>, Point<3>)> {
+    let range = range.into();
+
+    // Moving along the axis of the helix doesn't cause any deviation from the
+    // straight segments of the approximation. The helix can be approximated
+    // with the same parameters as the circle it is based on.
+    let params = PathApproxParams::for_circle(&helix.circle(), tolerance);
+    let mut points = Vec::new();
+
+    for point_curve in params.points(range) {
+        let point_global = helix.point_from_helix_coords(point_curve);
+        points.push((point_curve, point_global));
+    }
+
+    points
+}
+
 struct PathApproxParams {
     increment: Scalar,
 }
@@ -148,7 +191,20 @@ impl PathApproxParams {
     ) -> impl Iterator<Item = Point<1>> + '_ {
         let range = range.into();
 
-        let [a, b] = range.boundary.map(|point| point.t / self.increment());
+        let [a, b] = range.boundary.map(|point| {
+            let i = point.t / self.increment();
+
+            // A boundary that is meant to be at a multiple of the increment
+            // can end up slightly off, due to floating point inaccuracy. If
+            // it's past the multiple, we'd generate a point that is all but
+            // identical to the boundary. Snap it back.
+            let rounded = i.round();
+            if (i - rounded).abs() < Scalar::from(1e-9) {
+                rounded
+            } else {
+                i
+            }
+        });
         let direction = (b - a).sign();
         let [min, max] = if a < b { [a, b] } else { [b, a] };
 
@@ -229,12 +285,19 @@ mod tests {
         test_path([[2.], [TAU]], [2., 3.]);
         test_path([[0.], [TAU - 2.]], [1., 2.]);
 
+        // Boundaries that are slightly off from a multiple of the increment
+        // don't result in points that are almost identical to them.
+        test_path([[0.], [TAU + 1e-14]], [1., 2., 3.]);
+        test_path([[-1e-14], [TAU]], [1., 2., 3.]);
+
         // And everything again, but in reverse.
         test_path([[TAU], [0.]], [3., 2., 1.]);
         test_path([[TAU], [1.]], [3., 2., 1.]);
         test_path([[TAU - 1.], [0.]], [3., 2., 1.]);
         test_path([[TAU], [2.]], [3., 2.]);
         test_path([[TAU - 2.], [0.]], [2., 1.]);
+        test_path([[TAU + 1e-14], [0.]], [3., 2., 1.]);
+        test_path([[TAU], [-1e-14]], [3., 2., 1.]);
 
         fn test_path(
             range: impl Into<RangeOnPath>,
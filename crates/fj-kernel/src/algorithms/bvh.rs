@@ -0,0 +1,182 @@
+//! Bounding volume hierarchy of faces
+//!
+//! See [`FaceBvh`].
+
+use fj_math::{Aabb, Bvh, Point, Vector};
+
+use crate::{objects::Face, storage::Handle};
+
+use super::approx::{Approx, Tolerance};
+
+/// A bounding volume hierarchy of faces
+///
+/// Narrows down the faces that a query needs to look at, like the faces that
+/// a ray could hit, or the pairs of faces that could intersect before a
+/// boolean operation.
+///
+/// The AABB of each face is computed from its approximation, and grown by the
+/// tolerance of the approximation. Since curved faces don't currently curve
+/// beyond what their edges define, this includes the whole face.
+#[derive(Clone, Debug, Default)]
+pub struct FaceBvh {
+    bvh: Bvh,
+    faces: Vec<Handle<Face>>,
+}
+
+impl FaceBvh {
+    /// Build a BVH of the provided faces
+    pub fn new<'r>(
+        faces: impl IntoIterator<Item = &'r Handle<Face>>,
+        tolerance: impl Into<Tolerance>,
+    ) -> Self {
+        let tolerance = tolerance.into();
+        let margin = Vector::from([tolerance.inner(); 3]);
+
+        let faces = faces.into_iter().cloned().collect::<Vec<_>>();
+        let bvh = Bvh::new(faces.iter().map(|face| {
+            let aabb = Aabb::<3>::from_points(
+                face.approx(tolerance)
+                    .points()
+                    .into_iter()
+                    .map(|point| point.global_form),
+            );
+
+            Aabb {
+                min: aabb.min - margin,
+                max: aabb.max + margin,
+            }
+        }));
+
+        Self { bvh, faces }
+    }
+
+    /// Access the BVH of the faces
+    ///
+    /// The items of the BVH are the indices of the faces, as they are passed
+    /// to [`FaceBvh::face`].
+    pub fn bvh(&self) -> &Bvh {
+        &self.bvh
+    }
+
+    /// Access a face by its index
+    pub fn face(&self, index: usize) -> &Handle<Face> {
+        &self.faces[index]
+    }
+
+    /// Iterate over the faces whose AABBs contain the point
+    pub fn near_point(
+        &self,
+        point: impl Into<Point<3>>,
+    ) -> impl Iterator<Item = &Handle<Face>> + '_ {
+        self.bvh
+            .containing(point)
+            .into_iter()
+            .map(|index| &self.faces[index])
+    }
+
+    /// Iterate over the faces whose AABBs overlap the provided AABB
+    pub fn overlapping(
+        &self,
+        aabb: &Aabb<3>,
+    ) -> impl Iterator<Item = &Handle<Face>> + '_ {
+        self.bvh
+            .overlapping(aabb)
+            .into_iter()
+            .map(|index| &self.faces[index])
+    }
+
+    /// Iterate over the faces whose AABBs are hit by a ray
+    pub fn along_ray(
+        &self,
+        origin: Point<3>,
+        dir: Vector<3>,
+    ) -> impl Iterator<Item = &Handle<Face>> + '_ {
+        self.bvh
+            .along_ray(origin, dir, f64::INFINITY)
+            .into_iter()
+            .map(|index| &self.faces[index])
+    }
+
+    /// Iterate over the pairs of faces from two BVHs, whose AABBs overlap
+    ///
+    /// Only those pairs of faces can intersect.
+    pub fn overlapping_pairs<'r>(
+        &'r self,
+        other: &'r Self,
+    ) -> impl Iterator<Item = [&'r Handle<Face>; 2]> + 'r {
+        self.bvh
+            .overlapping_pairs(&other.bvh)
+            .into_iter()
+            .map(|[a, b]| [&self.faces[a], &other.faces[b]])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Aabb, Point, Vector};
+
+    use crate::{
+        algorithms::approx::Tolerance,
+        builder::SolidBuilder,
+        insert::Insert,
+        objects::{Face, Solid},
+        partial::{PartialObject, PartialSolid},
+        services::Services,
+        storage::Handle,
+    };
+
+    use super::FaceBvh;
+
+    fn cuboid(services: &mut Services) -> Handle<Solid> {
+        let mut solid = PartialSolid::default();
+        solid.add_cuboid([2., 2., 2.]);
+        solid
+            .build(&mut services.objects)
+            .insert(&mut services.objects)
+    }
+
+    fn faces(solid: &Solid) -> Vec<&Handle<Face>> {
+        solid.shells().flat_map(|shell| shell.faces()).collect()
+    }
+
+    #[test]
+    fn queries() -> anyhow::Result<()> {
+        let mut services = Services::new();
+        let solid = cuboid(&mut services);
+        let bvh = FaceBvh::new(faces(&solid), Tolerance::from_scalar(0.001)?);
+
+        // The center of the cuboid is not close to any of its faces. Its
+        // corners are close to three each.
+        assert_eq!(bvh.near_point([0., 0., 0.]).count(), 0);
+        assert_eq!(bvh.near_point([1., 1., 1.]).count(), 3);
+
+        // A ray along the x-axis passes through two faces, and misses the
+        // others.
+        let origin = Point::from([-2., 0., 0.]);
+        let dir = Vector::from([1., 0., 0.]);
+        assert_eq!(bvh.along_ray(origin, dir).count(), 2);
+
+        let top = Aabb::<3>::from_points([[-2., -2., 0.5], [2., 2., 2.]]);
+        let top_faces = bvh.overlapping(&top).count();
+        assert_eq!(top_faces, 5);
+
+        Ok(())
+    }
+
+    #[test]
+    fn overlapping_pairs() -> anyhow::Result<()> {
+        let mut services = Services::new();
+        let a = cuboid(&mut services);
+        let b = cuboid(&mut services);
+
+        let tolerance = Tolerance::from_scalar(0.001)?;
+        let a = FaceBvh::new(faces(&a), tolerance);
+        let b = FaceBvh::new(faces(&b), tolerance);
+
+        // Each face overlaps the face in the same place, and the four faces
+        // around it.
+        assert_eq!(a.overlapping_pairs(&b).count(), 6 * 5);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,332 @@
+use fj_math::{Scalar, Vector};
+
+use crate::{
+    builder::{CycleBuilder, HalfEdgeBuilder},
+    geometry::path::SurfacePath,
+    insert::Insert,
+    objects::{Cycle, Objects},
+    partial::{Partial, PartialCycle, PartialObject},
+    services::Service,
+    storage::Handle,
+};
+
+use super::{Fillet, FilletError, FilletKind};
+
+impl Fillet for Handle<Cycle> {
+    fn fillet(
+        self,
+        kind: FilletKind,
+        corners: impl Fn(usize) -> bool,
+        objects: &mut Service<Objects>,
+    ) -> Result<Self, FilletError> {
+        if kind.size() <= Scalar::ZERO {
+            return Err(FilletError::InvalidSize { size: kind.size() });
+        }
+
+        let segments = self
+            .half_edges()
+            .map(|half_edge| {
+                let start = half_edge.start_vertex().position();
+
+                let route = match half_edge.curve().path() {
+                    SurfacePath::Line(_) => Route::Line,
+                    SurfacePath::Circle(circle) => {
+                        let [a, b] = half_edge.boundary();
+                        let angle = b.t - a.t;
+
+                        let angle =
+                            if circle.a().cross2d(&circle.b()).is_negative() {
+                                -angle
+                            } else {
+                                angle
+                            };
+
+                        Route::Arc { angle }
+                    }
+                };
+
+                (start, route)
+            })
+            .collect::<Vec<_>>();
+
+        let num_segments = segments.len();
+        if num_segments < 2 {
+            // A cycle that consists of a single half-edge (i.e. a circle) has
+            // no corners.
+            return Ok(self);
+        }
+
+        let treatments = (0..num_segments)
+            .map(|i| {
+                if !corners(i) {
+                    return None;
+                }
+
+                let (prev, prev_route) =
+                    segments[(i + num_segments - 1) % num_segments];
+                let (vertex, route) = segments[i];
+                let (next, _) = segments[(i + 1) % num_segments];
+
+                if prev_route != Route::Line || route != Route::Line {
+                    return None;
+                }
+
+                let incoming = (vertex - prev).normalize();
+                let outgoing = (next - vertex).normalize();
+
+                let turn =
+                    incoming.cross2d(&outgoing).atan2(incoming.dot(&outgoing));
+                if turn.is_zero() {
+                    return None;
+                }
+
+                let distance = match kind {
+                    FilletKind::Round { radius } => {
+                        let half_turn = turn.abs().into_f64() / 2.;
+                        radius * half_turn.tan()
+                    }
+                    FilletKind::Chamfer { distance } => distance,
+                };
+
+                Some(Corner {
+                    incoming,
+                    outgoing,
+                    turn,
+                    distance,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        // Lines that are consumed completely by the treatments of their
+        // corners are left out, instead of becoming half-edges of zero length.
+        let mut consumed_lines = vec![false; num_segments];
+        for (i, (start, route)) in segments.iter().enumerate() {
+            if *route != Route::Line {
+                continue;
+            }
+
+            let (end, _) = segments[(i + 1) % num_segments];
+            let length = (end - *start).magnitude();
+
+            let consumed = [i, (i + 1) % num_segments]
+                .into_iter()
+                .filter_map(|corner| treatments[corner])
+                .fold(Scalar::ZERO, |consumed, corner| {
+                    consumed + corner.distance
+                });
+
+            let remaining = length - consumed;
+            let tolerance = length * Scalar::from_f64(1e-9);
+            if remaining < -tolerance {
+                return Err(FilletError::TooLarge {
+                    length,
+                    required: consumed,
+                });
+            }
+            consumed_lines[i] = remaining <= tolerance;
+        }
+
+        let mut treated_segments = Vec::new();
+        for (i, ((vertex, route), treatment)) in
+            segments.into_iter().zip(treatments).enumerate()
+        {
+            let keep_route = !consumed_lines[i];
+
+            match treatment {
+                Some(corner) => {
+                    let corner_route = match kind {
+                        FilletKind::Round { .. } => {
+                            Route::Arc { angle: corner.turn }
+                        }
+                        FilletKind::Chamfer { .. } => Route::Line,
+                    };
+
+                    treated_segments.push((
+                        vertex - corner.incoming * corner.distance,
+                        corner_route,
+                    ));
+                    if keep_route {
+                        treated_segments.push((
+                            vertex + corner.outgoing * corner.distance,
+                            route,
+                        ));
+                    }
+                }
+                None if keep_route => treated_segments.push((vertex, route)),
+                None => {}
+            }
+        }
+
+        let mut cycle = PartialCycle {
+            surface: Partial::from(self.surface().clone()),
+            ..Default::default()
+        };
+
+        let half_edges = treated_segments
+            .into_iter()
+            .map(|(start, route)| {
+                (cycle.add_half_edge_from_point_to_start(start), route)
+            })
+            .collect::<Vec<_>>();
+        for (mut half_edge, route) in half_edges {
+            match route {
+                Route::Line => half_edge.write().update_as_line_segment(),
+                Route::Arc { angle } => half_edge.write().update_as_arc(angle),
+            }
+        }
+
+        Ok(cycle.build(objects).insert(objects))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Route {
+    Line,
+    Arc { angle: Scalar },
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Corner {
+    incoming: Vector<2>,
+    outgoing: Vector<2>,
+    turn: Scalar,
+    distance: Scalar,
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::{Point, Scalar};
+
+    use crate::{
+        builder::CycleBuilder,
+        geometry::path::SurfacePath,
+        insert::Insert,
+        partial::{Partial, PartialCycle, PartialObject},
+        services::Services,
+    };
+
+    use super::super::{Fillet, FilletKind};
+
+    const SQUARE: [[f64; 2]; 4] = [[0., 0.], [2., 0.], [2., 2.], [0., 2.]];
+
+    #[test]
+    fn chamfer_all_corners() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let mut cycle = PartialCycle {
+            surface: Partial::from(services.objects.surfaces.xy_plane()),
+            ..Default::default()
+        };
+        cycle.update_as_polygon_from_points(SQUARE);
+        let cycle = cycle
+            .build(&mut services.objects)
+            .insert(&mut services.objects);
+
+        let chamfered = cycle.fillet(
+            FilletKind::Chamfer {
+                distance: Scalar::from(0.5),
+            },
+            |_| true,
+            &mut services.objects,
+        )?;
+
+        let starts = chamfered
+            .half_edges()
+            .map(|half_edge| half_edge.start_vertex().position())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            starts,
+            [
+                [0., 0.5],
+                [0.5, 0.],
+                [1.5, 0.],
+                [2., 0.5],
+                [2., 1.5],
+                [1.5, 2.],
+                [0.5, 2.],
+                [0., 1.5],
+            ]
+            .map(Point::from)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn round_selected_corner() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let mut cycle = PartialCycle {
+            surface: Partial::from(services.objects.surfaces.xy_plane()),
+            ..Default::default()
+        };
+        cycle.update_as_polygon_from_points(SQUARE);
+        let cycle = cycle
+            .build(&mut services.objects)
+            .insert(&mut services.objects);
+
+        let rounded = cycle.fillet(
+            FilletKind::Round {
+                radius: Scalar::from(0.5),
+            },
+            |i| i == 2,
+            &mut services.objects,
+        )?;
+
+        let circles = rounded
+            .half_edges()
+            .filter_map(|half_edge| match half_edge.curve().path() {
+                SurfacePath::Circle(circle) => Some(circle),
+                SurfacePath::Line(_) => None,
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(rounded.half_edges().count(), 5);
+        assert_eq!(circles.len(), 1);
+        let circle = circles[0];
+        assert!(
+            circle.center().distance_to(&Point::from([1.5, 1.5]))
+                < Scalar::from(1e-12)
+        );
+        assert!((circle.radius() - 0.5).abs() < Scalar::from(1e-12));
+
+        Ok(())
+    }
+
+    #[test]
+    fn consume_whole_lines() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let mut cycle = PartialCycle {
+            surface: Partial::from(services.objects.surfaces.xy_plane()),
+            ..Default::default()
+        };
+        cycle.update_as_polygon_from_points(SQUARE);
+        let cycle = cycle
+            .build(&mut services.objects)
+            .insert(&mut services.objects);
+
+        // Rounding all corners of the square with half its width leaves
+        // nothing of the lines, turning it into a circle.
+        let rounded = cycle.fillet(
+            FilletKind::Round {
+                radius: Scalar::from(1.),
+            },
+            |_| true,
+            &mut services.objects,
+        )?;
+
+        assert_eq!(rounded.half_edges().count(), 4);
+        for half_edge in rounded.half_edges() {
+            let SurfacePath::Circle(circle) = half_edge.curve().path() else {
+                panic!("Expected only arcs");
+            };
+            assert!(
+                circle.center().distance_to(&Point::from([1., 1.]))
+                    < Scalar::from(1e-12)
+            );
+        }
+
+        Ok(())
+    }
+}
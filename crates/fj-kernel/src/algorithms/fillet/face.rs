@@ -0,0 +1,138 @@
+use crate::{
+    insert::Insert,
+    objects::{Face, Objects},
+    partial::{Partial, PartialFace, PartialObject},
+    services::Service,
+    storage::Handle,
+};
+
+use super::{Fillet, FilletError, FilletKind};
+
+impl Fillet for Handle<Face> {
+    fn fillet(
+        self,
+        kind: FilletKind,
+        corners: impl Fn(usize) -> bool,
+        objects: &mut Service<Objects>,
+    ) -> Result<Self, FilletError> {
+        // Corners are indexed across all cycles of the face, starting with the
+        // exterior.
+        let mut offset = 0;
+        let mut cycles = Vec::new();
+
+        for cycle in self.all_cycles() {
+            let base = offset;
+            offset += cycle.half_edges().count();
+
+            cycles.push(cycle.clone().fillet(
+                kind,
+                |i| corners(base + i),
+                objects,
+            )?);
+        }
+
+        let mut cycles = cycles.into_iter();
+        let exterior = cycles.next().expect("Face must have an exterior cycle");
+
+        let face = PartialFace {
+            exterior: Partial::from(exterior),
+            interiors: cycles.map(Partial::from).collect(),
+            color: Some(self.color()),
+        };
+        Ok(face.build(objects).insert(objects))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Scalar;
+
+    use crate::{
+        algorithms::sweep::Sweep,
+        builder::{CycleBuilder, FaceBuilder},
+        insert::Insert,
+        objects::Shell,
+        partial::{Partial, PartialFace, PartialObject},
+        services::Services,
+    };
+
+    use super::super::{Fillet, FilletError, FilletKind};
+
+    const SQUARE: [[f64; 2]; 4] = [[0., 0.], [2., 0.], [2., 2.], [0., 2.]];
+    const HOLE: [[f64; 2]; 4] =
+        [[0.5, 0.5], [0.5, 1.5], [1.5, 1.5], [1.5, 0.5]];
+
+    #[test]
+    fn swept_shell_is_watertight() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let mut face = PartialFace::default();
+        face.exterior.write().surface =
+            Partial::from(services.objects.surfaces.xy_plane());
+        face.exterior.write().update_as_polygon_from_points(SQUARE);
+        let mut interior = face.add_interior();
+        interior.write().update_as_polygon_from_points(HOLE);
+        let face = face
+            .build(&mut services.objects)
+            .insert(&mut services.objects);
+
+        for kind in [
+            FilletKind::Round {
+                radius: Scalar::from(0.25),
+            },
+            FilletKind::Chamfer {
+                distance: Scalar::from(0.25),
+            },
+        ] {
+            let treated =
+                face.clone().fillet(kind, |_| true, &mut services.objects)?;
+            let num_half_edges = treated
+                .all_cycles()
+                .map(|cycle| cycle.half_edges().count())
+                .sum::<usize>();
+            assert_eq!(num_half_edges, 16);
+
+            let open = Shell::new([treated.clone()]);
+            assert!(!open.is_watertight());
+
+            let shell = treated.sweep([0., 0., 1.], &mut services.objects);
+            assert_eq!(shell.faces().into_iter().count(), 2 + num_half_edges);
+            assert!(shell.is_watertight());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn treatment_too_large() {
+        let mut services = Services::new();
+
+        let mut face = PartialFace::default();
+        face.exterior.write().surface =
+            Partial::from(services.objects.surfaces.xy_plane());
+        face.exterior.write().update_as_polygon_from_points(SQUARE);
+        let face = face
+            .build(&mut services.objects)
+            .insert(&mut services.objects);
+
+        let fillet = |radius: f64, services: &mut Services| {
+            face.clone().fillet(
+                FilletKind::Round {
+                    radius: Scalar::from(radius),
+                },
+                |_| true,
+                &mut services.objects,
+            )
+        };
+
+        assert!(fillet(1., &mut services).is_ok());
+        assert!(matches!(
+            fillet(1.5, &mut services),
+            Err(FilletError::TooLarge { .. })
+        ));
+        assert!(matches!(
+            fillet(0., &mut services),
+            Err(FilletError::InvalidSize { .. })
+        ));
+    }
+}
@@ -0,0 +1,95 @@
+//! Round off or chamfer the corners of planar objects
+//!
+//! Corners are where two half-edges of a cycle meet. If a face with treated
+//! corners is swept, the corners turn into rounded (cylindrical) or chamfered
+//! (planar) faces along the sweep.
+//!
+//! This is the only kind of edge treatment the kernel supports: Only the edges
+//! of a swept solid that run along the path of the sweep can be treated, by
+//! treating the corners of the face before it is swept. Treating arbitrary
+//! edges of an existing solid, like the edges around its caps, is not
+//! supported.
+
+mod cycle;
+mod face;
+
+use fj_math::Scalar;
+
+use crate::{objects::Objects, services::Service};
+
+/// Round off or chamfer the corners of an object
+pub trait Fillet: Sized {
+    /// Treat the selected corners of the object
+    ///
+    /// Corners are indexed by the half-edge that starts at them, in the order
+    /// in which the half-edges appear in their cycle. `corners` is called with
+    /// each index and decides whether that corner is treated.
+    ///
+    /// Only corners between two straight line segments can be treated. Other
+    /// corners, as well as corners between collinear line segments, are left
+    /// as they are.
+    ///
+    /// Returns an error, if the radius or distance of the treatment is not
+    /// positive, or if the treatments of the corners would consume more than
+    /// the length of one of their adjacent line segments.
+    fn fillet(
+        self,
+        kind: FilletKind,
+        corners: impl Fn(usize) -> bool,
+        objects: &mut Service<Objects>,
+    ) -> Result<Self, FilletError>;
+}
+
+/// The treatment that is applied to a corner
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum FilletKind {
+    /// Replace the corner with an arc of the given radius
+    ///
+    /// The arc is tangent to both line segments that form the corner.
+    Round {
+        /// The radius of the arc
+        radius: Scalar,
+    },
+
+    /// Cut off the corner with a straight line
+    ///
+    /// The line starts and ends at the given distance from the corner, on
+    /// each of the line segments that form it.
+    Chamfer {
+        /// The distance from the corner to each end of the line
+        distance: Scalar,
+    },
+}
+
+impl FilletKind {
+    fn size(&self) -> Scalar {
+        match self {
+            Self::Round { radius } => *radius,
+            Self::Chamfer { distance } => *distance,
+        }
+    }
+}
+
+/// An error that can occur when treating corners
+#[derive(Clone, Debug, thiserror::Error)]
+pub enum FilletError {
+    /// The radius or distance of the treatment is not positive
+    #[error("Corner treatment must have a positive size (got {size})")]
+    InvalidSize {
+        /// The radius or distance of the treatment
+        size: Scalar,
+    },
+
+    /// The treatments of the corners don't fit on a line segment
+    #[error(
+        "Corner treatment doesn't fit on line segment (length: {length}; \
+        required: {required})"
+    )]
+    TooLarge {
+        /// The length of the line segment
+        length: Scalar,
+
+        /// The length that the treatments of the adjacent corners require
+        required: Scalar,
+    },
+}
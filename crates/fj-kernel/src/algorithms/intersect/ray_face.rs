@@ -1,10 +1,12 @@
 //! Intersection between a ray and a face, in 3D
 
+use std::f64::consts::PI;
+
 use fj_math::{Plane, Point, Scalar};
 
 use crate::{
     algorithms::intersect::face_point::FacePointIntersection,
-    geometry::path::GlobalPath,
+    geometry::path::{GlobalPath, SurfacePath},
     objects::{Face, HalfEdge, SurfaceVertex},
     storage::Handle,
 };
@@ -18,9 +20,9 @@ impl Intersect for (&HorizontalRayToTheRight<3>, &Handle<Face>) {
         let (ray, face) = self;
 
         let plane = match face.surface().geometry().u {
-            GlobalPath::Circle(_) => todo!(
-                "Casting a ray against a swept circle is not supported yet"
-            ),
+            GlobalPath::Circle(_) | GlobalPath::Helix(_) => {
+                return intersect_curved_surface(ray, face);
+            }
             GlobalPath::Line(line) => Plane::from_parametric(
                 line.origin(),
                 line.direction(),
@@ -106,21 +108,187 @@ impl Intersect for (&HorizontalRayToTheRight<3>, &Handle<Face>) {
             return None;
         }
 
-        let point = Point::from([u, v]);
-        let intersection = match (face, &point).intersect()? {
-            FacePointIntersection::PointIsInsideFace => {
-                RayFaceIntersection::RayHitsFace
-            }
-            FacePointIntersection::PointIsOnEdge(edge) => {
-                RayFaceIntersection::RayHitsEdge(edge)
-            }
-            FacePointIntersection::PointIsOnVertex(vertex) => {
-                RayFaceIntersection::RayHitsVertex(vertex)
+        intersect_face_at(face, Point::from([u, v]))
+    }
+}
+
+/// Intersect a ray with a face whose surface is curved
+///
+/// The surface is parametrized as `c(u) + v(u) * v`, where `c` is the u-axis of
+/// the surface, and `v(u)` is its v-axis at `u`. As the ray's direction is
+/// `(1, 0, 0)`, only the y- and z-components of the ray and the surface need
+/// to match. Eliminating `v` from those two equations leaves one equation in
+/// `u`, which is solved numerically within the range of `u` covered by the
+/// face.
+///
+/// If the ray hits the face more than once, the hit closest to the ray's origin
+/// is returned.
+fn intersect_curved_surface(
+    ray: &HorizontalRayToTheRight<3>,
+    face: &Handle<Face>,
+) -> Option<RayFaceIntersection> {
+    // The angle between neighboring samples. Any roots that are closer
+    // together than that might be missed.
+    const STEP: f64 = PI / 16.;
+
+    let geometry = face.surface().geometry();
+    let origin = ray.origin;
+
+    let axes_at = |u: Scalar| {
+        let c = geometry.point_from_surface_coords([u, Scalar::ZERO]);
+        let v = geometry.v_from_path_coords([u]);
+        (c, v)
+    };
+    let f = |u: Scalar| {
+        let (c, v) = axes_at(u);
+        (origin.y - c.y) * v.z - (origin.z - c.z) * v.y
+    };
+
+    let [min, max] = range_of_u(face)?;
+    let num_steps = ((max - min) / STEP).ceil().max(Scalar::ONE);
+    let samples = (0..=num_steps.into_f64() as usize)
+        .map(|i| min + (max - min) * Scalar::from(i as f64) / num_steps)
+        .collect::<Vec<_>>();
+
+    if samples.iter().all(|&u| {
+        let (_, v) = axes_at(u);
+        v.y == Scalar::ZERO && v.z == Scalar::ZERO
+    }) {
+        // The v-axis is parallel to the ray everywhere. The ray is either
+        // contained in the surface, or doesn't hit it at all.
+        let contained = samples.windows(2).any(|window| {
+            let [a, b] = [window[0], window[1]];
+            let g = |u: Scalar| axes_at(u).0.y - origin.y;
+            let Some(u) = find_root(g, a, b) else {
+                return false;
+            };
+            let distance = (axes_at(u).0.z - origin.z).abs();
+            distance <= Scalar::from(1e-12) * (Scalar::ONE + origin.z.abs())
+        });
+
+        return contained
+            .then_some(RayFaceIntersection::RayHitsFaceAndAreParallel);
+    }
+
+    let mut roots = samples
+        .windows(2)
+        .filter_map(|window| find_root(f, window[0], window[1]))
+        .collect::<Vec<_>>();
+    roots.dedup();
+
+    let mut hits = roots
+        .into_iter()
+        .filter_map(|u| {
+            let (c, v) = axes_at(u);
+
+            // Use the component that is larger in magnitude, for numerical
+            // stability.
+            let v_coord = if v.y.abs() > v.z.abs() {
+                (origin.y - c.y) / v.y
+            } else {
+                (origin.z - c.z) / v.z
+            };
+            let t = c.x + v.x * v_coord - origin.x;
+
+            if t < Scalar::ZERO {
+                // Ray points away from this point on the surface.
+                return None;
             }
-        };
 
-        Some(intersection)
+            Some((t, Point::from([u, v_coord])))
+        })
+        .collect::<Vec<_>>();
+    hits.sort_by_key(|&(t, _)| t);
+
+    hits.into_iter()
+        .find_map(|(_, point)| intersect_face_at(face, point))
+}
+
+/// Compute the range of u-coordinates that a face covers
+fn range_of_u(face: &Handle<Face>) -> Option<[Scalar; 2]> {
+    let mut range: Option<[Scalar; 2]> = None;
+
+    for cycle in face.all_cycles() {
+        for half_edge in cycle.half_edges() {
+            let [min, max] = match half_edge.curve().path() {
+                SurfacePath::Circle(circle) => {
+                    let u = circle.center().u;
+                    let radius = circle.a().magnitude();
+                    [u - radius, u + radius]
+                }
+                SurfacePath::Line(_) => {
+                    let u = half_edge.start_vertex().position().u;
+                    [u, u]
+                }
+            };
+
+            range = Some(match range {
+                Some([a, b]) => [a.min(min), b.max(max)],
+                None => [min, max],
+            });
+        }
+    }
+
+    range
+}
+
+/// Find a root of `f` between `a` and `b`, if `f` changes sign there
+///
+/// A root at `b` is ignored, as that is expected to be checked as part of the
+/// next interval.
+fn find_root(
+    f: impl Fn(Scalar) -> Scalar,
+    mut a: Scalar,
+    mut b: Scalar,
+) -> Option<Scalar> {
+    let mut f_a = f(a);
+    let f_b = f(b);
+
+    if f_a == Scalar::ZERO {
+        return Some(a);
+    }
+    if f_a.sign() == f_b.sign() || f_b == Scalar::ZERO {
+        return None;
+    }
+
+    // Enough iterations to get down to the precision of `f64`.
+    for _ in 0..64 {
+        let m = (a + b) / 2.;
+        let f_m = f(m);
+
+        if f_m == Scalar::ZERO {
+            return Some(m);
+        }
+
+        if f_m.sign() == f_a.sign() {
+            a = m;
+            f_a = f_m;
+        } else {
+            b = m;
+        }
     }
+
+    Some((a + b) / 2.)
+}
+
+/// Determine whether a point in surface coordinates is within a face
+fn intersect_face_at(
+    face: &Handle<Face>,
+    point: Point<2>,
+) -> Option<RayFaceIntersection> {
+    let intersection = match (face, &point).intersect()? {
+        FacePointIntersection::PointIsInsideFace => {
+            RayFaceIntersection::RayHitsFace
+        }
+        FacePointIntersection::PointIsOnEdge(edge) => {
+            RayFaceIntersection::RayHitsEdge(edge)
+        }
+        FacePointIntersection::PointIsOnVertex(vertex) => {
+            RayFaceIntersection::RayHitsVertex(vertex)
+        }
+    };
+
+    Some(intersection)
 }
 
 /// A hit between a ray and a face
@@ -141,6 +309,8 @@ pub enum RayFaceIntersection {
 
 #[cfg(test)]
 mod tests {
+    use std::f64::consts::PI;
+
     use fj_math::Point;
 
     use crate::{
@@ -151,10 +321,13 @@ mod tests {
             },
             transform::TransformObject,
         },
-        builder::CycleBuilder,
+        builder::{CycleBuilder, SurfaceBuilder},
+        geometry::path::GlobalPath,
         insert::Insert,
-        partial::{Partial, PartialFace, PartialObject},
+        objects::Face,
+        partial::{Partial, PartialFace, PartialObject, PartialSurface},
         services::Services,
+        storage::Handle,
     };
 
     #[test]
@@ -344,4 +517,59 @@ mod tests {
 
         assert_eq!((&ray, &face).intersect(), None);
     }
+
+    #[test]
+    fn ray_hits_curved_face() {
+        let mut services = Services::new();
+
+        let face = half_cylinder(&mut services);
+
+        let ray = HorizontalRayToTheRight::from([0., 0.5, 0.]);
+        assert_eq!(
+            (&ray, &face).intersect(),
+            Some(RayFaceIntersection::RayHitsFace)
+        );
+
+        let ray = HorizontalRayToTheRight::from([-2., 0.5, 0.]);
+        assert_eq!(
+            (&ray, &face).intersect(),
+            Some(RayFaceIntersection::RayHitsFace)
+        );
+    }
+
+    #[test]
+    fn ray_misses_curved_face() {
+        let mut services = Services::new();
+
+        let face = half_cylinder(&mut services);
+
+        let ray = HorizontalRayToTheRight::from([0., -0.5, 0.]);
+        assert_eq!((&ray, &face).intersect(), None);
+
+        let ray = HorizontalRayToTheRight::from([0., 0.5, 2.]);
+        assert_eq!((&ray, &face).intersect(), None);
+
+        let ray = HorizontalRayToTheRight::from([2., 0.5, 0.]);
+        assert_eq!((&ray, &face).intersect(), None);
+    }
+
+    fn half_cylinder(services: &mut Services) -> Handle<Face> {
+        let surface = PartialSurface::from_axes(
+            GlobalPath::circle_from_radius(1.),
+            [0., 0., 1.],
+        )
+        .build(&mut services.objects)
+        .insert(&mut services.objects);
+
+        let mut face = PartialFace::default();
+        face.exterior.write().surface = Partial::from(surface);
+        face.exterior.write().update_as_polygon_from_points([
+            [0., -1.],
+            [PI, -1.],
+            [PI, 1.],
+            [0., 1.],
+        ]);
+        face.build(&mut services.objects)
+            .insert(&mut services.objects)
+    }
 }
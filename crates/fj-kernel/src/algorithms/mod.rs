@@ -4,6 +4,8 @@
 //! on their respective purpose.
 
 pub mod approx;
+pub mod bvh;
+pub mod fillet;
 pub mod intersect;
 pub mod reverse;
 pub mod sweep;
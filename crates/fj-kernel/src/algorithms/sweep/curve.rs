@@ -22,7 +22,7 @@ impl Sweep for Handle<Curve> {
         objects: &mut Service<Objects>,
     ) -> Self::Swept {
         match self.surface().geometry().u {
-            GlobalPath::Circle(_) => {
+            GlobalPath::Circle(_) | GlobalPath::Helix(_) => {
                 // Sweeping a `Curve` creates a `Surface`. The u-axis of that
                 // `Surface` is a `GlobalPath`, which we are computing below.
                 // That computation might or might not work with an arbitrary
@@ -1,8 +1,7 @@
-use fj_math::{Scalar, Vector};
+use fj_math::{Point, Scalar, Vector};
 
 use crate::{
     algorithms::{reverse::Reverse, transform::TransformObject},
-    geometry::path::GlobalPath,
     insert::Insert,
     objects::{Face, Objects, Shell},
     partial::{Partial, PartialObject, PartialShell},
@@ -26,16 +25,18 @@ impl Sweep for Handle<Face> {
         let mut faces = Vec::new();
 
         let is_negative_sweep = {
-            let u = match self.surface().geometry().u {
-                GlobalPath::Circle(_) => todo!(
-                    "Sweeping from faces defined in round surfaces is not \
-                    supported"
-                ),
-                GlobalPath::Line(line) => line.direction(),
-            };
-            let v = self.surface().geometry().v;
-
-            let normal = u.cross(&v);
+            // The normal of a curved surface differs from point to point. Any
+            // point on the exterior is as good as any other, as long as the
+            // sweep doesn't cross the surface.
+            let point = self
+                .exterior()
+                .half_edges()
+                .next()
+                .map_or_else(Point::origin, |half_edge| {
+                    half_edge.start_vertex().position()
+                });
+            let normal =
+                self.surface().geometry().normal_from_surface_coords(point);
 
             normal.dot(&path) < Scalar::ZERO
         };
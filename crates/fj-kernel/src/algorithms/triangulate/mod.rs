@@ -3,19 +3,47 @@
 mod delaunay;
 mod polygon;
 
-use fj_interop::mesh::Mesh;
-use fj_math::Point;
+use std::collections::HashMap;
+
+use fj_interop::{
+    debug::{DebugCategory, DebugInfo},
+    mesh::{Color, EdgeId, FaceId, Mesh, Polyline},
+};
+use fj_math::{Point, Scalar, Triangle, Vector};
+
+use crate::{
+    geometry::path::GlobalPath, objects::Handedness, storage::ObjectId,
+};
 
 use self::polygon::Polygon;
 
 use super::approx::{face::FaceApprox, Approx, Tolerance};
 
+/// The angle, in degrees, up to which neighboring flat faces are shaded smooth
+///
+/// Curved surfaces that are approximated by flat faces, like spheres, look
+/// smooth that way. Where flat faces meet at a larger angle, the edge between
+/// them stays sharp.
+const CREASE_ANGLE: f64 = 30.;
+
 /// Triangulate a shape
 pub trait Triangulate: Sized {
     /// Triangulate the shape
     fn triangulate(self) -> Mesh<Point<3>> {
+        self.triangulate_with_debug_info(&mut DebugInfo::new())
+    }
+
+    /// Triangulate the shape, adding debug geometry to the provided debug info
+    ///
+    /// Emits the approximated edges of each face as
+    /// [`DebugCategory::Approximation`], and the triangles that were discarded
+    /// for being outside of their face as [`DebugCategory::Triangulation`].
+    fn triangulate_with_debug_info(
+        self,
+        debug_info: &mut DebugInfo,
+    ) -> Mesh<Point<3>> {
         let mut mesh = Mesh::new();
-        self.triangulate_into_mesh(&mut mesh);
+        self.triangulate_into_mesh(&mut mesh, debug_info);
         mesh
     }
 
@@ -23,7 +51,11 @@ pub trait Triangulate: Sized {
     ///
     /// This is a low-level method, intended for implementation of
     /// `Triangulate`. Most callers should prefer [`Triangulate::triangulate`].
-    fn triangulate_into_mesh(self, mesh: &mut Mesh<Point<3>>);
+    fn triangulate_into_mesh(
+        self,
+        mesh: &mut Mesh<Point<3>>,
+        debug_info: &mut DebugInfo,
+    );
 }
 
 impl<T> Triangulate for (T, Tolerance)
@@ -31,19 +63,81 @@ where
     T: Approx,
     T::Approximation: IntoIterator<Item = FaceApprox>,
 {
-    fn triangulate_into_mesh(self, mesh: &mut Mesh<Point<3>>) {
+    fn triangulate_into_mesh(
+        self,
+        mesh: &mut Mesh<Point<3>>,
+        debug_info: &mut DebugInfo,
+    ) {
         let (approx, tolerance) = self;
 
         let approx = approx.approx(tolerance);
 
+        // The normals of flat faces depend on their neighbors, so all faces
+        // need to be triangulated, before the triangles can be added.
+        let mut triangles = Vec::new();
         for approx in approx {
-            approx.triangulate_into_mesh(mesh);
+            approx.push_polylines(mesh, debug_info);
+            triangles.extend(approx.triangles(debug_info));
         }
+        push_triangles(triangles, mesh);
     }
 }
 
 impl Triangulate for FaceApprox {
-    fn triangulate_into_mesh(self, mesh: &mut Mesh<Point<3>>) {
+    fn triangulate_into_mesh(
+        self,
+        mesh: &mut Mesh<Point<3>>,
+        debug_info: &mut DebugInfo,
+    ) {
+        self.push_polylines(mesh, debug_info);
+        push_triangles(self.triangles(debug_info), mesh);
+    }
+}
+
+impl FaceApprox {
+    fn push_polylines(
+        &self,
+        mesh: &mut Mesh<Point<3>>,
+        debug_info: &mut DebugInfo,
+    ) {
+        for polyline in self.polylines() {
+            debug_info
+                .push(DebugCategory::Approximation, polyline.points.clone());
+            mesh.push_polyline(polyline);
+        }
+    }
+
+    fn polylines(&self) -> Vec<Polyline> {
+        let mut polylines = Vec::new();
+
+        for cycle in [&self.exterior].into_iter().chain(&self.interiors) {
+            let half_edges = &cycle.half_edges;
+
+            // The approximation of a half-edge doesn't include its last point,
+            // which is the first point of the next half-edge in the cycle.
+            for (i, half_edge) in half_edges.iter().enumerate() {
+                let next = &half_edges[(i + 1) % half_edges.len()];
+
+                let points = half_edge
+                    .points()
+                    .into_iter()
+                    .chain([next.first.clone()])
+                    .map(|point| point.global_form)
+                    .collect();
+
+                polylines.push(Polyline {
+                    points,
+                    half_edge: half_edge.half_edge.into(),
+                    global_edge: half_edge.global_edge.into(),
+                    face: self.face.into(),
+                });
+            }
+        }
+
+        polylines
+    }
+
+    fn triangles(self, debug_info: &mut DebugInfo) -> Vec<FaceTriangle> {
         let face_as_polygon = Polygon::new()
             .with_exterior(
                 self.exterior
@@ -59,28 +153,133 @@ impl Triangulate for FaceApprox {
         let mut triangles =
             delaunay::triangulate(cycles, self.coord_handedness);
         triangles.retain(|triangle| {
-            face_as_polygon
-                .contains_triangle(triangle.map(|point| point.point_surface))
+            let contains = face_as_polygon
+                .contains_triangle(triangle.map(|point| point.point_surface));
+
+            if !contains {
+                let [a, b, c] = triangle.map(|point| point.point_global);
+                debug_info.push(DebugCategory::Triangulation, vec![a, b, c, a]);
+            }
+
+            contains
         });
 
-        for triangle in triangles {
-            let points = triangle.map(|point| point.point_global);
-            mesh.push_triangle(points, self.color);
+        // The normals of curved surfaces are computed from the surface, which
+        // is exact. Flat surfaces have the same normal everywhere, which is
+        // left to `push_triangles`.
+        let is_flat = matches!(self.surface.u, GlobalPath::Line(_));
+
+        triangles
+            .into_iter()
+            .map(|triangle| {
+                let normals = (!is_flat).then(|| {
+                    triangle.map(|point| {
+                        let normal = self
+                            .surface
+                            .normal_from_surface_coords(point.point_surface);
+
+                        match self.coord_handedness {
+                            Handedness::LeftHanded => -normal,
+                            Handedness::RightHanded => normal,
+                        }
+                    })
+                });
+
+                FaceTriangle {
+                    points: triangle.map(|point| point.point_global),
+                    normals,
+                    color: self.color,
+                    face: self.face.into(),
+                }
+            })
+            .collect()
+    }
+}
+
+impl From<ObjectId> for FaceId {
+    fn from(id: ObjectId) -> Self {
+        Self(id.0)
+    }
+}
+
+impl From<ObjectId> for EdgeId {
+    fn from(id: ObjectId) -> Self {
+        Self(id.0)
+    }
+}
+
+/// A triangle of a face, before it is added to a mesh
+struct FaceTriangle {
+    points: [Point<3>; 3],
+
+    /// The normals at the corners, or `None`, if the face is flat
+    normals: Option<[Vector<3>; 3]>,
+
+    color: Color,
+    face: FaceId,
+}
+
+/// Add triangles to a mesh, computing the normals of flat faces
+///
+/// Each corner of a flat triangle gets the average normal of all flat
+/// triangles that share the corner's point and meet the triangle at an angle
+/// no larger than [`CREASE_ANGLE`].
+fn push_triangles(triangles: Vec<FaceTriangle>, mesh: &mut Mesh<Point<3>>) {
+    // The cross product of two edges points along the normal of a triangle,
+    // and its length is proportional to the triangle's area. Averaging those
+    // weighs each triangle by its area.
+    let weighted_normal = |[a, b, c]: [Point<3>; 3]| (b - a).cross(&(c - a));
+
+    let mut flat_normals: HashMap<Point<3>, Vec<Vector<3>>> = HashMap::new();
+    for triangle in &triangles {
+        if triangle.normals.is_none() {
+            let normal = weighted_normal(triangle.points);
+            for point in triangle.points {
+                flat_normals.entry(point).or_default().push(normal);
+            }
         }
     }
+
+    let min_cos = Scalar::from_f64(CREASE_ANGLE.to_radians().cos());
+
+    for triangle in triangles {
+        let normals = triangle.normals.unwrap_or_else(|| {
+            let normal = Triangle::from(triangle.points).normal();
+
+            triangle.points.map(|point| {
+                flat_normals[&point]
+                    .iter()
+                    .filter(|other| other.normalize().dot(&normal) >= min_cos)
+                    .fold(Vector::from([0., 0., 0.]), |sum, &other| sum + other)
+                    .normalize()
+            })
+        });
+
+        mesh.push_mesh_triangle(fj_interop::mesh::Triangle {
+            inner: triangle.points.into(),
+            normals,
+            color: triangle.color,
+            face: Some(triangle.face),
+        });
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use fj_interop::mesh::Mesh;
-    use fj_math::{Point, Scalar};
+    use std::collections::BTreeSet;
+
+    use fj_interop::{
+        debug::{DebugCategory, DebugInfo},
+        mesh::{FaceId, Mesh},
+    };
+    use fj_math::{Point, Scalar, Vector};
 
     use crate::{
         algorithms::approx::{Approx, Tolerance},
-        builder::{CycleBuilder, FaceBuilder},
+        builder::{CycleBuilder, FaceBuilder, SolidBuilder},
         insert::Insert,
         objects::Face,
-        partial::{Partial, PartialFace, PartialObject},
+        partial::{Partial, PartialFace, PartialObject, PartialSolid},
         services::Services,
         storage::Handle,
     };
@@ -224,6 +423,140 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn normals_of_curved_surface() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let mut solid = PartialSolid::default();
+        solid.add_cylinder(1., 1.);
+        let solid = solid
+            .build(&mut services.objects)
+            .insert(&mut services.objects);
+
+        let tolerance = Tolerance::from_scalar(0.01)?;
+        let mesh = (&*solid, tolerance).triangulate();
+
+        for triangle in mesh.triangles() {
+            let flat_normal = triangle.inner.normal();
+
+            for (point, normal) in
+                triangle.inner.points().into_iter().zip(triangle.normals)
+            {
+                // The caps are flat. On the side, the normal points away from
+                // the axis.
+                let expected = if flat_normal.z.abs() > Scalar::from_f64(0.5) {
+                    flat_normal
+                } else {
+                    Vector::from([point.x, point.y, Scalar::ZERO])
+                };
+
+                assert!(
+                    (normal - expected).magnitude() < Scalar::from_f64(1e-9)
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn normals_of_flat_faces_with_sharp_edges() {
+        let mut services = Services::new();
+
+        let mut solid = PartialSolid::default();
+        solid.add_cuboid([1., 2., 3.]);
+        let solid = solid
+            .build(&mut services.objects)
+            .insert(&mut services.objects);
+
+        let tolerance = Tolerance::from_scalar(Scalar::ONE).unwrap();
+        let mesh = (&*solid, tolerance).triangulate();
+
+        for triangle in mesh.triangles() {
+            let flat_normal = triangle.inner.normal();
+            assert_eq!(triangle.normals, [flat_normal; 3]);
+        }
+    }
+
+    #[test]
+    fn triangles_and_polylines_refer_to_faces() {
+        let mut services = Services::new();
+
+        let mut solid = PartialSolid::default();
+        solid.add_cuboid([1., 2., 3.]);
+        let solid = solid
+            .build(&mut services.objects)
+            .insert(&mut services.objects);
+
+        let tolerance = Tolerance::from_scalar(Scalar::ONE).unwrap();
+        let mesh = (&*solid, tolerance).triangulate();
+
+        let faces = solid
+            .shells()
+            .flat_map(|shell| shell.faces().into_iter())
+            .map(|face| FaceId::from(face.id()))
+            .collect::<BTreeSet<_>>();
+
+        for triangle in mesh.triangles() {
+            let face = triangle.face.expect("Triangle should have a face");
+            assert!(faces.contains(&face));
+        }
+        for face in &faces {
+            assert_eq!(mesh.triangles_of_face(*face).count(), 2);
+        }
+
+        // Each of the 6 faces of the cuboid is bounded by 4 half-edges.
+        let mut half_edges = BTreeSet::new();
+        for polyline in mesh.polylines() {
+            assert!(faces.contains(&polyline.face));
+            assert_eq!(polyline.points.len(), 2);
+
+            half_edges.insert(polyline.half_edge);
+        }
+        assert_eq!(half_edges.len(), 24);
+    }
+
+    #[test]
+    fn triangulation_emits_debug_geometry() -> anyhow::Result<()> {
+        let mut services = Services::new();
+
+        let mut face = PartialFace::default();
+        face.exterior.write().surface =
+            Partial::from(services.objects.surfaces.xy_plane());
+        face.exterior.write().update_as_polygon_from_points([
+            [0., 0.],
+            [4., 0.],
+            [4., 4.],
+            [0., 4.],
+        ]);
+        face.add_interior().write().update_as_polygon_from_points([
+            [1., 1.],
+            [1., 2.],
+            [3., 3.],
+            [3., 1.],
+        ]);
+        let face = face
+            .build(&mut services.objects)
+            .insert(&mut services.objects);
+
+        let tolerance = Tolerance::from_scalar(Scalar::ONE)?;
+        let mut debug_info = DebugInfo::new();
+        face.approx(tolerance)
+            .triangulate_with_debug_info(&mut debug_info);
+
+        // One polyline per half-edge.
+        let approximation =
+            debug_info.geometry_of(DebugCategory::Approximation);
+        assert_eq!(approximation.count(), 8);
+
+        // The triangles within the hole are discarded.
+        let triangulation =
+            debug_info.geometry_of(DebugCategory::Triangulation);
+        assert!(triangulation.count() > 0);
+
+        Ok(())
+    }
+
     fn triangulate(face: Handle<Face>) -> anyhow::Result<Mesh<Point<3>>> {
         let tolerance = Tolerance::from_scalar(Scalar::ONE)?;
         Ok(face.approx(tolerance).triangulate())
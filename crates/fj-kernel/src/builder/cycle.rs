@@ -76,6 +76,16 @@ pub trait CycleBuilder {
         &mut self,
         points: [impl Into<Point<3>>; 3],
     ) -> [Partial<HalfEdge>; 3];
+
+    /// Update cycle as a planar polygon, from global (3D) points
+    ///
+    /// Uses the first three points to infer a plane that is used as the
+    /// surface. These points must not be collinear, and all other points must
+    /// lie in the same plane.
+    fn update_as_polygon_from_global_points(
+        &mut self,
+        points: impl IntoIterator<Item = impl Into<Point<3>>>,
+    ) -> Vec<Partial<HalfEdge>>;
 }
 
 impl CycleBuilder for PartialCycle {
@@ -197,4 +207,46 @@ impl CycleBuilder for PartialCycle {
 
         half_edges
     }
+
+    fn update_as_polygon_from_global_points(
+        &mut self,
+        points: impl IntoIterator<Item = impl Into<Point<3>>>,
+    ) -> Vec<Partial<HalfEdge>> {
+        let points_global =
+            points.into_iter().map(Into::into).collect::<Vec<_>>();
+        assert!(
+            points_global.len() >= 3,
+            "Polygon must have at least 3 points"
+        );
+
+        let [a, b, c] = [0, 1, 2].map(|i| points_global[i]);
+        let plane_points =
+            self.surface.write().update_as_plane_from_points([a, b, c]);
+
+        // The plane is spanned by the vectors from the first point to the
+        // second and third points. The surface coordinates of the remaining
+        // points are their components along those vectors.
+        let [u, v] = [b - a, c - a];
+        let [uu, uv, vv] = [u.dot(&u), u.dot(&v), v.dot(&v)];
+        let det = uu * vv - uv * uv;
+
+        let mut points_surface = plane_points.to_vec();
+        points_surface.extend(points_global[3..].iter().map(|point| {
+            let w = *point - a;
+            let [uw, vw] = [u.dot(&w), v.dot(&w)];
+
+            Point::from([(vv * uw - uv * vw) / det, (uu * vw - uv * uw) / det])
+        }));
+
+        let half_edges = self.update_as_polygon_from_points(points_surface);
+
+        for (mut half_edge, point) in
+            half_edges.clone().into_iter().zip(points_global)
+        {
+            let [vertex, _] = &mut half_edge.write().vertices;
+            vertex.1.write().global_form.write().position = Some(point);
+        }
+
+        half_edges
+    }
 }
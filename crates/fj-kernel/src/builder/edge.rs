@@ -23,6 +23,14 @@ pub trait HalfEdgeBuilder {
     /// Update partial half-edge to be a circle, from the given radius
     fn update_as_circle_from_radius(&mut self, radius: impl Into<Scalar>);
 
+    /// Update partial half-edge to be a circle, from the given center and
+    /// radius
+    fn update_as_circle_from_center_and_radius(
+        &mut self,
+        center: impl Into<Point<2>>,
+        radius: impl Into<Scalar>,
+    );
+
     /// Update partial half-edge to be an arc, spanning the given angle in
     /// radians
     ///
@@ -66,7 +74,18 @@ impl HalfEdgeBuilder for PartialHalfEdge {
     }
 
     fn update_as_circle_from_radius(&mut self, radius: impl Into<Scalar>) {
-        let path = self.curve.write().update_as_circle_from_radius(radius);
+        self.update_as_circle_from_center_and_radius(Point::origin(), radius);
+    }
+
+    fn update_as_circle_from_center_and_radius(
+        &mut self,
+        center: impl Into<Point<2>>,
+        radius: impl Into<Scalar>,
+    ) {
+        let path = self
+            .curve
+            .write()
+            .update_as_circle_from_center_and_radius(center, radius);
 
         let [a_curve, b_curve] =
             [Scalar::ZERO, Scalar::TAU].map(|coord| Point::from([coord]));
@@ -1,9 +1,560 @@
-use crate::partial::PartialSolid;
+use fj_interop::ext::ArrayExt;
+use fj_math::{Line, Point, Scalar, Vector};
+
+use crate::{
+    algorithms::approx::Tolerance,
+    geometry::path::GlobalPath,
+    objects::Shell,
+    partial::{Partial, PartialShell, PartialSolid, PartialSurface},
+};
+
+use super::{CycleBuilder, HalfEdgeBuilder, ShellBuilder, SurfaceBuilder};
 
 /// Builder API for [`PartialSolid`]
 pub trait SolidBuilder {
-    // No methods are currently defined. This trait serves as a placeholder, to
-    // make it clear where to add such methods, once necessary.
+    /// Add a cuboid of the given size, centered on the origin
+    fn add_cuboid(&mut self, size: impl Into<Vector<3>>) -> Partial<Shell>;
+
+    /// Add a cylinder around the z-axis
+    ///
+    /// The bottom of the cylinder is in the xy-plane, the top is at `height`.
+    fn add_cylinder(
+        &mut self,
+        radius: impl Into<Scalar>,
+        height: impl Into<Scalar>,
+    ) -> Partial<Shell>;
+
+    /// Add a cone around the z-axis
+    ///
+    /// The base of the cone is in the xy-plane, the apex is at `height`.
+    ///
+    /// The surface geometry that is currently supported can't represent the
+    /// side of a cone. The side is approximated by planar triangles instead,
+    /// which deviate from the actual cone no more than `tolerance`.
+    fn add_cone(
+        &mut self,
+        radius: impl Into<Scalar>,
+        height: impl Into<Scalar>,
+        tolerance: impl Into<Tolerance>,
+    ) -> Partial<Shell>;
+
+    /// Add a sphere, centered on the origin
+    ///
+    /// The surface geometry that is currently supported can't represent a
+    /// sphere. The sphere is approximated by planar faces instead, which
+    /// deviate from the actual sphere no more than `tolerance`. Those are
+    /// quadrilaterals between neighboring rings of latitude, and triangles
+    /// around the poles.
+    fn add_sphere(
+        &mut self,
+        radius: impl Into<Scalar>,
+        tolerance: impl Into<Tolerance>,
+    ) -> Partial<Shell>;
+
+    /// Add a torus around the z-axis, centered on the origin
+    ///
+    /// The surface geometry that is currently supported can't represent a
+    /// torus. The torus is approximated by planar quadrilaterals instead,
+    /// which deviate from the actual torus no more than `tolerance`.
+    fn add_torus(
+        &mut self,
+        major_radius: impl Into<Scalar>,
+        minor_radius: impl Into<Scalar>,
+        tolerance: impl Into<Tolerance>,
+    ) -> Partial<Shell>;
 }
 
-impl SolidBuilder for PartialSolid {}
+impl SolidBuilder for PartialSolid {
+    fn add_cuboid(&mut self, size: impl Into<Vector<3>>) -> Partial<Shell> {
+        let size = size.into();
+
+        let x = Vector::from([size.x, Scalar::ZERO, Scalar::ZERO]);
+        let y = Vector::from([Scalar::ZERO, size.y, Scalar::ZERO]);
+        let z = Vector::from([Scalar::ZERO, Scalar::ZERO, size.z]);
+
+        let min = Point::origin() - size / 2.;
+        let max = Point::origin() + size / 2.;
+
+        let mut shell = PartialShell::default();
+
+        // Each side is defined by one of its corners and two of its edges.
+        // The cross product of the edges points outward.
+        for (origin, u, v) in [
+            (min, y, x),
+            (max - x - y, x, y),
+            (min, x, z),
+            (max - x - z, z, x),
+            (min, z, y),
+            (max - y - z, y, z),
+        ] {
+            let surface = PartialSurface::from_axes(
+                GlobalPath::Line(Line::from_origin_and_direction(origin, u)),
+                v,
+            );
+
+            let mut face = shell.add_face();
+            let mut face = face.write();
+
+            face.exterior.write().surface = Partial::from_partial(surface);
+            face.exterior.write().update_as_polygon_from_points([
+                [0., 0.],
+                [1., 0.],
+                [1., 1.],
+                [0., 1.],
+            ]);
+        }
+
+        let shell = Partial::from_partial(shell);
+        self.shells.push(shell.clone());
+        shell
+    }
+
+    fn add_cylinder(
+        &mut self,
+        radius: impl Into<Scalar>,
+        height: impl Into<Scalar>,
+    ) -> Partial<Shell> {
+        let radius = radius.into();
+        let height = height.into();
+
+        let mut shell = PartialShell::default();
+
+        // The bottom and top are defined in planes that are parallel to the
+        // xy-plane, so their circles line up with the circle that defines the
+        // side. The bottom is traversed in the opposite direction, so it faces
+        // outward.
+        let [bottom, top] =
+            [(Scalar::ZERO, true), (height, false)].map(|(z, reverse)| {
+                let surface = PartialSurface::from_axes(
+                    GlobalPath::Line(Line::from_origin_and_direction(
+                        Point::from([Scalar::ZERO, Scalar::ZERO, z]),
+                        Vector::unit_x(),
+                    )),
+                    Vector::unit_y(),
+                );
+
+                let mut face = shell.add_face();
+                let mut face = face.write();
+
+                face.exterior.write().surface = Partial::from_partial(surface);
+                let mut half_edge = face.exterior.write().add_half_edge();
+                half_edge.write().update_as_circle_from_radius(radius);
+
+                if reverse {
+                    let [a, b] = &mut half_edge.write().vertices;
+                    std::mem::swap(&mut a.0, &mut b.0);
+                }
+
+                half_edge
+            });
+
+        // The side is defined in a surface whose u-axis is the circle around
+        // the bottom. The exterior covers the full circle and the full height.
+        {
+            let surface = PartialSurface::from_axes(
+                GlobalPath::circle_from_radius(radius),
+                [Scalar::ZERO, Scalar::ZERO, height],
+            );
+
+            let mut face = shell.add_face();
+            let mut face = face.write();
+
+            face.exterior.write().surface = Partial::from_partial(surface);
+            let [mut side_bottom, right, mut side_top, left] =
+                face.exterior.write().update_as_polygon_from_points([
+                    [Scalar::ZERO, Scalar::ZERO],
+                    [Scalar::TAU, Scalar::ZERO],
+                    [Scalar::TAU, Scalar::ONE],
+                    [Scalar::ZERO, Scalar::ONE],
+                ]);
+
+            // The bottom and top edges of the side need the same curve
+            // coordinates as the circles of the bottom and top faces, so they
+            // can share their global curves.
+            for (half_edge, boundary) in [
+                (&mut side_bottom, [Scalar::ZERO, Scalar::TAU]),
+                (&mut side_top, [Scalar::TAU, Scalar::ZERO]),
+            ] {
+                let mut half_edge = half_edge.write();
+
+                for (vertex, t) in
+                    half_edge.vertices.each_mut_ext().zip_ext(boundary)
+                {
+                    vertex.0 = Some(Point::from([t]));
+                }
+                half_edge.update_as_line_segment();
+            }
+
+            // The left and right edges of the side are the same edge in global
+            // space, the seam of the side. The bottom and top edges of the side
+            // are the same edges as the circles of the bottom and top faces.
+            // Make sure they all refer to the same global objects, or the
+            // approximation ends up with distinct points in the same place.
+            for (from, mut to) in [
+                (&side_bottom, right.clone()),
+                (&left, side_top.clone()),
+                (&side_bottom, bottom.clone()),
+                (&left, top.clone()),
+            ] {
+                let global_form = {
+                    let from = from.read();
+                    let [vertex, _] = &from.vertices;
+                    let global_form = vertex.1.read().global_form.clone();
+                    global_form
+                };
+
+                let [vertex, _] = &mut to.write().vertices;
+                vertex.1.write().global_form = global_form;
+            }
+
+            for (from, mut to) in [
+                (&left, right.clone()),
+                (&side_bottom, bottom.clone()),
+                (&side_top, top.clone()),
+            ] {
+                let global_curve = from.read().curve.read().global_form.clone();
+                to.write().curve.write().global_form = global_curve;
+            }
+
+            for mut half_edge in
+                [side_bottom, right, side_top, left, bottom, top]
+            {
+                half_edge.write().infer_global_form();
+            }
+        }
+
+        let shell = Partial::from_partial(shell);
+        self.shells.push(shell.clone());
+        shell
+    }
+
+    fn add_cone(
+        &mut self,
+        radius: impl Into<Scalar>,
+        height: impl Into<Scalar>,
+        tolerance: impl Into<Tolerance>,
+    ) -> Partial<Shell> {
+        let radius = radius.into();
+        let height = height.into();
+
+        let num_segments = num_segments_for_circle(radius, tolerance.into());
+        let base = ring(radius, Scalar::ZERO, num_segments);
+        let apex = Point::from([Scalar::ZERO, Scalar::ZERO, height]);
+
+        let mut shell = PartialShell::default();
+
+        {
+            // The axes of the base's plane are chosen such that their cross
+            // product points downward, away from the cone.
+            let surface = PartialSurface::from_axes(
+                GlobalPath::x_axis(),
+                -Vector::unit_y(),
+            );
+
+            let mut face = shell.add_face();
+            let mut face = face.write();
+
+            face.exterior.write().surface = Partial::from_partial(surface);
+            face.exterior.write().update_as_polygon_from_points(
+                base.iter()
+                    .rev()
+                    .map(|point| [point.x, -point.y])
+                    .collect::<Vec<_>>(),
+            );
+        }
+
+        add_faceted_surface(&mut shell, &[base, vec![apex]]);
+
+        let shell = Partial::from_partial(shell);
+        self.shells.push(shell.clone());
+        shell
+    }
+
+    fn add_sphere(
+        &mut self,
+        radius: impl Into<Scalar>,
+        tolerance: impl Into<Tolerance>,
+    ) -> Partial<Shell> {
+        let radius = radius.into();
+
+        let num_segments = num_segments_for_circle(radius, tolerance.into());
+        let num_rings = usize::max((num_segments + 1) / 2, 2);
+
+        // The rings go from the bottom pole to the top pole. The poles
+        // themselves are single points.
+        let mut rows = Vec::new();
+        rows.push(vec![Point::from([Scalar::ZERO, Scalar::ZERO, -radius])]);
+        for i in 1..num_rings {
+            let angle =
+                -Scalar::PI / 2. + Scalar::PI * i as f64 / num_rings as f64;
+            rows.push(ring(
+                radius * angle.cos(),
+                radius * angle.sin(),
+                num_segments,
+            ));
+        }
+        rows.push(vec![Point::from([Scalar::ZERO, Scalar::ZERO, radius])]);
+
+        let mut shell = PartialShell::default();
+        add_faceted_surface(&mut shell, &rows);
+
+        let shell = Partial::from_partial(shell);
+        self.shells.push(shell.clone());
+        shell
+    }
+
+    fn add_torus(
+        &mut self,
+        major_radius: impl Into<Scalar>,
+        minor_radius: impl Into<Scalar>,
+        tolerance: impl Into<Tolerance>,
+    ) -> Partial<Shell> {
+        let major_radius = major_radius.into();
+        let minor_radius = minor_radius.into();
+        let tolerance = tolerance.into();
+
+        let num_segments =
+            num_segments_for_circle(major_radius + minor_radius, tolerance);
+        let num_rings = num_segments_for_circle(minor_radius, tolerance);
+
+        // The rings go around the tube, starting and ending at its outside.
+        let mut rows = Vec::new();
+        for i in 0..num_rings {
+            let angle = Scalar::TAU * i as f64 / num_rings as f64;
+            rows.push(ring(
+                major_radius + minor_radius * angle.cos(),
+                minor_radius * angle.sin(),
+                num_segments,
+            ));
+        }
+        rows.push(rows[0].clone());
+
+        let mut shell = PartialShell::default();
+        add_faceted_surface(&mut shell, &rows);
+
+        let shell = Partial::from_partial(shell);
+        self.shells.push(shell.clone());
+        shell
+    }
+}
+
+/// Compute the number of segments needed to approximate a full circle
+fn num_segments_for_circle(radius: Scalar, tolerance: Tolerance) -> usize {
+    // This is the same calculation that is used for the approximation of
+    // circles. See `PathApproxParams::for_circle`.
+    //
+    // If the tolerance is larger than the diameter of the circle, the argument
+    // of `acos` would be out of its domain. Any polygon is within tolerance
+    // then, so the minimum number of segments applies.
+    let ratio = Scalar::min(tolerance.inner() / radius, Scalar::TWO);
+    Scalar::max(Scalar::PI / (Scalar::ONE - ratio).acos(), 3.)
+        .ceil()
+        .into_f64() as usize
+}
+
+/// Create the points of a ring around the z-axis
+///
+/// The points go counter-clockwise, as seen from above.
+fn ring(radius: Scalar, z: Scalar, num_segments: usize) -> Vec<Point<3>> {
+    (0..num_segments)
+        .map(|i| {
+            let angle = Scalar::TAU * i as f64 / num_segments as f64;
+            Point::from([radius * angle.cos(), radius * angle.sin(), z])
+        })
+        .collect()
+}
+
+/// Add planar faces that connect consecutive rows of points to a shell
+///
+/// Each row is a ring of points, as created by [`ring`], or a single point
+/// that the neighboring rows converge to. The rows must be ordered such, that
+/// the surface they describe faces outward, if the rows go upward along it.
+///
+/// Two rings are connected by quadrilaterals. Since all rings are centered on
+/// the z-axis and have their points at the same angles, each of those is an
+/// isosceles trapezoid, and therefore planar. A ring and a single point are
+/// connected by triangles.
+fn add_faceted_surface(shell: &mut PartialShell, rows: &[Vec<Point<3>>]) {
+    for rows in rows.windows(2) {
+        let [a, b] = [&rows[0], &rows[1]];
+        let num_segments = usize::max(a.len(), b.len());
+
+        for i in 0..num_segments {
+            let j = (i + 1) % num_segments;
+
+            let points = match (a.len(), b.len()) {
+                (1, _) => vec![a[0], b[j], b[i]],
+                (_, 1) => vec![a[i], a[j], b[0]],
+                _ => vec![a[i], a[j], b[j], b[i]],
+            };
+
+            let mut face = shell.add_face();
+            face.write()
+                .exterior
+                .write()
+                .update_as_polygon_from_global_points(points);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::analysis::MeshAnalysis;
+    use fj_math::{Point, Scalar, Vector};
+
+    use crate::{
+        algorithms::{approx::Tolerance, triangulate::Triangulate},
+        insert::Insert,
+        partial::{PartialObject, PartialSolid},
+        services::Services,
+    };
+
+    use super::{num_segments_for_circle, SolidBuilder};
+
+    /// Build a solid and check that it is valid
+    ///
+    /// Returns the number of faces of the solid, and the positions of all of
+    /// its vertices.
+    fn build(
+        add: impl FnOnce(&mut PartialSolid),
+    ) -> anyhow::Result<(usize, Vec<Point<3>>)> {
+        let mut services = Services::new();
+
+        let mut solid = PartialSolid::default();
+        add(&mut solid);
+        let solid = solid
+            .build(&mut services.objects)
+            .insert(&mut services.objects);
+
+        let mut num_faces = 0;
+        let mut vertices = Vec::new();
+        for shell in solid.shells() {
+            assert!(shell.is_watertight());
+
+            for face in shell.faces() {
+                num_faces += 1;
+
+                for cycle in face.all_cycles() {
+                    vertices.extend(cycle.half_edges().map(|half_edge| {
+                        half_edge.start_vertex().global_form().position()
+                    }));
+                }
+            }
+        }
+
+        let tolerance = Tolerance::from_scalar(0.01)?;
+        let mesh = (&*solid, tolerance).triangulate();
+
+        let analysis = MeshAnalysis::of(&mesh);
+        assert!(analysis.is_ok(), "{analysis}");
+
+        Ok((num_faces, vertices))
+    }
+
+    fn assert_on_surface(
+        vertices: &[Point<3>],
+        distance_to_surface: impl Fn(&Point<3>) -> Scalar,
+    ) {
+        for vertex in vertices {
+            let distance = distance_to_surface(vertex);
+            assert!(
+                distance.abs() < Scalar::from(1e-12),
+                "{vertex:?} is {distance} away from surface"
+            );
+        }
+    }
+
+    #[test]
+    fn cuboid() -> anyhow::Result<()> {
+        let (num_faces, vertices) = build(|solid| {
+            solid.add_cuboid([1., 2., 3.]);
+        })?;
+
+        assert_eq!(num_faces, 6);
+        assert_on_surface(&vertices, |vertex| {
+            let [x, y, z] = vertex.coords.components.map(Scalar::abs);
+            (x - 0.5).min(y - 1.).min(z - 1.5)
+        });
+
+        Ok(())
+    }
+
+    #[test]
+    fn cylinder() -> anyhow::Result<()> {
+        let (num_faces, _) = build(|solid| {
+            solid.add_cylinder(1., 2.);
+        })?;
+
+        // Bottom, top, and a single face for the side.
+        assert_eq!(num_faces, 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn cone() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(0.01)?;
+        let (num_faces, vertices) = build(|solid| {
+            solid.add_cone(1., 2., tolerance);
+        })?;
+
+        let num_segments = num_segments_for_circle(Scalar::ONE, tolerance);
+        assert_eq!(num_faces, 1 + num_segments);
+        assert_on_surface(&vertices, |vertex| {
+            let radius = vertex.coords.xy().magnitude();
+            let radius_at_height = Scalar::ONE - vertex.z / 2.;
+            radius - radius_at_height
+        });
+
+        Ok(())
+    }
+
+    #[test]
+    fn sphere() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(0.01)?;
+        let (num_faces, vertices) = build(|solid| {
+            solid.add_sphere(1., tolerance);
+        })?;
+
+        // Each ring of latitude is connected to the next one, or the pole, by
+        // one face per segment.
+        let num_segments = num_segments_for_circle(Scalar::ONE, tolerance);
+        let num_rings = (num_segments + 1) / 2;
+        assert_eq!(num_faces, num_rings * num_segments);
+        assert_on_surface(&vertices, |vertex| vertex.coords.magnitude() - 1.);
+
+        Ok(())
+    }
+
+    #[test]
+    fn torus() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(0.01)?;
+        let (num_faces, vertices) = build(|solid| {
+            solid.add_torus(2., 0.5, tolerance);
+        })?;
+
+        let num_segments =
+            num_segments_for_circle(Scalar::from(2.5), tolerance);
+        let num_rings = num_segments_for_circle(Scalar::from(0.5), tolerance);
+        assert_eq!(num_faces, num_rings * num_segments);
+        assert_on_surface(&vertices, |vertex| {
+            let distance_to_axis = vertex.coords.xy().magnitude() - 2.;
+            Vector::from([distance_to_axis, vertex.z]).magnitude() - 0.5
+        });
+
+        Ok(())
+    }
+
+    #[test]
+    fn tolerance_larger_than_diameter() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(10.)?;
+        assert_eq!(num_segments_for_circle(Scalar::ONE, tolerance), 3);
+
+        let (num_faces, _) = build(|solid| {
+            solid.add_sphere(1., tolerance);
+        })?;
+        assert_eq!(num_faces, 2 * 3);
+
+        Ok(())
+    }
+}
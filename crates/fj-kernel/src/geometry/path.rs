@@ -22,7 +22,7 @@
 //! [`Surface`]: crate::objects::Surface
 //! [#1021]: https://github.com/hannobraun/Fornjot/issues/1021
 
-use fj_math::{Circle, Line, Point, Scalar, Transform, Vector};
+use fj_math::{Circle, Helix, Line, Point, Scalar, Transform, Vector};
 
 /// A path through surface (2D) space
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
@@ -83,6 +83,9 @@ pub enum GlobalPath {
     /// A circle
     Circle(Circle<3>),
 
+    /// A helix
+    Helix(Helix),
+
     /// A line
     Line(Line<3>),
 }
@@ -119,6 +122,24 @@ impl GlobalPath {
         Self::Circle(Circle::from_center_and_radius(Point::origin(), radius))
     }
 
+    /// Build a helix around the z-axis from the given radius and pitch
+    ///
+    /// The helix starts in the xy-plane. It winds counter-clockwise, as seen
+    /// from above, and advances along the positive z-axis, if `pitch` is
+    /// positive.
+    pub fn helix_from_radius_and_pitch(
+        radius: impl Into<Scalar>,
+        pitch: impl Into<Scalar>,
+    ) -> Self {
+        let radius = radius.into();
+        let pitch = pitch.into();
+
+        Self::Helix(Helix::new(
+            Circle::from_center_and_radius(Point::origin(), radius),
+            [Scalar::ZERO, Scalar::ZERO, pitch],
+        ))
+    }
+
     /// Construct a line from two points
     ///
     /// Also returns the coordinates of the points on the path.
@@ -133,6 +154,7 @@ impl GlobalPath {
     pub fn origin(&self) -> Point<3> {
         match self {
             Self::Circle(circle) => circle.center() + circle.a(),
+            Self::Helix(helix) => helix.point_from_helix_coords([0.]),
             Self::Line(line) => line.origin(),
         }
     }
@@ -144,6 +166,7 @@ impl GlobalPath {
     ) -> Point<3> {
         match self {
             Self::Circle(circle) => circle.point_from_circle_coords(point),
+            Self::Helix(helix) => helix.point_from_helix_coords(point),
             Self::Line(line) => line.point_from_line_coords(point),
         }
     }
@@ -155,10 +178,36 @@ impl GlobalPath {
     ) -> Vector<3> {
         match self {
             Self::Circle(circle) => circle.vector_from_circle_coords(vector),
+            Self::Helix(helix) => helix.vector_from_helix_coords(vector),
             Self::Line(line) => line.vector_from_line_coords(vector),
         }
     }
 
+    /// Compute the tangent of the path at a point in path coordinates
+    ///
+    /// The tangent points in the direction of increasing path coordinates. It
+    /// is not normalized.
+    pub fn tangent_from_path_coords(
+        &self,
+        point: impl Into<Point<1>>,
+    ) -> Vector<3> {
+        let point = point.into();
+
+        // The vector from the center of a circle to a point on it, rotated by
+        // a quarter turn, is the tangent at that point.
+        let circle_tangent = |circle: &Circle<3>| {
+            circle.vector_from_circle_coords([point.t + Scalar::PI / 2.])
+        };
+
+        match self {
+            Self::Circle(circle) => circle_tangent(circle),
+            Self::Helix(helix) => {
+                circle_tangent(&helix.circle()) + helix.pitch() / Scalar::TAU
+            }
+            Self::Line(line) => line.direction(),
+        }
+    }
+
     /// Transform the path
     #[must_use]
     pub fn transform(self, transform: &Transform) -> Self {
@@ -166,6 +215,9 @@ impl GlobalPath {
             Self::Circle(curve) => {
                 Self::Circle(transform.transform_circle(&curve))
             }
+            Self::Helix(curve) => {
+                Self::Helix(transform.transform_helix(&curve))
+            }
             Self::Line(curve) => Self::Line(transform.transform_line(&curve)),
         }
     }
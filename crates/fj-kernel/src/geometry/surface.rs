@@ -11,6 +11,10 @@ pub struct SurfaceGeometry {
     pub u: GlobalPath,
 
     /// The v-axis of the surface
+    ///
+    /// If the u-axis is a helix, the v-axis rotates around the axis of the
+    /// helix, along with it. This is the v-axis at the origin of the u-axis
+    /// then. See [`SurfaceGeometry::v_from_path_coords`].
     pub v: Vector<3>,
 }
 
@@ -22,10 +26,13 @@ impl SurfaceGeometry {
     ) -> Point<3> {
         let point = point.into();
         self.u.point_from_path_coords([point.u])
-            + self.path_to_line().vector_from_line_coords([point.v])
+            + self.v_from_path_coords([point.u]) * point.v
     }
 
     /// Convert a vector in surface coordinates to model coordinates
+    ///
+    /// If the u-axis is a helix, this uses the v-axis at the origin of the
+    /// u-axis.
     pub fn vector_from_surface_coords(
         &self,
         vector: impl Into<Vector<2>>,
@@ -35,6 +42,49 @@ impl SurfaceGeometry {
             + self.path_to_line().vector_from_line_coords([vector.v])
     }
 
+    /// Compute the normal of the surface at a point in surface coordinates
+    ///
+    /// The normal is the normalized cross product of the surface's u- and
+    /// v-axis at that point. Whether it points to the front or back side of a
+    /// face on the surface, depends on the face's [`Handedness`].
+    ///
+    /// [`Handedness`]: crate::objects::Handedness
+    pub fn normal_from_surface_coords(
+        &self,
+        point: impl Into<Point<2>>,
+    ) -> Vector<3> {
+        let point = point.into();
+        let v = self.v_from_path_coords([point.u]);
+
+        let u = match self.u {
+            GlobalPath::Helix(helix) => {
+                // The v-axis rotates along with the helix. Points that aren't
+                // on the helix itself move faster or slower than it does.
+                let circle = helix.circle();
+                let axis = circle.a().cross(&circle.b()).normalize();
+
+                self.u.tangent_from_path_coords([point.u])
+                    + axis.cross(&v) * point.v
+            }
+            GlobalPath::Circle(_) | GlobalPath::Line(_) => {
+                self.u.tangent_from_path_coords([point.u])
+            }
+        };
+
+        u.cross(&v).normalize()
+    }
+
+    /// Compute the v-axis of the surface at a point on its u-axis
+    ///
+    /// This is the same everywhere, unless the u-axis is a helix. Then the
+    /// v-axis rotates around the axis of the helix, along with it.
+    pub fn v_from_path_coords(&self, point: impl Into<Point<1>>) -> Vector<3> {
+        match self.u {
+            GlobalPath::Helix(helix) => helix.rotate_vector(self.v, point),
+            GlobalPath::Circle(_) | GlobalPath::Line(_) => self.v,
+        }
+    }
+
     fn path_to_line(&self) -> Line<3> {
         Line::from_origin_and_direction(self.u.origin(), self.v)
     }
@@ -50,7 +100,7 @@ impl SurfaceGeometry {
 
 #[cfg(test)]
 mod tests {
-    use fj_math::{Line, Point, Vector};
+    use fj_math::{Line, Point, Scalar, Vector};
     use pretty_assertions::assert_eq;
 
     use crate::geometry::{path::GlobalPath, surface::SurfaceGeometry};
@@ -86,4 +136,47 @@ mod tests {
             Vector::from([0., 4., 8.]),
         );
     }
+
+    #[test]
+    fn normal_from_surface_coords() {
+        let surface = SurfaceGeometry {
+            u: GlobalPath::circle_from_radius(2.),
+            v: Vector::from([0., 0., 1.]),
+        };
+
+        let normal = surface.normal_from_surface_coords([
+            Scalar::PI / 2.,
+            Scalar::from_f64(3.),
+        ]);
+        assert!(
+            (normal - Vector::from([0., 1., 0.])).magnitude()
+                < Scalar::from_f64(1e-12)
+        );
+    }
+
+    #[test]
+    fn helix_surface() {
+        // The v-axis points away from the axis of the helix, and rotates along
+        // with it.
+        let surface = SurfaceGeometry {
+            u: GlobalPath::helix_from_radius_and_pitch(2., 1.),
+            v: Vector::from([1., 0., 0.]),
+        };
+        let point = [Scalar::PI / 2., Scalar::from_f64(0.5)];
+
+        let position = surface.point_from_surface_coords(point);
+        assert!(
+            (position - Point::from([0., 2.5, 0.25])).magnitude()
+                < Scalar::from_f64(1e-12)
+        );
+
+        // The normal is perpendicular to the v-axis, and to the path of the
+        // point, which is a helix with a larger radius.
+        let normal = surface.normal_from_surface_coords(point);
+        let expected = Vector::from([-1. / Scalar::TAU.into_f64(), 0., -2.5]);
+        assert!(
+            (normal - expected.normalize()).magnitude()
+                < Scalar::from_f64(1e-12)
+        );
+    }
 }
@@ -93,6 +93,7 @@ pub mod geometry;
 pub mod insert;
 pub mod objects;
 pub mod partial;
+pub mod serialize;
 pub mod services;
 pub mod storage;
 pub mod validate;
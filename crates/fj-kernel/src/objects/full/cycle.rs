@@ -1,6 +1,5 @@
 use std::slice;
 
-use fj_interop::ext::SliceExt;
 use fj_math::{Scalar, Winding};
 
 use crate::{
@@ -91,10 +90,12 @@ impl Cycle {
 
         let mut sum = Scalar::ZERO;
 
-        for [a, b] in self.half_edges.as_slice().array_windows_ext() {
-            let [a, b] =
-                [a, b].map(|half_edge| half_edge.start_vertex().position());
-
+        let positions = self
+            .half_edges()
+            .map(|half_edge| half_edge.start_vertex().position())
+            .collect::<Vec<_>>();
+        for (i, a) in positions.iter().enumerate() {
+            let b = positions[(i + 1) % positions.len()];
             sum += (b.u - a.u) * (b.v + a.v);
         }
 
@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 use crate::{
     objects::{Face, FaceSet},
     storage::Handle,
@@ -8,7 +10,8 @@ use crate::{
 /// # Implementation Note
 ///
 /// The faces that make up a shell should be closed ("watertight"). This is not
-/// currently validated.
+/// validated when a shell is inserted, but can be checked using
+/// [`Shell::is_watertight`].
 #[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub struct Shell {
     faces: FaceSet,
@@ -31,4 +34,30 @@ impl Shell {
     pub fn find_face(&self, face: &Handle<Face>) -> Option<Handle<Face>> {
         self.faces().find(face)
     }
+
+    /// Indicate whether the shell is watertight
+    ///
+    /// A shell is watertight, if each of its edges is shared by exactly two of
+    /// its half-edges. Edges are identified by the positions of their
+    /// vertices, as faces that were created separately (for example by a
+    /// sweep) don't share the same global curves.
+    pub fn is_watertight(&self) -> bool {
+        let mut num_half_edges = BTreeMap::new();
+
+        for face in self.faces() {
+            for cycle in face.all_cycles() {
+                for half_edge in cycle.half_edges() {
+                    let vertices = half_edge
+                        .global_form()
+                        .vertices()
+                        .access_in_normalized_order()
+                        .map(|vertex| vertex.position());
+
+                    *num_half_edges.entry(vertices).or_insert(0) += 1;
+                }
+            }
+        }
+
+        num_half_edges.values().all(|&num| num == 2)
+    }
 }
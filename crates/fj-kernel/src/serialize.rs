@@ -0,0 +1,778 @@
+//! Serialization of objects
+//!
+//! See [`ObjectGraph`].
+
+use std::collections::HashMap;
+
+use fj_interop::mesh::Color;
+use fj_math::{Circle, Helix, Line, Point, Scalar, Vector};
+
+use crate::{
+    geometry::{
+        path::{GlobalPath, SurfacePath},
+        surface::SurfaceGeometry,
+    },
+    insert::Insert,
+    objects::{
+        Curve, Cycle, Face, GlobalCurve, GlobalEdge, GlobalVertex, HalfEdge,
+        Objects, Shell, Sketch, Solid, Surface, SurfaceVertex,
+    },
+    services::Service,
+    storage::{Handle, ObjectId},
+    validate::ValidationError,
+};
+
+/// A serializable representation of the objects that make up a shape
+///
+/// An `ObjectGraph` is created from a [`Solid`] or [`Sketch`], and contains
+/// all objects that are reachable from there. Every object is stored once, and
+/// objects refer to each other by their position in the graph. This preserves
+/// the identity of objects, meaning objects that are shared between multiple
+/// other objects are still shared, after the graph has been loaded.
+///
+/// Enable the `serde` feature to serialize an `ObjectGraph` using the format
+/// of your choice.
+///
+/// Loading an `ObjectGraph` inserts all of its objects into the provided
+/// [`Service<Objects>`]. Every object is validated before it is inserted, and
+/// loading fails, if any of them is invalid.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ObjectGraph {
+    surfaces: Vec<SurfaceData>,
+    global_vertices: Vec<[f64; 3]>,
+    global_curves: usize,
+    global_edges: Vec<GlobalEdgeData>,
+    surface_vertices: Vec<SurfaceVertexData>,
+    curves: Vec<CurveData>,
+    half_edges: Vec<HalfEdgeData>,
+    cycles: Vec<Vec<usize>>,
+    faces: Vec<FaceData>,
+    shells: Vec<Vec<usize>>,
+    root: Root,
+}
+
+impl ObjectGraph {
+    /// Create an `ObjectGraph` from a solid
+    pub fn from_solid(solid: &Solid) -> Self {
+        let mut builder = GraphBuilder::default();
+        let shells = solid.shells().map(|shell| builder.shell(shell)).collect();
+        builder.finish(Root::Solid(shells))
+    }
+
+    /// Create an `ObjectGraph` from a sketch
+    pub fn from_sketch(sketch: &Sketch) -> Self {
+        let mut builder = GraphBuilder::default();
+        let faces = sketch
+            .faces()
+            .into_iter()
+            .map(|face| builder.face(face))
+            .collect();
+        builder.finish(Root::Sketch(faces))
+    }
+
+    /// Load the solid that the graph was created from
+    ///
+    /// Returns an error, if the graph was created from a sketch, or if the
+    /// graph is malformed or contains invalid objects.
+    pub fn load_solid(
+        &self,
+        objects: &mut Service<Objects>,
+    ) -> Result<Handle<Solid>, LoadError> {
+        let shells = match &self.root {
+            Root::Solid(shells) => shells,
+            Root::Sketch(_) => return Err(LoadError::WrongRoot("solid")),
+        };
+
+        let loaded = self.load(objects)?;
+        let shells = shells
+            .iter()
+            .map(|&index| get(&loaded.shells, index))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        insert(Solid::new(shells), objects)
+    }
+
+    /// Load the sketch that the graph was created from
+    ///
+    /// Returns an error, if the graph was created from a solid, or if the
+    /// graph is malformed or contains invalid objects.
+    pub fn load_sketch(
+        &self,
+        objects: &mut Service<Objects>,
+    ) -> Result<Handle<Sketch>, LoadError> {
+        let faces = match &self.root {
+            Root::Sketch(faces) => faces,
+            Root::Solid(_) => return Err(LoadError::WrongRoot("sketch")),
+        };
+
+        let loaded = self.load(objects)?;
+        let faces = faces
+            .iter()
+            .map(|&index| get(&loaded.faces, index))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        insert(Sketch::new(faces), objects)
+    }
+
+    /// Insert all objects of the graph, except for the root
+    ///
+    /// Objects only refer to objects that come before them in the graph. They
+    /// are inserted in that order, which guarantees that the graph has no
+    /// cycles.
+    fn load(
+        &self,
+        objects: &mut Service<Objects>,
+    ) -> Result<LoadedObjects, LoadError> {
+        let mut loaded = LoadedObjects::default();
+
+        for surface in &self.surfaces {
+            let geometry = SurfaceGeometry {
+                u: surface.u.load()?,
+                v: Vector::from(finite(surface.v)?),
+            };
+            loaded
+                .surfaces
+                .push(insert(Surface::new(geometry), objects)?);
+        }
+
+        for &position in &self.global_vertices {
+            let position = Point::from(finite(position)?);
+            loaded
+                .global_vertices
+                .push(insert(GlobalVertex::new(position), objects)?);
+        }
+
+        for _ in 0..self.global_curves {
+            loaded.global_curves.push(insert(GlobalCurve, objects)?);
+        }
+
+        for global_edge in &self.global_edges {
+            let curve = get(&loaded.global_curves, global_edge.curve)?;
+            let [a, b] = global_edge.vertices;
+            let vertices = [
+                get(&loaded.global_vertices, a)?,
+                get(&loaded.global_vertices, b)?,
+            ];
+            loaded
+                .global_edges
+                .push(insert(GlobalEdge::new(curve, vertices), objects)?);
+        }
+
+        for vertex in &self.surface_vertices {
+            let surface_vertex = SurfaceVertex::new(
+                finite(vertex.position)?,
+                get(&loaded.surfaces, vertex.surface)?,
+                get(&loaded.global_vertices, vertex.global_form)?,
+            );
+            loaded
+                .surface_vertices
+                .push(insert(surface_vertex, objects)?);
+        }
+
+        for curve in &self.curves {
+            let curve = Curve::new(
+                get(&loaded.surfaces, curve.surface)?,
+                curve.path.load()?,
+                get(&loaded.global_curves, curve.global_form)?,
+            );
+            loaded.curves.push(insert(curve, objects)?);
+        }
+
+        for half_edge in &self.half_edges {
+            let [(a, a_vertex), (b, b_vertex)] = half_edge.boundary;
+            let boundary = [
+                (
+                    Point::from(finite([a])?),
+                    get(&loaded.surface_vertices, a_vertex)?,
+                ),
+                (
+                    Point::from(finite([b])?),
+                    get(&loaded.surface_vertices, b_vertex)?,
+                ),
+            ];
+            let half_edge = HalfEdge::new(
+                get(&loaded.curves, half_edge.curve)?,
+                boundary,
+                get(&loaded.global_edges, half_edge.global_form)?,
+            );
+            loaded.half_edges.push(insert(half_edge, objects)?);
+        }
+
+        for half_edges in &self.cycles {
+            if half_edges.is_empty() {
+                return Err(LoadError::EmptyCycle);
+            }
+
+            let half_edges = half_edges
+                .iter()
+                .map(|&index| get(&loaded.half_edges, index))
+                .collect::<Result<Vec<_>, _>>()?;
+            loaded.cycles.push(insert(Cycle::new(half_edges), objects)?);
+        }
+
+        for face in &self.faces {
+            let interiors = face
+                .interiors
+                .iter()
+                .map(|&index| get(&loaded.cycles, index))
+                .collect::<Result<Vec<_>, _>>()?;
+            let face = Face::new(
+                get(&loaded.cycles, face.exterior)?,
+                interiors,
+                Color(face.color),
+            );
+            loaded.faces.push(insert(face, objects)?);
+        }
+
+        for faces in &self.shells {
+            let faces = faces
+                .iter()
+                .map(|&index| get(&loaded.faces, index))
+                .collect::<Result<Vec<_>, _>>()?;
+            loaded.shells.push(insert(Shell::new(faces), objects)?);
+        }
+
+        Ok(loaded)
+    }
+}
+
+/// An error that can occur while loading an [`ObjectGraph`]
+#[derive(Clone, Debug, thiserror::Error)]
+pub enum LoadError {
+    /// The graph was created from a different kind of object
+    #[error("Object graph does not contain a {0}")]
+    WrongRoot(&'static str),
+
+    /// An object refers to an object that doesn't come before it in the graph
+    #[error("Object refers to invalid index {0}")]
+    InvalidIndex(usize),
+
+    /// A number is infinite or NaN
+    #[error("Object graph contains invalid number {0}")]
+    InvalidNumber(f64),
+
+    /// A cycle has no half-edges
+    #[error("Cycle in object graph has no half-edges")]
+    EmptyCycle,
+
+    /// Geometry can't be constructed from the stored values
+    #[error("Object graph contains invalid {0}")]
+    InvalidGeometry(&'static str),
+
+    /// An object in the graph is not valid
+    #[error("Object graph contains invalid object")]
+    Invalid(#[from] ValidationError),
+}
+
+/// Validate an object, then insert it
+///
+/// Inserting an invalid object doesn't fail right away, but leaves a
+/// validation error in the [`Service<Objects>`] that isn't reported as part of
+/// loading.
+fn insert<T: Insert>(
+    object: T,
+    objects: &mut Service<Objects>,
+) -> Result<Handle<T>, LoadError> {
+    object.validate_and_return_first_error()?;
+    Ok(object.insert(objects))
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct SurfaceData {
+    u: GlobalPathData,
+    v: [f64; 3],
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct GlobalEdgeData {
+    curve: usize,
+    vertices: [usize; 2],
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct SurfaceVertexData {
+    position: [f64; 2],
+    surface: usize,
+    global_form: usize,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct CurveData {
+    path: SurfacePathData,
+    surface: usize,
+    global_form: usize,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct HalfEdgeData {
+    curve: usize,
+    boundary: [(f64, usize); 2],
+    global_form: usize,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct FaceData {
+    exterior: usize,
+    interiors: Vec<usize>,
+    color: [u8; 4],
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum Root {
+    Solid(Vec<usize>),
+    Sketch(Vec<usize>),
+}
+
+/// The geometry of a [`SurfacePath`]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum SurfacePathData {
+    Circle {
+        center: [f64; 2],
+        a: [f64; 2],
+        b: [f64; 2],
+    },
+    Line {
+        origin: [f64; 2],
+        direction: [f64; 2],
+    },
+}
+
+impl SurfacePathData {
+    fn load(&self) -> Result<SurfacePath, LoadError> {
+        match *self {
+            Self::Circle { center, a, b } => {
+                Ok(SurfacePath::Circle(load_circle(center, a, b)?))
+            }
+            Self::Line { origin, direction } => {
+                Ok(SurfacePath::Line(load_line(origin, direction)?))
+            }
+        }
+    }
+}
+
+impl From<SurfacePath> for SurfacePathData {
+    fn from(path: SurfacePath) -> Self {
+        match path {
+            SurfacePath::Circle(circle) => Self::Circle {
+                center: coords(circle.center().coords),
+                a: coords(circle.a()),
+                b: coords(circle.b()),
+            },
+            SurfacePath::Line(line) => Self::Line {
+                origin: coords(line.origin().coords),
+                direction: coords(line.direction()),
+            },
+        }
+    }
+}
+
+/// The geometry of a [`GlobalPath`]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum GlobalPathData {
+    Circle {
+        center: [f64; 3],
+        a: [f64; 3],
+        b: [f64; 3],
+    },
+    Helix {
+        center: [f64; 3],
+        a: [f64; 3],
+        b: [f64; 3],
+        pitch: [f64; 3],
+    },
+    Line {
+        origin: [f64; 3],
+        direction: [f64; 3],
+    },
+}
+
+impl GlobalPathData {
+    fn load(&self) -> Result<GlobalPath, LoadError> {
+        match *self {
+            Self::Circle { center, a, b } => {
+                Ok(GlobalPath::Circle(load_circle(center, a, b)?))
+            }
+            Self::Helix {
+                center,
+                a,
+                b,
+                pitch,
+            } => {
+                let circle = load_circle(center, a, b)?;
+                let pitch = finite(pitch)?;
+                Ok(GlobalPath::Helix(Helix::new(circle, pitch)))
+            }
+            Self::Line { origin, direction } => {
+                Ok(GlobalPath::Line(load_line(origin, direction)?))
+            }
+        }
+    }
+}
+
+impl From<GlobalPath> for GlobalPathData {
+    fn from(path: GlobalPath) -> Self {
+        match path {
+            GlobalPath::Circle(circle) => Self::Circle {
+                center: coords(circle.center().coords),
+                a: coords(circle.a()),
+                b: coords(circle.b()),
+            },
+            GlobalPath::Helix(helix) => {
+                let circle = helix.circle();
+                Self::Helix {
+                    center: coords(circle.center().coords),
+                    a: coords(circle.a()),
+                    b: coords(circle.b()),
+                    pitch: coords(helix.pitch()),
+                }
+            }
+            GlobalPath::Line(line) => Self::Line {
+                origin: coords(line.origin().coords),
+                direction: coords(line.direction()),
+            },
+        }
+    }
+}
+
+/// Builds an [`ObjectGraph`], assigning an index to each distinct object
+#[derive(Default)]
+struct GraphBuilder {
+    indices: HashMap<ObjectId, usize>,
+
+    surfaces: Vec<SurfaceData>,
+    global_vertices: Vec<[f64; 3]>,
+    global_curves: usize,
+    global_edges: Vec<GlobalEdgeData>,
+    surface_vertices: Vec<SurfaceVertexData>,
+    curves: Vec<CurveData>,
+    half_edges: Vec<HalfEdgeData>,
+    cycles: Vec<Vec<usize>>,
+    faces: Vec<FaceData>,
+    shells: Vec<Vec<usize>>,
+}
+
+impl GraphBuilder {
+    fn finish(self, root: Root) -> ObjectGraph {
+        ObjectGraph {
+            surfaces: self.surfaces,
+            global_vertices: self.global_vertices,
+            global_curves: self.global_curves,
+            global_edges: self.global_edges,
+            surface_vertices: self.surface_vertices,
+            curves: self.curves,
+            half_edges: self.half_edges,
+            cycles: self.cycles,
+            faces: self.faces,
+            shells: self.shells,
+            root,
+        }
+    }
+
+    fn shell(&mut self, shell: &Handle<Shell>) -> usize {
+        if let Some(&index) = self.indices.get(&shell.id()) {
+            return index;
+        }
+
+        let faces = shell.faces().into_iter().map(|f| self.face(f)).collect();
+        self.shells.push(faces);
+        self.add(shell, self.shells.len())
+    }
+
+    fn face(&mut self, face: &Handle<Face>) -> usize {
+        if let Some(&index) = self.indices.get(&face.id()) {
+            return index;
+        }
+
+        let data = FaceData {
+            exterior: self.cycle(face.exterior()),
+            interiors: face.interiors().map(|c| self.cycle(c)).collect(),
+            color: face.color().0,
+        };
+        self.faces.push(data);
+        self.add(face, self.faces.len())
+    }
+
+    fn cycle(&mut self, cycle: &Handle<Cycle>) -> usize {
+        if let Some(&index) = self.indices.get(&cycle.id()) {
+            return index;
+        }
+
+        let half_edges =
+            cycle.half_edges().map(|h| self.half_edge(h)).collect();
+        self.cycles.push(half_edges);
+        self.add(cycle, self.cycles.len())
+    }
+
+    fn half_edge(&mut self, half_edge: &Handle<HalfEdge>) -> usize {
+        if let Some(&index) = self.indices.get(&half_edge.id()) {
+            return index;
+        }
+
+        let [a, b] = half_edge.boundary();
+        let [a_vertex, b_vertex] = half_edge.surface_vertices();
+        let data = HalfEdgeData {
+            curve: self.curve(half_edge.curve()),
+            boundary: [
+                (a.t.into_f64(), self.surface_vertex(a_vertex)),
+                (b.t.into_f64(), self.surface_vertex(b_vertex)),
+            ],
+            global_form: self.global_edge(half_edge.global_form()),
+        };
+        self.half_edges.push(data);
+        self.add(half_edge, self.half_edges.len())
+    }
+
+    fn curve(&mut self, curve: &Handle<Curve>) -> usize {
+        if let Some(&index) = self.indices.get(&curve.id()) {
+            return index;
+        }
+
+        let data = CurveData {
+            path: curve.path().into(),
+            surface: self.surface(curve.surface()),
+            global_form: self.global_curve(curve.global_form()),
+        };
+        self.curves.push(data);
+        self.add(curve, self.curves.len())
+    }
+
+    fn surface_vertex(&mut self, vertex: &Handle<SurfaceVertex>) -> usize {
+        if let Some(&index) = self.indices.get(&vertex.id()) {
+            return index;
+        }
+
+        let data = SurfaceVertexData {
+            position: coords(vertex.position().coords),
+            surface: self.surface(vertex.surface()),
+            global_form: self.global_vertex(vertex.global_form()),
+        };
+        self.surface_vertices.push(data);
+        self.add(vertex, self.surface_vertices.len())
+    }
+
+    fn global_edge(&mut self, global_edge: &Handle<GlobalEdge>) -> usize {
+        if let Some(&index) = self.indices.get(&global_edge.id()) {
+            return index;
+        }
+
+        let [a, b] = global_edge.vertices().access_in_normalized_order();
+        let data = GlobalEdgeData {
+            curve: self.global_curve(global_edge.curve()),
+            vertices: [self.global_vertex(&a), self.global_vertex(&b)],
+        };
+        self.global_edges.push(data);
+        self.add(global_edge, self.global_edges.len())
+    }
+
+    fn global_curve(&mut self, global_curve: &Handle<GlobalCurve>) -> usize {
+        if let Some(&index) = self.indices.get(&global_curve.id()) {
+            return index;
+        }
+
+        self.global_curves += 1;
+        self.add(global_curve, self.global_curves)
+    }
+
+    fn global_vertex(&mut self, vertex: &Handle<GlobalVertex>) -> usize {
+        if let Some(&index) = self.indices.get(&vertex.id()) {
+            return index;
+        }
+
+        self.global_vertices.push(coords(vertex.position().coords));
+        self.add(vertex, self.global_vertices.len())
+    }
+
+    fn surface(&mut self, surface: &Handle<Surface>) -> usize {
+        if let Some(&index) = self.indices.get(&surface.id()) {
+            return index;
+        }
+
+        let geometry = surface.geometry();
+        self.surfaces.push(SurfaceData {
+            u: geometry.u.into(),
+            v: coords(geometry.v),
+        });
+        self.add(surface, self.surfaces.len())
+    }
+
+    /// Remember the index of an object that was just added to its table
+    fn add<T>(&mut self, handle: &Handle<T>, len: usize) -> usize {
+        let index = len - 1;
+        self.indices.insert(handle.id(), index);
+        index
+    }
+}
+
+/// The objects that have been inserted while loading an [`ObjectGraph`]
+#[derive(Default)]
+struct LoadedObjects {
+    surfaces: Vec<Handle<Surface>>,
+    global_vertices: Vec<Handle<GlobalVertex>>,
+    global_curves: Vec<Handle<GlobalCurve>>,
+    global_edges: Vec<Handle<GlobalEdge>>,
+    surface_vertices: Vec<Handle<SurfaceVertex>>,
+    curves: Vec<Handle<Curve>>,
+    half_edges: Vec<Handle<HalfEdge>>,
+    cycles: Vec<Handle<Cycle>>,
+    faces: Vec<Handle<Face>>,
+    shells: Vec<Handle<Shell>>,
+}
+
+fn get<T>(handles: &[Handle<T>], index: usize) -> Result<Handle<T>, LoadError> {
+    handles
+        .get(index)
+        .cloned()
+        .ok_or(LoadError::InvalidIndex(index))
+}
+
+fn coords<const D: usize>(vector: Vector<D>) -> [f64; D] {
+    vector.components.map(Scalar::into_f64)
+}
+
+fn finite<const D: usize>(values: [f64; D]) -> Result<[f64; D], LoadError> {
+    for value in values {
+        if !value.is_finite() {
+            return Err(LoadError::InvalidNumber(value));
+        }
+    }
+
+    Ok(values)
+}
+
+fn load_circle<const D: usize>(
+    center: [f64; D],
+    a: [f64; D],
+    b: [f64; D],
+) -> Result<Circle<D>, LoadError> {
+    let center = Point::from(finite(center)?);
+    let a = Vector::from(finite(a)?);
+    let b = Vector::from(finite(b)?);
+
+    // Check what `Circle::new` would otherwise panic about.
+    if a.magnitude() != b.magnitude()
+        || a.magnitude() == Scalar::ZERO
+        || a.dot(&b) >= Scalar::from_f64(f64::EPSILON)
+    {
+        return Err(LoadError::InvalidGeometry("circle"));
+    }
+
+    Ok(Circle::new(center, a, b))
+}
+
+fn load_line<const D: usize>(
+    origin: [f64; D],
+    direction: [f64; D],
+) -> Result<Line<D>, LoadError> {
+    let origin = Point::from(finite(origin)?);
+    let direction = Vector::from(finite(direction)?);
+
+    if direction.magnitude() == Scalar::ZERO {
+        return Err(LoadError::InvalidGeometry("line"));
+    }
+
+    Ok(Line::from_origin_and_direction(origin, direction))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        builder::SolidBuilder,
+        insert::Insert,
+        partial::{PartialObject, PartialSolid},
+        services::Services,
+    };
+
+    use super::{LoadError, ObjectGraph};
+
+    #[test]
+    fn solid_survives_round_trip() {
+        let mut services = Services::new();
+
+        let mut solid = PartialSolid::default();
+        solid.add_cuboid([1., 2., 3.]);
+        let solid = solid
+            .build(&mut services.objects)
+            .insert(&mut services.objects);
+
+        let graph = ObjectGraph::from_solid(&solid);
+
+        let mut services = Services::new();
+        let loaded = graph.load_solid(&mut services.objects).unwrap();
+
+        // Objects that are shared in the original are still shared in the
+        // loaded solid, so the graph created from it is the same.
+        assert_eq!(ObjectGraph::from_solid(&loaded), graph);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn graph_survives_serialization() {
+        let mut services = Services::new();
+
+        let mut solid = PartialSolid::default();
+        solid.add_cylinder(1., 2.);
+        let solid = solid
+            .build(&mut services.objects)
+            .insert(&mut services.objects);
+
+        let graph = ObjectGraph::from_solid(&solid);
+        let json = serde_json::to_string(&graph).unwrap();
+        let deserialized: ObjectGraph = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, graph);
+
+        let mut services = Services::new();
+        deserialized.load_solid(&mut services.objects).unwrap();
+    }
+
+    #[test]
+    fn invalid_index_is_rejected() {
+        let mut services = Services::new();
+
+        let mut solid = PartialSolid::default();
+        solid.add_cuboid([1., 1., 1.]);
+        let solid = solid
+            .build(&mut services.objects)
+            .insert(&mut services.objects);
+
+        let mut graph = ObjectGraph::from_solid(&solid);
+        graph.cycles[0][0] = graph.half_edges.len();
+
+        let mut services = Services::new();
+        assert!(matches!(
+            graph.load_solid(&mut services.objects),
+            Err(LoadError::InvalidIndex(_))
+        ));
+    }
+
+    #[test]
+    fn invalid_object_is_rejected() {
+        let mut services = Services::new();
+
+        let mut solid = PartialSolid::default();
+        solid.add_cuboid([1., 1., 1.]);
+        let solid = solid
+            .build(&mut services.objects)
+            .insert(&mut services.objects);
+
+        // The surface vertices that refer to this global vertex no longer
+        // match its position.
+        let mut graph = ObjectGraph::from_solid(&solid);
+        graph.global_vertices[0] = [5., 5., 5.];
+
+        let mut services = Services::new();
+        assert!(matches!(
+            graph.load_solid(&mut services.objects),
+            Err(LoadError::Invalid(_))
+        ));
+    }
+}
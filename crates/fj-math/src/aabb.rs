@@ -1,6 +1,9 @@
-use parry3d_f64::bounding_volume::BoundingVolume as _;
+use parry3d_f64::{
+    bounding_volume::BoundingVolume as _,
+    query::{Ray, RayCast as _},
+};
 
-use super::{Point, Vector};
+use super::{Point, Scalar, Vector};
 
 /// An axis-aligned bounding box (AABB)
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
@@ -126,6 +129,34 @@ impl Aabb<3> {
     pub fn merged(&self, other: &Self) -> Self {
         self.to_parry().merged(&other.to_parry()).into()
     }
+
+    /// Determine whether this AABB overlaps another
+    ///
+    /// AABBs that merely touch are considered to overlap.
+    pub fn intersects(&self, other: &Self) -> bool {
+        self.to_parry().intersects(&other.to_parry())
+    }
+
+    /// Cast a ray against the AABB
+    ///
+    /// Returns the distance along the ray, in multiples of `dir`, at which it
+    /// enters the AABB. If the origin of the ray is within the AABB, that is
+    /// zero.
+    pub fn cast_local_ray(
+        &self,
+        origin: Point<3>,
+        dir: Vector<3>,
+        max_toi: f64,
+    ) -> Option<Scalar> {
+        let ray = Ray {
+            origin: origin.to_na(),
+            dir: dir.to_na(),
+        };
+
+        self.to_parry()
+            .cast_local_ray(&ray, max_toi, true)
+            .map(Into::into)
+    }
 }
 
 impl From<parry2d_f64::bounding_volume::Aabb> for Aabb<2> {
@@ -142,6 +173,8 @@ impl From<parry3d_f64::bounding_volume::Aabb> for Aabb<3> {
 
 #[cfg(test)]
 mod tests {
+    use crate::{Point, Scalar, Vector};
+
     use super::Aabb;
 
     #[test]
@@ -160,4 +193,38 @@ mod tests {
         assert!(!aabb.contains([0., 2.]));
         assert!(!aabb.contains([4., 2.]));
     }
+
+    #[test]
+    fn intersects() {
+        let aabb = Aabb::<3>::from_points([[0., 0., 0.], [2., 2., 2.]]);
+
+        let overlapping = Aabb::<3>::from_points([[1., 1., 1.], [3., 3., 3.]]);
+        let touching = Aabb::<3>::from_points([[2., 0., 0.], [3., 2., 2.]]);
+        let separate = Aabb::<3>::from_points([[3., 0., 0.], [4., 2., 2.]]);
+
+        assert!(aabb.intersects(&overlapping));
+        assert!(aabb.intersects(&touching));
+        assert!(!aabb.intersects(&separate));
+    }
+
+    #[test]
+    fn cast_local_ray() {
+        let aabb = Aabb::<3>::from_points([[1., 1., 1.], [2., 2., 2.]]);
+        let dir = Vector::from([1., 0., 0.]);
+
+        let outside = Point::from([0., 1.5, 1.5]);
+        let inside = Point::from([1.5, 1.5, 1.5]);
+        let missing = Point::from([0., 3., 1.5]);
+
+        assert_eq!(
+            aabb.cast_local_ray(outside, dir, f64::INFINITY),
+            Some(Scalar::ONE)
+        );
+        assert_eq!(
+            aabb.cast_local_ray(inside, dir, f64::INFINITY),
+            Some(Scalar::ZERO)
+        );
+        assert_eq!(aabb.cast_local_ray(missing, dir, f64::INFINITY), None);
+        assert_eq!(aabb.cast_local_ray(outside, dir, 0.5), None);
+    }
 }
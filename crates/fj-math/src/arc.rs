@@ -42,10 +42,10 @@ impl Arc {
             [p0, p1]
         };
 
-        let (uv_factor, end_angle_offset) = if angle_rad > Scalar::PI {
-            (Scalar::from_f64(-1.), Scalar::TAU)
+        let uv_factor = if angle_rad > Scalar::PI {
+            Scalar::from_f64(-1.)
         } else {
-            (Scalar::ONE, Scalar::ZERO)
+            Scalar::ONE
         };
         let [[x0, y0], [x1, y1]] = [p0, p1].map(|p| p.coords.components);
         // https://math.stackexchange.com/questions/27535/how-to-find-center-of-an-arc-given-start-point-end-point-radius-and-arc-direc
@@ -62,7 +62,10 @@ impl Arc {
         let cx = ((x0 + x1) / 2.) - h * v;
         let cy = ((y0 + y1) / 2.) + h * u;
         let start_angle = (y0 - cy).atan2(x0 - cx);
-        let end_angle = (y1 - cy).atan2(x1 - cx) + end_angle_offset;
+        // Deriving the end angle from the start angle, instead of from `p1`,
+        // keeps it greater than the start angle, even if the arc crosses the
+        // negative x-axis.
+        let end_angle = start_angle + angle_rad;
         Self {
             start: p0,
             end: p1,
@@ -143,5 +146,17 @@ mod tests {
             0_f64.to_radians(),
             270_f64.to_radians(),
         );
+        check_arc_calculation(
+            [2., 1.],
+            0.5,
+            135_f64.to_radians(),
+            225_f64.to_radians(),
+        );
+        check_arc_calculation(
+            [2., 1.],
+            0.5,
+            225_f64.to_radians(),
+            135_f64.to_radians(),
+        );
     }
 }
@@ -0,0 +1,399 @@
+use super::{Aabb, Point, Scalar, Vector};
+
+/// The maximum number of items in a leaf node
+const MAX_LEAF_SIZE: usize = 4;
+
+/// A bounding volume hierarchy (BVH)
+///
+/// Organizes items by their AABBs, so queries only need to look at the items
+/// that are close to what they're looking for, instead of at all of them.
+/// Items are identified by the index of their AABB, in the order in which the
+/// AABBs were passed to [`Bvh::new`].
+#[derive(Clone, Debug, Default)]
+pub struct Bvh {
+    nodes: Vec<Node>,
+
+    /// The indices of all items, ordered such that each leaf node refers to a
+    /// contiguous range of them
+    items: Vec<usize>,
+
+    aabbs: Vec<Aabb<3>>,
+}
+
+impl Bvh {
+    /// Build a BVH from the AABBs of the items
+    pub fn new(aabbs: impl IntoIterator<Item = Aabb<3>>) -> Self {
+        let aabbs = aabbs.into_iter().collect::<Vec<_>>();
+        let mut items = (0..aabbs.len()).collect::<Vec<_>>();
+
+        let mut nodes = Vec::new();
+        if !items.is_empty() {
+            build(&aabbs, &mut items, 0, &mut nodes);
+        }
+
+        Self {
+            nodes,
+            items,
+            aabbs,
+        }
+    }
+
+    /// Access the number of items in the BVH
+    pub fn len(&self) -> usize {
+        self.aabbs.len()
+    }
+
+    /// Indicate whether the BVH contains no items
+    pub fn is_empty(&self) -> bool {
+        self.aabbs.is_empty()
+    }
+
+    /// Access the AABB of an item
+    pub fn aabb(&self, item: usize) -> Aabb<3> {
+        self.aabbs[item]
+    }
+
+    /// Find the items whose AABBs contain the point
+    pub fn containing(&self, point: impl Into<Point<3>>) -> Vec<usize> {
+        let point = point.into();
+        self.query(|aabb| aabb.contains(point))
+    }
+
+    /// Find the items whose AABBs overlap the provided AABB
+    pub fn overlapping(&self, aabb: &Aabb<3>) -> Vec<usize> {
+        self.query(|other| other.intersects(aabb))
+    }
+
+    /// Find the items whose AABBs are hit by a ray
+    pub fn along_ray(
+        &self,
+        origin: Point<3>,
+        dir: Vector<3>,
+        max_toi: f64,
+    ) -> Vec<usize> {
+        self.query(|aabb| aabb.cast_local_ray(origin, dir, max_toi).is_some())
+    }
+
+    /// Cast a ray against the items, returning the closest hit
+    ///
+    /// `cast_item` casts the ray against an item, returning the distance
+    /// along the ray at which it hits the item, if it does. It is only called
+    /// for items whose AABBs are hit, and that could be closer than the
+    /// closest hit found so far.
+    ///
+    /// Returns the closest item that was hit, and the distance at which it
+    /// was hit.
+    pub fn cast_ray(
+        &self,
+        origin: Point<3>,
+        dir: Vector<3>,
+        max_toi: f64,
+        mut cast_item: impl FnMut(usize) -> Option<Scalar>,
+    ) -> Option<(usize, Scalar)> {
+        let cast = |aabb: &Aabb<3>| aabb.cast_local_ray(origin, dir, max_toi);
+
+        let mut closest: Option<(usize, Scalar)> = None;
+        let is_closer =
+            |closest: Option<(usize, Scalar)>, toi: Scalar| match closest {
+                Some((_, closest)) => toi < closest,
+                None => true,
+            };
+
+        let mut stack = match self.nodes.first() {
+            Some(root) => match cast(&root.aabb) {
+                Some(toi) => vec![(0, toi)],
+                None => return None,
+            },
+            None => return None,
+        };
+
+        while let Some((index, toi)) = stack.pop() {
+            if !is_closer(closest, toi) {
+                continue;
+            }
+
+            match self.nodes[index].kind {
+                NodeKind::Leaf { start, end } => {
+                    for &item in &self.items[start..end] {
+                        let Some(toi) = cast(&self.aabbs[item]) else {
+                            continue;
+                        };
+                        if !is_closer(closest, toi) {
+                            continue;
+                        }
+
+                        if let Some(toi) = cast_item(item) {
+                            if is_closer(closest, toi) {
+                                closest = Some((item, toi));
+                            }
+                        }
+                    }
+                }
+                NodeKind::Branch { children } => {
+                    let mut children = children
+                        .into_iter()
+                        .filter_map(|child| {
+                            cast(&self.nodes[child].aabb)
+                                .map(|toi| (child, toi))
+                        })
+                        .collect::<Vec<_>>();
+
+                    // The stack is processed from its end, so put the closer
+                    // child last.
+                    children.sort_by_key(|&(_, toi)| -toi);
+                    stack.extend(children);
+                }
+            }
+        }
+
+        closest
+    }
+
+    /// Find all pairs of items from this and another BVH, whose AABBs overlap
+    ///
+    /// Each pair consists of an item of this BVH, followed by an item of the
+    /// other.
+    pub fn overlapping_pairs(&self, other: &Self) -> Vec<[usize; 2]> {
+        let mut pairs = Vec::new();
+
+        if self.is_empty() || other.is_empty() {
+            return pairs;
+        }
+
+        let mut stack = vec![[0, 0]];
+        while let Some([a, b]) = stack.pop() {
+            let [node_a, node_b] = [&self.nodes[a], &other.nodes[b]];
+            if !node_a.aabb.intersects(&node_b.aabb) {
+                continue;
+            }
+
+            match (node_a.kind, node_b.kind) {
+                (
+                    NodeKind::Leaf { start, end },
+                    NodeKind::Leaf {
+                        start: other_start,
+                        end: other_end,
+                    },
+                ) => {
+                    for &item_a in &self.items[start..end] {
+                        for &item_b in &other.items[other_start..other_end] {
+                            if self.aabbs[item_a]
+                                .intersects(&other.aabbs[item_b])
+                            {
+                                pairs.push([item_a, item_b]);
+                            }
+                        }
+                    }
+                }
+                (NodeKind::Branch { children }, NodeKind::Leaf { .. }) => {
+                    stack.extend(children.map(|child| [child, b]));
+                }
+                (NodeKind::Leaf { .. }, NodeKind::Branch { children }) => {
+                    stack.extend(children.map(|child| [a, child]));
+                }
+                (
+                    NodeKind::Branch { children },
+                    NodeKind::Branch {
+                        children: other_children,
+                    },
+                ) => {
+                    for child in children {
+                        stack
+                            .extend(other_children.map(|other| [child, other]));
+                    }
+                }
+            }
+        }
+
+        pairs
+    }
+
+    /// Find the items whose AABBs match a predicate
+    ///
+    /// The predicate must match the AABB of each node that contains a matching
+    /// AABB.
+    fn query(&self, mut predicate: impl FnMut(&Aabb<3>) -> bool) -> Vec<usize> {
+        let mut items = Vec::new();
+
+        let mut stack = if self.is_empty() { vec![] } else { vec![0] };
+        while let Some(index) = stack.pop() {
+            let node = &self.nodes[index];
+            if !predicate(&node.aabb) {
+                continue;
+            }
+
+            match node.kind {
+                NodeKind::Leaf { start, end } => {
+                    items.extend(
+                        self.items[start..end]
+                            .iter()
+                            .copied()
+                            .filter(|&item| predicate(&self.aabbs[item])),
+                    );
+                }
+                NodeKind::Branch { children } => {
+                    stack.extend(children);
+                }
+            }
+        }
+
+        items
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Node {
+    aabb: Aabb<3>,
+    kind: NodeKind,
+}
+
+#[derive(Clone, Copy, Debug)]
+enum NodeKind {
+    /// A node that refers to a range of items
+    Leaf { start: usize, end: usize },
+
+    /// A node with two child nodes
+    Branch { children: [usize; 2] },
+}
+
+/// Build the node for a range of items, and all nodes below it
+///
+/// Returns the index of the node. `offset` is the index of the first item of
+/// the range within all items.
+fn build(
+    aabbs: &[Aabb<3>],
+    items: &mut [usize],
+    offset: usize,
+    nodes: &mut Vec<Node>,
+) -> usize {
+    let aabb = items
+        .iter()
+        .map(|&item| aabbs[item])
+        .reduce(|a, b| a.merged(&b))
+        .expect("Building BVH node from empty range of items");
+
+    let index = nodes.len();
+    nodes.push(Node {
+        aabb,
+        kind: NodeKind::Leaf {
+            start: offset,
+            end: offset + items.len(),
+        },
+    });
+
+    if items.len() <= MAX_LEAF_SIZE {
+        return index;
+    }
+
+    // Split the items in half along the axis on which their centers are
+    // spread the furthest.
+    let centers =
+        Aabb::<3>::from_points(items.iter().map(|&item| aabbs[item].center()));
+    let size = centers.size().components;
+    let axis = (0..3)
+        .max_by_key(|&axis| size[axis])
+        .expect("Range of axes is not empty");
+
+    let middle = items.len() / 2;
+    items.select_nth_unstable_by_key(middle, |&item| {
+        aabbs[item].center().coords.components[axis]
+    });
+
+    let (left, right) = items.split_at_mut(middle);
+    let left = build(aabbs, left, offset, nodes);
+    let right = build(aabbs, right, offset + middle, nodes);
+
+    nodes[index].kind = NodeKind::Branch {
+        children: [left, right],
+    };
+
+    index
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Aabb, Point, Scalar, Vector};
+
+    use super::Bvh;
+
+    /// A row of unit cubes along the x-axis, with gaps between them
+    fn cubes(n: usize) -> Vec<Aabb<3>> {
+        (0..n)
+            .map(|i| {
+                let x = i as f64 * 2.;
+                Aabb::<3>::from_points([[x, 0., 0.], [x + 1., 1., 1.]])
+            })
+            .collect()
+    }
+
+    #[test]
+    fn containing() {
+        let bvh = Bvh::new(cubes(100));
+
+        assert_eq!(bvh.containing([20.5, 0.5, 0.5]), vec![10]);
+        assert_eq!(bvh.containing([21.5, 0.5, 0.5]), vec![]);
+        assert_eq!(Bvh::new([]).containing([0., 0., 0.]), vec![]);
+    }
+
+    #[test]
+    fn overlapping() {
+        let bvh = Bvh::new(cubes(100));
+
+        let aabb = Aabb::<3>::from_points([[19.5, 0., 0.], [24.5, 1., 1.]]);
+        let mut items = bvh.overlapping(&aabb);
+        items.sort();
+
+        assert_eq!(items, vec![10, 11, 12]);
+    }
+
+    #[test]
+    fn cast_ray() {
+        let cubes = cubes(100);
+        let bvh = Bvh::new(cubes.clone());
+
+        let origin = Point::from([100.5, 0.5, 0.5]);
+        let dir = Vector::from([-1., 0., 0.]);
+
+        let mut items = bvh.along_ray(origin, dir, f64::INFINITY);
+        items.sort();
+        assert_eq!(items, (0..=50).collect::<Vec<_>>());
+
+        // Only some cubes are hit by the ray; the others are treated as if
+        // they were hollow.
+        let hit = bvh.cast_ray(origin, dir, f64::INFINITY, |item| {
+            if item % 3 == 0 {
+                cubes[item].cast_local_ray(origin, dir, f64::INFINITY)
+            } else {
+                None
+            }
+        });
+        assert_eq!(hit, Some((48, Scalar::from(3.5))));
+
+        let above = Point::from([100.5, 2., 0.5]);
+        let miss = bvh.cast_ray(above, dir, f64::INFINITY, |_| {
+            panic!("No item should be cast against")
+        });
+        assert_eq!(miss, None);
+    }
+
+    #[test]
+    fn overlapping_pairs() {
+        let a = Bvh::new(cubes(100));
+        // Each of these cubes touches two of the others.
+        let b = Bvh::new(cubes(10).into_iter().map(|aabb| {
+            let offset = Vector::from([1., 0., 0.]);
+            Aabb {
+                min: aabb.min + offset,
+                max: aabb.max + offset,
+            }
+        }));
+
+        let mut pairs = a.overlapping_pairs(&b);
+        pairs.sort();
+
+        let expected = (0..10)
+            .flat_map(|i| [[i, i], [i + 1, i]])
+            .collect::<Vec<_>>();
+        assert_eq!(pairs, expected);
+    }
+}
@@ -0,0 +1,187 @@
+use crate::{Circle, Point, Scalar, Vector};
+
+/// A helix
+///
+/// A helix is defined by a circle that is moved along an axis, while it is
+/// being traversed. The helix coordinate is the angle in radians, just like
+/// the circle coordinate. Per full turn, the helix advances by its pitch.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Helix {
+    circle: Circle<3>,
+    pitch: Vector<3>,
+}
+
+impl Helix {
+    /// Construct a helix from a circle and a pitch
+    ///
+    /// The pitch is the distance, as a vector, that the helix advances per
+    /// full turn. To get a regular helix, it must be perpendicular to the
+    /// plane of the circle.
+    pub fn new(circle: Circle<3>, pitch: impl Into<Vector<3>>) -> Self {
+        let pitch = pitch.into();
+        Self { circle, pitch }
+    }
+
+    /// Access the circle that defines the helix at helix coordinate `0.`
+    pub fn circle(&self) -> Circle<3> {
+        self.circle
+    }
+
+    /// Access the pitch of the helix
+    pub fn pitch(&self) -> Vector<3> {
+        self.pitch
+    }
+
+    /// Access the radius of the helix
+    pub fn radius(&self) -> Scalar {
+        self.circle.radius()
+    }
+
+    /// Create a new instance that is reversed
+    #[must_use]
+    pub fn reverse(self) -> Self {
+        Self {
+            circle: self.circle.reverse(),
+            pitch: -self.pitch,
+        }
+    }
+
+    /// Convert a point in helix coordinates into a 3-dimensional point
+    pub fn point_from_helix_coords(
+        &self,
+        point: impl Into<Point<1>>,
+    ) -> Point<3> {
+        self.circle.center()
+            + self.vector_from_helix_coords(point.into().coords)
+    }
+
+    /// Convert a vector in helix coordinates into a 3-dimensional vector
+    pub fn vector_from_helix_coords(
+        &self,
+        vector: impl Into<Vector<1>>,
+    ) -> Vector<3> {
+        let vector = vector.into();
+        self.circle.vector_from_circle_coords(vector)
+            + self.pitch * (vector.t / Scalar::TAU)
+    }
+
+    /// Rotate a vector around the axis of the helix
+    ///
+    /// The vector is rotated by the angle that corresponds to the provided
+    /// helix coordinate, in the direction in which the helix winds. This is the
+    /// rotation that a shape undergoes, while it moves along the helix.
+    pub fn rotate_vector(
+        &self,
+        vector: impl Into<Vector<3>>,
+        point: impl Into<Point<1>>,
+    ) -> Vector<3> {
+        let vector = vector.into();
+        let angle = point.into().t;
+
+        let a = self.circle.a().normalize();
+        let b = self.circle.b().normalize();
+        let axis = a.cross(&b);
+
+        let [va, vb, v_axis] = [a, b, axis].map(|dir| vector.dot(&dir));
+        let (sin, cos) = angle.sin_cos();
+
+        a * (va * cos - vb * sin) + b * (va * sin + vb * cos) + axis * v_axis
+    }
+}
+
+impl approx::AbsDiffEq for Helix {
+    type Epsilon = <Scalar as approx::AbsDiffEq>::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        Scalar::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.circle.abs_diff_eq(&other.circle, epsilon)
+            && self.pitch.abs_diff_eq(&other.pitch, epsilon)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, PI, TAU};
+
+    use approx::assert_abs_diff_eq;
+
+    use crate::{Circle, Point, Scalar, Vector};
+
+    use super::Helix;
+
+    #[test]
+    fn point_from_helix_coords() {
+        let helix = Helix::new(
+            Circle::from_center_and_radius([0., 0., 1.], 2.),
+            [0., 0., 4.],
+        );
+
+        assert_abs_diff_eq!(
+            helix.point_from_helix_coords([0.]),
+            Point::from([2., 0., 1.]),
+            epsilon = Scalar::from(1e-12),
+        );
+        assert_abs_diff_eq!(
+            helix.point_from_helix_coords([FRAC_PI_2]),
+            Point::from([0., 2., 2.]),
+            epsilon = Scalar::from(1e-12),
+        );
+        assert_abs_diff_eq!(
+            helix.point_from_helix_coords([PI]),
+            Point::from([-2., 0., 3.]),
+            epsilon = Scalar::from(1e-12),
+        );
+        assert_abs_diff_eq!(
+            helix.point_from_helix_coords([TAU]),
+            Point::from([2., 0., 5.]),
+            epsilon = Scalar::from(1e-12),
+        );
+    }
+
+    #[test]
+    fn rotate_vector() {
+        let helix = Helix::new(
+            Circle::from_center_and_radius([0., 0., 1.], 2.),
+            [0., 0., 4.],
+        );
+
+        assert_abs_diff_eq!(
+            helix.rotate_vector([1., 0., 1.], [FRAC_PI_2]),
+            Vector::from([0., 1., 1.]),
+            epsilon = Scalar::from(1e-12),
+        );
+        assert_abs_diff_eq!(
+            helix.rotate_vector([1., 0., 1.], [PI]),
+            Vector::from([-1., 0., 1.]),
+            epsilon = Scalar::from(1e-12),
+        );
+
+        // Vectors along the axis are not affected by the rotation.
+        let pitch = helix.pitch();
+        assert_abs_diff_eq!(
+            helix.rotate_vector(pitch, [1.]),
+            pitch,
+            epsilon = Scalar::from(1e-12),
+        );
+    }
+
+    #[test]
+    fn reverse() {
+        let helix = Helix::new(
+            Circle::from_center_and_radius([0., 0., 0.], 1.),
+            [0., 0., 1.],
+        );
+        let reversed = helix.reverse();
+
+        for t in [0., 1., 2., 7.] {
+            assert_abs_diff_eq!(
+                helix.point_from_helix_coords([t]),
+                reversed.point_from_helix_coords([-t]),
+                epsilon = Scalar::from(1e-12),
+            );
+        }
+    }
+}
@@ -0,0 +1,221 @@
+//! Convex hulls of point sets
+//!
+//! The algorithms in this module rely on the predicates in [`crate::robust`],
+//! so their results don't depend on floating-point rounding errors. Both return
+//! indices into the provided points, which allows callers to relate the hull
+//! back to wherever the points came from.
+
+use std::collections::BTreeSet;
+
+use crate::{robust, Point, Scalar};
+
+/// Compute the convex hull of a set of 2-dimensional points
+///
+/// Returns the indices of the points that make up the hull, in
+/// counterclockwise order. Points that lie on an edge of the hull, without
+/// being one of its corners, are not included. Duplicate points are only
+/// included once.
+///
+/// If all points are collinear, the returned hull is degenerate and only
+/// consists of the two outermost points.
+pub fn convex_hull_2d(points: &[Point<2>]) -> Vec<usize> {
+    let orient = |a: usize, b: usize, c: usize| {
+        robust::orient2d(points[a].into(), points[b].into(), points[c].into())
+    };
+
+    let mut indices = (0..points.len()).collect::<Vec<_>>();
+    indices.sort_by_key(|&i| points[i]);
+    indices.dedup_by_key(|i| points[*i]);
+
+    if indices.len() < 3 {
+        return indices;
+    }
+
+    // This is Andrew's monotone chain algorithm. It first builds the lower
+    // part of the hull, from left to right, then the upper part, from right to
+    // left. Each part only ever turns counterclockwise.
+    let mut hull: Vec<usize> = Vec::new();
+
+    for &i in &indices {
+        while hull.len() >= 2
+            && orient(hull[hull.len() - 2], hull[hull.len() - 1], i) <= 0.
+        {
+            hull.pop();
+        }
+        hull.push(i);
+    }
+
+    let lower_len = hull.len() + 1;
+    for &i in indices.iter().rev().skip(1) {
+        while hull.len() >= lower_len
+            && orient(hull[hull.len() - 2], hull[hull.len() - 1], i) <= 0.
+        {
+            hull.pop();
+        }
+        hull.push(i);
+    }
+
+    // The upper part ends with the point the lower part started with.
+    hull.pop();
+
+    hull
+}
+
+/// Compute the convex hull of a set of 3-dimensional points
+///
+/// Returns the triangles that make up the surface of the hull, as indices
+/// into `points`. The points of each triangle are in counterclockwise order,
+/// as seen from outside of the hull.
+///
+/// Points that lie on a face of the hull, without being one of its corners,
+/// might still end up as corners of its triangles. Points that lie inside of
+/// the hull never do. If all points are coplanar, the hull has no volume, and
+/// an empty list of triangles is returned.
+pub fn convex_hull_3d(points: &[Point<3>]) -> Vec<[usize; 3]> {
+    // A point is in front of a triangle, if it is on the side that the
+    // triangle faces, meaning the points of the triangle appear in
+    // counterclockwise order, as seen from the point.
+    let orient = |[a, b, c]: [usize; 3], d: usize| {
+        robust::orient3d(
+            points[a].into(),
+            points[b].into(),
+            points[c].into(),
+            points[d].into(),
+        )
+    };
+    let is_in_front = |triangle: [usize; 3], d: usize| orient(triangle, d) < 0.;
+
+    let Some(initial) = initial_tetrahedron(points) else {
+        return Vec::new();
+    };
+    let [a, b, c, d] = initial;
+
+    let [b, c] = if is_in_front([a, b, c], d) {
+        [c, b]
+    } else {
+        [b, c]
+    };
+
+    let mut triangles = vec![[a, b, c], [b, a, d], [c, b, d], [a, c, d]];
+
+    for p in 0..points.len() {
+        if initial.contains(&p) {
+            continue;
+        }
+
+        let (visible, hidden): (Vec<_>, Vec<_>) = triangles
+            .into_iter()
+            .partition(|&triangle| is_in_front(triangle, p));
+        triangles = hidden;
+
+        // The edges of the visible triangles, which are not shared by two
+        // visible triangles, form the horizon. Connecting the point to the
+        // horizon replaces the visible triangles.
+        let edges = visible
+            .iter()
+            .flat_map(|&[a, b, c]| [(a, b), (b, c), (c, a)])
+            .collect::<BTreeSet<_>>();
+        let horizon = edges.iter().filter(|(u, v)| !edges.contains(&(*v, *u)));
+
+        triangles.extend(horizon.map(|&(u, v)| [u, v, p]));
+    }
+
+    triangles
+}
+
+fn initial_tetrahedron(points: &[Point<3>]) -> Option<[usize; 4]> {
+    let a = 0;
+    let b = (0..points.len()).find(|&i| points[i] != points[a])?;
+    let c = (0..points.len()).find(|&i| {
+        let ab = points[b] - points[a];
+        let ac = points[i] - points[a];
+        ab.cross(&ac).magnitude() > Scalar::ZERO
+    })?;
+    let d = (0..points.len()).find(|&i| {
+        robust::orient3d(
+            points[a].into(),
+            points[b].into(),
+            points[c].into(),
+            points[i].into(),
+        ) != 0.
+    })?;
+
+    Some([a, b, c, d])
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{robust, Point};
+
+    use super::{convex_hull_2d, convex_hull_3d};
+
+    #[test]
+    fn convex_hull_2d_square() {
+        let points = [
+            Point::from([1., 1.]),
+            Point::from([0., 0.]),
+            Point::from([0.5, 0.5]),
+            Point::from([0., 1.]),
+            Point::from([1., 0.]),
+            Point::from([0.5, 0.]),
+            Point::from([1., 1.]),
+        ];
+
+        let hull = convex_hull_2d(&points);
+        assert_eq!(hull, vec![1, 4, 0, 3]);
+    }
+
+    #[test]
+    fn convex_hull_2d_collinear() {
+        let points = [
+            Point::from([1., 1.]),
+            Point::from([0., 0.]),
+            Point::from([2., 2.]),
+        ];
+
+        let hull = convex_hull_2d(&points);
+        assert_eq!(hull, vec![1, 2]);
+    }
+
+    #[test]
+    fn convex_hull_3d_cube() {
+        let mut points = Vec::new();
+        for x in [0., 1.] {
+            for y in [0., 1.] {
+                for z in [0., 1.] {
+                    points.push(Point::from([x, y, z]));
+                }
+            }
+        }
+        points.push(Point::from([0.5, 0.5, 0.5]));
+
+        let hull = convex_hull_3d(&points);
+        assert_eq!(hull.len(), 12);
+
+        // No point must be in front of any triangle.
+        for &[a, b, c] in &hull {
+            for &d in &points {
+                let orientation = robust::orient3d(
+                    points[a].into(),
+                    points[b].into(),
+                    points[c].into(),
+                    d.into(),
+                );
+                assert!(orientation >= 0.);
+            }
+        }
+    }
+
+    #[test]
+    fn convex_hull_3d_coplanar() {
+        let points = [
+            Point::from([0., 0., 0.]),
+            Point::from([1., 0., 0.]),
+            Point::from([0., 1., 0.]),
+            Point::from([1., 1., 0.]),
+        ];
+
+        let hull = convex_hull_3d(&points);
+        assert!(hull.is_empty());
+    }
+}
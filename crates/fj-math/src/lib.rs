@@ -35,12 +35,15 @@
 
 #![warn(missing_docs)]
 
+pub mod hull;
 pub mod robust;
 
 mod aabb;
 mod arc;
+mod bvh;
 mod circle;
 mod coordinates;
+mod helix;
 mod line;
 mod plane;
 mod point;
@@ -54,8 +57,10 @@ mod vector;
 pub use self::{
     aabb::Aabb,
     arc::Arc,
+    bvh::Bvh,
     circle::Circle,
     coordinates::{Uv, Xyz, T},
+    helix::Helix,
     line::Line,
     plane::Plane,
     point::Point,
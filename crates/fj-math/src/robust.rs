@@ -28,6 +28,17 @@ const O3DERRBOUNDA: f64 = 7.771561172376103e-16;
 const O3DERRBOUNDB: f64 = 3.330669073875473e-16;
 const O3DERRBOUNDC: f64 = 3.2047474274603644e-31;
 
+/// Test a point's orientation against a line
+///
+/// Returns a positive value, if `pa`, `pb`, and `pc` occur in counterclockwise
+/// order, a negative value if they occur in clockwise order, and zero if they
+/// are collinear. This is provided by the [`robust`] crate and only wrapped
+/// here, for consistency with [`orient3d`].
+pub fn orient2d(pa: [f64; 2], pb: [f64; 2], pc: [f64; 2]) -> f64 {
+    let [pa, pb, pc] = [pa, pb, pc].map(|[x, y]| ::robust::Coord { x, y });
+    ::robust::orient2d(pa, pb, pc)
+}
+
 /// Test a point's orientation against a plane
 pub fn orient3d(pa: [f64; 3], pb: [f64; 3], pc: [f64; 3], pd: [f64; 3]) -> f64 {
     let adx: f64 = pa[0] - pd[0];
@@ -133,6 +133,11 @@ impl Scalar {
         self.0.cos().into()
     }
 
+    /// Compute the sine
+    pub fn sin(self) -> Self {
+        self.0.sin().into()
+    }
+
     /// Compute sine and cosine
     pub fn sin_cos(self) -> (Self, Self) {
         let (sin, cos) = self.0.sin_cos();
@@ -2,7 +2,7 @@ use std::ops;
 
 use nalgebra::Perspective3;
 
-use crate::{Circle, Line, Scalar};
+use crate::{Circle, Helix, Line, Scalar};
 
 use super::{Aabb, Point, Segment, Triangle, Vector};
 
@@ -88,6 +88,14 @@ impl Transform {
         )
     }
 
+    /// Transform the given helix
+    pub fn transform_helix(&self, helix: &Helix) -> Helix {
+        Helix::new(
+            self.transform_circle(&helix.circle()),
+            self.transform_vector(&helix.pitch()),
+        )
+    }
+
     /// Inverse transform
     pub fn inverse(&self) -> Self {
         Self(self.0.inverse())
@@ -120,11 +128,15 @@ impl Transform {
     }
 
     /// Transform the given axis-aligned bounding box
+    ///
+    /// Returns the axis-aligned bounding box of the transformed box, which
+    /// can be larger than the original, if the transform rotates it.
     pub fn transform_aabb(&self, aabb: &Aabb<3>) -> Aabb<3> {
-        Aabb {
-            min: self.transform_point(&aabb.min),
-            max: self.transform_point(&aabb.max),
-        }
+        Aabb::<3>::from_points(
+            aabb.vertices()
+                .into_iter()
+                .map(|vertex| self.transform_point(&vertex)),
+        )
     }
 
     /// Exposes the data of this Transform as a slice of f64.
@@ -157,7 +169,7 @@ impl ops::Mul<Self> for Transform {
 mod tests {
     use approx::assert_abs_diff_eq;
 
-    use crate::{Line, Point, Scalar, Vector};
+    use crate::{Aabb, Line, Point, Scalar, Vector};
 
     use super::Transform;
 
@@ -182,6 +194,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn transform_aabb() {
+        let aabb = Aabb::<3>::from_points([[1., 0., 0.], [2., 1., 1.]]);
+
+        let transform = Transform::rotation(Vector::unit_z() * Scalar::PI);
+        let aabb = transform.transform_aabb(&aabb);
+
+        assert_abs_diff_eq!(
+            aabb.min,
+            Point::from([-2., -1., 0.]),
+            epsilon = Scalar::from(1e-8),
+        );
+        assert_abs_diff_eq!(
+            aabb.max,
+            Point::from([-1., 0., 1.]),
+            epsilon = Scalar::from(1e-8),
+        );
+    }
+
     #[test]
     fn extract_rotation_translation() {
         let rotation =
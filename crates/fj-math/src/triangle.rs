@@ -110,6 +110,12 @@ impl Triangle<3> {
             .into_inner()
             .into()
     }
+
+    /// Compute the triangle's area
+    pub fn area(&self) -> Scalar {
+        let [a, b, c] = self.points;
+        (b - a).cross(&(c - a)).magnitude() / 2.
+    }
 }
 
 impl<P, const D: usize> From<[P; 3]> for Triangle<D>
@@ -138,7 +144,7 @@ pub enum Winding {
 
 #[cfg(test)]
 mod tests {
-    use crate::{Point, Vector};
+    use crate::{Point, Scalar, Vector};
 
     use super::Triangle;
 
@@ -182,4 +188,11 @@ mod tests {
             Triangle::from([[0.0, 0.0, 0.0], [2.0, 1.0, 0.0], [2.0, 0.0, 0.0]]);
         assert_eq!(triangle.normal(), Vector::from([0.0, 0.0, -1.0]));
     }
+
+    #[test]
+    fn area() {
+        let triangle =
+            Triangle::from([[0.0, 0.0, 0.0], [2.0, 1.0, 0.0], [2.0, 0.0, 0.0]]);
+        assert_eq!(triangle.area(), Scalar::ONE);
+    }
 }
@@ -1,6 +1,6 @@
 use std::ops::Deref;
 
-use fj_interop::{debug::DebugInfo, ext::ArrayExt, mesh::Color};
+use fj_interop::{debug::DebugInfo, mesh::Color};
 use fj_kernel::{
     algorithms::reverse::Reverse,
     insert::Insert,
@@ -10,7 +10,7 @@ use fj_kernel::{
 };
 use fj_math::Aabb;
 
-use super::Shape;
+use super::{BrepError, Shape};
 
 impl Shape for fj::Difference2d {
     type Brep = Sketch;
@@ -19,7 +19,7 @@ impl Shape for fj::Difference2d {
         &self,
         objects: &mut Service<Objects>,
         debug_info: &mut DebugInfo,
-    ) -> Self::Brep {
+    ) -> Result<Self::Brep, BrepError> {
         // This method assumes that `b` is fully contained within `a`:
         // https://github.com/hannobraun/Fornjot/issues/92
 
@@ -28,10 +28,9 @@ impl Shape for fj::Difference2d {
         let mut exteriors = Vec::new();
         let mut interiors = Vec::new();
 
-        let [a, b] = self
-            .shapes()
-            .each_ref_ext()
-            .map(|shape| shape.compute_brep(objects, debug_info));
+        let [a, b] = self.shapes();
+        let a = a.compute_brep(objects, debug_info)?;
+        let b = b.compute_brep(objects, debug_info)?;
 
         if let Some(face) = a.faces().into_iter().next() {
             // If there's at least one face to subtract from, we can proceed.
@@ -90,7 +89,7 @@ impl Shape for fj::Difference2d {
         }
 
         let difference = PartialSketch { faces }.build(objects).insert(objects);
-        difference.deref().clone()
+        Ok(difference.deref().clone())
     }
 
     fn bounding_volume(&self) -> Aabb<3> {
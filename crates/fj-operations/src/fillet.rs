@@ -0,0 +1,68 @@
+use std::ops::Deref;
+
+use fj_interop::debug::DebugInfo;
+use fj_kernel::{
+    algorithms::{
+        fillet::{Fillet, FilletKind},
+        sweep::Sweep,
+    },
+    insert::Insert,
+    objects::{Objects, Sketch, Solid},
+    services::Service,
+};
+use fj_math::{Aabb, Scalar, Vector};
+
+use super::{BrepError, Shape};
+
+impl Shape for fj::Fillet {
+    type Brep = Solid;
+
+    fn compute_brep(
+        &self,
+        objects: &mut Service<Objects>,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Self::Brep, BrepError> {
+        let sketch = self.sweep().shape().compute_brep(objects, debug_info)?;
+
+        let kind = match self.kind() {
+            fj::FilletKind::Round { radius } => FilletKind::Round {
+                radius: Scalar::from(radius),
+            },
+            fj::FilletKind::Chamfer { distance } => FilletKind::Chamfer {
+                distance: Scalar::from(distance),
+            },
+        };
+
+        // The edges along the sweep path start at the corners of the swept
+        // sketch. Treating those corners before sweeping results in treated
+        // edges.
+        let mut offset = 0;
+        let mut faces = Vec::new();
+        for face in sketch.faces() {
+            let base = offset;
+            offset += face
+                .all_cycles()
+                .map(|cycle| cycle.half_edges().count())
+                .sum::<usize>();
+
+            faces.push(face.clone().fillet(
+                kind,
+                |i| self.edges().contains(base + i),
+                objects,
+            )?);
+        }
+
+        let sketch = Sketch::new(faces).insert(objects);
+
+        let path = Vector::from(self.sweep().path());
+
+        let solid = sketch.sweep(path, objects);
+        Ok(solid.deref().clone())
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        // Treating edges only ever removes material, so the bounding volume of
+        // the sweep is a valid (if not necessarily tight) bounding volume.
+        self.sweep().bounding_volume()
+    }
+}
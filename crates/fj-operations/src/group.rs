@@ -1,11 +1,15 @@
+use std::ops::Deref;
+
 use fj_interop::debug::DebugInfo;
 use fj_kernel::{
-    objects::{FaceSet, Objects},
+    insert::Insert,
+    objects::{FaceSet, Objects, Sketch},
+    partial::{Partial, PartialObject, PartialSketch},
     services::Service,
 };
 use fj_math::Aabb;
 
-use super::Shape;
+use super::{BrepError, Shape};
 
 impl Shape for fj::Group {
     type Brep = FaceSet;
@@ -14,16 +18,16 @@ impl Shape for fj::Group {
         &self,
         objects: &mut Service<Objects>,
         debug_info: &mut DebugInfo,
-    ) -> Self::Brep {
+    ) -> Result<Self::Brep, BrepError> {
         let mut faces = FaceSet::new();
 
-        let a = self.a.compute_brep(objects, debug_info);
-        let b = self.b.compute_brep(objects, debug_info);
+        let a = self.a.compute_brep(objects, debug_info)?;
+        let b = self.b.compute_brep(objects, debug_info)?;
 
         faces.extend(a);
         faces.extend(b);
 
-        faces
+        Ok(faces)
     }
 
     fn bounding_volume(&self) -> Aabb<3> {
@@ -33,3 +37,31 @@ impl Shape for fj::Group {
         a.merged(&b)
     }
 }
+
+impl Shape for fj::Group2d {
+    type Brep = Sketch;
+
+    fn compute_brep(
+        &self,
+        objects: &mut Service<Objects>,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Self::Brep, BrepError> {
+        let mut faces = Vec::new();
+        for shape in self.shapes() {
+            let sketch = shape.compute_brep(objects, debug_info)?;
+            faces
+                .extend(sketch.faces().into_iter().cloned().map(Partial::from));
+        }
+
+        let sketch = PartialSketch { faces }.build(objects).insert(objects);
+        Ok(sketch.deref().clone())
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        self.shapes()
+            .iter()
+            .map(|shape| shape.bounding_volume())
+            .reduce(|a, b| a.merged(&b))
+            .unwrap_or_default()
+    }
+}
@@ -0,0 +1,450 @@
+use std::ops::Deref;
+
+use fj_interop::{debug::DebugInfo, ext::ArrayExt, mesh::Color};
+use fj_kernel::{
+    algorithms::approx::{Approx, Tolerance},
+    builder::{CycleBuilder, HalfEdgeBuilder, ShellBuilder, SurfaceBuilder},
+    geometry::path::GlobalPath,
+    insert::Insert,
+    objects::{Objects, Solid},
+    partial::{
+        Partial, PartialCycle, PartialFace, PartialGlobalVertex, PartialObject,
+        PartialShell, PartialSolid, PartialSurface,
+    },
+    services::Service,
+};
+use fj_math::{Aabb, Circle, Helix, Line, Point, Scalar, Vector};
+
+use super::{BrepError, Shape};
+
+impl Shape for fj::HelicalSweep {
+    type Brep = Solid;
+
+    fn compute_brep(
+        &self,
+        objects: &mut Service<Objects>,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Self::Brep, BrepError> {
+        let radius = Scalar::from(self.radius());
+        let pitch = Scalar::from(self.pitch());
+        let end = Scalar::TAU * self.turns();
+
+        if !(self.turns() > 0. && self.turns().is_finite()) {
+            return Err(HelicalSweepError::InvalidTurns {
+                turns: self.turns(),
+            }
+            .into());
+        }
+
+        let profile = self.shape().bounding_volume();
+        if radius + profile.min.x <= Scalar::ZERO {
+            return Err(HelicalSweepError::ProfileTouchesAxis {
+                radius: self.radius(),
+            }
+            .into());
+        }
+
+        let tolerance =
+            Tolerance::from_scalar(self.tolerance()).map_err(|_| {
+                HelicalSweepError::InvalidTolerance {
+                    tolerance: self.tolerance(),
+                }
+            })?;
+
+        let helix_through = |point: Point<2>| {
+            Helix::new(
+                Circle::from_center_and_radius(
+                    [Scalar::ZERO, Scalar::ZERO, point.v],
+                    radius + point.u,
+                ),
+                [Scalar::ZERO, Scalar::ZERO, pitch],
+            )
+        };
+        let place = |point: Point<2>, angle: Scalar| {
+            helix_through(point).point_from_helix_coords([angle])
+        };
+
+        let mut shell = PartialShell::default();
+        let color = Color(self.shape().color());
+
+        for face in self.shape().compute_brep(objects, debug_info)?.faces() {
+            // Curved segments of the profile can't be swept exactly, so the
+            // profile is approximated as polygons.
+            let polygons = face
+                .all_cycles()
+                .map(|cycle| {
+                    let mut points = cycle
+                        .deref()
+                        .approx(tolerance)
+                        .points()
+                        .into_iter()
+                        .map(|point| point.local_form)
+                        .collect::<Vec<_>>();
+
+                    // The approximation of a cycle is closed, meaning its
+                    // first point is repeated at the end. We don't need that
+                    // here.
+                    points.pop();
+
+                    points
+                })
+                .collect::<Vec<_>>();
+
+            // Every point of the profile ends up as a vertex on both caps.
+            // Create those vertices once, so all faces refer to the same ones.
+            let vertices = polygons
+                .iter()
+                .map(|polygon| {
+                    polygon
+                        .iter()
+                        .map(|&point| {
+                            [Scalar::ZERO, end].map(|angle| {
+                                Partial::from_partial(PartialGlobalVertex {
+                                    position: Some(place(point, angle)),
+                                })
+                            })
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>();
+
+            // The start cap is in the xz-plane. Its normal points against the
+            // direction of the sweep. The end cap is where the profile ends up
+            // after the sweep, but reversed.
+            let mut caps = Vec::new();
+            for (i, angle, reverse) in
+                [(0, Scalar::ZERO, false), (1, end, true)]
+            {
+                let surface = {
+                    let origin = place(Point::origin(), angle);
+                    let (sin, cos) = angle.sin_cos();
+                    let direction = Vector::from([cos, sin, Scalar::ZERO]);
+
+                    Partial::from_partial(PartialSurface::from_axes(
+                        GlobalPath::Line(Line::from_origin_and_direction(
+                            origin, direction,
+                        )),
+                        Vector::unit_z(),
+                    ))
+                };
+
+                let mut half_edges = Vec::new();
+                let mut cycles = polygons
+                    .iter()
+                    .zip(&vertices)
+                    .map(|(polygon, vertices)| {
+                        let mut points = polygon
+                            .iter()
+                            .copied()
+                            .zip(vertices)
+                            .collect::<Vec<_>>();
+                        if reverse {
+                            points.reverse();
+                        }
+
+                        let mut cycle = PartialCycle {
+                            surface: surface.clone(),
+                            ..Default::default()
+                        };
+                        let cycle_half_edges = cycle
+                            .update_as_polygon_from_points(
+                                points
+                                    .iter()
+                                    .map(|(point, _)| *point)
+                                    .collect::<Vec<_>>(),
+                            );
+
+                        for (half_edge, (_, vertex)) in
+                            cycle_half_edges.iter().zip(&points)
+                        {
+                            let mut half_edge = half_edge.clone();
+                            let [start, _] = &mut half_edge.write().vertices;
+                            start.1.write().global_form = vertex[i].clone();
+                        }
+                        half_edges.push(cycle_half_edges);
+
+                        Partial::from_partial(cycle)
+                    })
+                    .collect::<Vec<_>>()
+                    .into_iter();
+
+                let face = PartialFace {
+                    exterior: cycles
+                        .next()
+                        .expect("Face must have an exterior cycle"),
+                    interiors: cycles.collect(),
+                    color: Some(color),
+                };
+                shell.faces.push(Partial::from_partial(face));
+
+                caps.push(half_edges);
+            }
+
+            // Each segment of the profile becomes a side face. Its surface has
+            // the helix that the segment's start point follows as its u-axis,
+            // and the segment itself as its v-axis. The v-axis rotates with
+            // the helix, so the segment's end point follows a helix too, and
+            // the face is exact.
+            //
+            // The outside of the profile is to the right of the direction its
+            // cycles are going in. The side faces' normals point outward, if
+            // their exteriors are counter-clockwise in surface coordinates.
+            for (k, (polygon, vertices)) in
+                polygons.iter().zip(&vertices).enumerate()
+            {
+                let n = polygon.len();
+
+                let mut sides = Vec::new();
+                for j in 0..n {
+                    let [a, b] = [polygon[j], polygon[(j + 1) % n]];
+                    let [va, vb] = [&vertices[j], &vertices[(j + 1) % n]];
+
+                    let surface = PartialSurface::from_axes(
+                        GlobalPath::Helix(helix_through(a)),
+                        place(b, Scalar::ZERO) - place(a, Scalar::ZERO),
+                    );
+
+                    let mut face = shell.add_face();
+                    let mut face = face.write();
+                    face.color = Some(color);
+
+                    face.exterior.write().surface =
+                        Partial::from_partial(surface);
+                    let half_edges =
+                        face.exterior.write().update_as_polygon_from_points([
+                            [Scalar::ZERO, Scalar::ZERO],
+                            [end, Scalar::ZERO],
+                            [end, Scalar::ONE],
+                            [Scalar::ZERO, Scalar::ONE],
+                        ]);
+
+                    for (half_edge, vertex) in
+                        half_edges.iter().zip([&va[0], &va[1], &vb[1], &vb[0]])
+                    {
+                        let mut half_edge = half_edge.clone();
+                        let [start, _] = &mut half_edge.write().vertices;
+                        start.1.write().global_form = vertex.clone();
+                    }
+
+                    // The bottom and top edges follow helices. Their curve
+                    // coordinates need to match the u-coordinates of the
+                    // surface, for them to share global curves with the
+                    // neighboring side faces.
+                    let [bottom, _, top, _] = &half_edges;
+                    for (half_edge, boundary) in [
+                        (bottom, [Scalar::ZERO, end]),
+                        (top, [end, Scalar::ZERO]),
+                    ] {
+                        let mut half_edge = half_edge.clone();
+                        let mut half_edge = half_edge.write();
+
+                        for (vertex, t) in
+                            half_edge.vertices.each_mut_ext().zip_ext(boundary)
+                        {
+                            vertex.0 = Some(Point::from([t]));
+                        }
+                        half_edge.update_as_line_segment();
+                    }
+
+                    sides.push(half_edges);
+                }
+
+                // Make sure that edges that are shared between faces refer to
+                // the same global curves, or the approximation ends up with
+                // distinct points in the same place.
+                for j in 0..n {
+                    let [_, right, top, left] = &sides[j];
+                    let [next_bottom, ..] = &sides[(j + 1) % n];
+                    let start_cap = &caps[0][k][j];
+                    let end_cap = &caps[1][k][(2 * n - 2 - j) % n];
+
+                    for (from, to) in [
+                        (top, next_bottom),
+                        (left, start_cap),
+                        (right, end_cap),
+                    ] {
+                        let global_curve =
+                            from.read().curve.read().global_form.clone();
+                        to.clone().write().curve.write().global_form =
+                            global_curve;
+                    }
+                }
+
+                for mut half_edge in sides.into_iter().flatten() {
+                    half_edge.write().infer_global_form();
+                }
+            }
+
+            for mut half_edge in caps.into_iter().flatten().flatten() {
+                half_edge.write().infer_global_form();
+            }
+        }
+
+        let solid = PartialSolid {
+            shells: vec![Partial::from_partial(shell)],
+        }
+        .build(objects)
+        .insert(objects);
+        Ok(solid.deref().clone())
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        let profile = self.shape().bounding_volume();
+
+        let radius = self.radius() + profile.max.x.into_f64();
+        let rise = self.pitch() * self.turns();
+
+        let [min, max] = [profile.min.y, profile.max.y].map(Scalar::into_f64);
+        let [min, max] = [min + f64::min(rise, 0.), max + f64::max(rise, 0.)];
+
+        Aabb {
+            min: Point::from([-radius, -radius, min]),
+            max: Point::from([radius, radius, max]),
+        }
+    }
+}
+
+/// An error that can occur when computing a helical sweep
+#[derive(Debug, thiserror::Error)]
+pub enum HelicalSweepError {
+    /// The number of turns is not positive
+    #[error("Helical sweep must have a positive number of turns, not {turns}")]
+    InvalidTurns {
+        /// The number of turns
+        turns: f64,
+    },
+
+    /// The profile touches or crosses the axis of the sweep
+    #[error("Profile of helical sweep with radius {radius} touches the axis")]
+    ProfileTouchesAxis {
+        /// The radius of the sweep
+        radius: f64,
+    },
+
+    /// The tolerance is not positive
+    ///
+    /// By default, the tolerance is relative to the radius, so this is also
+    /// the case if the radius is not positive.
+    #[error("Tolerance {tolerance} of helical sweep is not positive")]
+    InvalidTolerance {
+        /// The tolerance of the sweep
+        tolerance: f64,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use fj::syntax::*;
+    use fj_interop::{analysis::MeshAnalysis, debug::DebugInfo};
+    use fj_kernel::{
+        algorithms::approx::Tolerance, geometry::path::GlobalPath,
+        services::Services,
+    };
+
+    use crate::{shape_processor::ShapeProcessor, BrepError, Shape};
+
+    /// Check that a helical sweep results in a valid solid
+    ///
+    /// Returns the number of side faces, which are defined on helical
+    /// surfaces.
+    fn check(sweep: fj::HelicalSweep) -> Result<usize, BrepError> {
+        let mut services = Services::new();
+
+        let solid =
+            sweep.compute_brep(&mut services.objects, &mut DebugInfo::new())?;
+
+        let mut num_sides = 0;
+        for shell in solid.shells() {
+            assert!(shell.is_watertight());
+
+            for face in shell.faces() {
+                if let GlobalPath::Helix(_) = face.surface().geometry().u {
+                    num_sides += 1;
+                }
+            }
+        }
+
+        let processor = ShapeProcessor {
+            tolerance: Some(Tolerance::from(0.05)),
+            debug_dump: None,
+        };
+        let shape = processor
+            .process(&sweep.into())
+            .expect("Failed to process helical sweep");
+
+        let analysis = MeshAnalysis::of(&shape.mesh);
+        assert!(analysis.is_ok(), "{analysis}");
+
+        Ok(num_sides)
+    }
+
+    #[test]
+    fn circle() -> Result<(), BrepError> {
+        let profile = fj::Sketch::from_circle(fj::Circle::from_radius(0.5));
+        let num_sides = check(profile.helical_sweep(5., 2., 10.))?;
+
+        // The circle is approximated by a polygon.
+        assert!(num_sides >= 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn square() -> Result<(), BrepError> {
+        let profile = fj::Sketch::from_points(vec![
+            [-0.5, -0.5],
+            [0.5, -0.5],
+            [0.5, 0.5],
+            [-0.5, 0.5],
+        ]);
+
+        let num_sides = check(profile.helical_sweep(5., 2., 3.))?;
+        assert_eq!(num_sides, 4);
+
+        // A negative pitch results in a left-handed helix.
+        let num_sides = check(profile.helical_sweep(5., -2., 3.))?;
+        assert_eq!(num_sides, 4);
+
+        Ok(())
+    }
+
+    #[test]
+    fn thread() -> Result<(), BrepError> {
+        let thread = fj::IsoMetricThread::new(6., 1.);
+
+        assert_eq!(check(thread.external(5.))?, 4);
+        assert_eq!(check(thread.internal(5.))?, 4);
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_parameters() {
+        let square = fj::Sketch::from_points(vec![
+            [0., 0.],
+            [1., 0.],
+            [1., 1.],
+            [0., 1.],
+        ]);
+        let thread = fj::IsoMetricThread::new(6., 1.);
+
+        let sweeps = [
+            thread.external(0.),
+            thread.external(-5.),
+            square.helical_sweep(5., 2., f64::INFINITY),
+            square.helical_sweep(-0.5, 2., 3.),
+            square.helical_sweep(0., 2., 3.),
+            square.helical_sweep(5., 2., 3.).with_tolerance(0.),
+        ];
+        let mut services = Services::new();
+
+        for sweep in sweeps {
+            let result = sweep
+                .compute_brep(&mut services.objects, &mut DebugInfo::new());
+            assert!(
+                matches!(result, Err(BrepError::HelicalSweep(_))),
+                "{sweep:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,381 @@
+use std::ops::Deref;
+
+use fj_interop::debug::DebugInfo;
+use fj_kernel::{
+    algorithms::{
+        reverse::Reverse,
+        sweep::{Sweep, SweepCache},
+        transform::TransformObject,
+    },
+    geometry::path::GlobalPath,
+    insert::Insert,
+    objects::{Face, Objects, Solid},
+    partial::{
+        Partial, PartialFace, PartialObject, PartialShell, PartialSolid,
+    },
+    services::Service,
+    storage::Handle,
+};
+use fj_math::{Aabb, Scalar, Vector};
+
+use crate::offset_2d::offset_shape;
+
+use super::{BrepError, Shape};
+
+impl Shape for fj::Hollow {
+    type Brep = Solid;
+
+    fn compute_brep(
+        &self,
+        objects: &mut Service<Objects>,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Self::Brep, BrepError> {
+        let path = Vector::from(self.sweep().path());
+        let thickness = Scalar::from(self.thickness());
+
+        let open_bottom = self.is_open(fj::SweepCap::Bottom);
+        let open_top = self.is_open(fj::SweepCap::Top);
+
+        let num_closed_caps = [open_bottom, open_top]
+            .into_iter()
+            .filter(|open| !open)
+            .count();
+        if thickness <= Scalar::ZERO
+            || thickness * (num_closed_caps as f64) >= path.magnitude()
+        {
+            return Err(HollowError::InvalidThickness {
+                thickness,
+                length: path.magnitude(),
+            }
+            .into());
+        }
+
+        // The inner shape is the outline of the cavity. Round joins keep the
+        // thickness of the walls constant at convex corners of the cavity.
+        let outer = self.sweep().shape().compute_brep(objects, debug_info)?;
+        let inner = offset_shape(
+            self.sweep().shape(),
+            -self.thickness(),
+            fj::OffsetJoin::Round,
+        )?
+        .compute_brep(objects, debug_info)?;
+
+        let mut outer_faces = Vec::new();
+        let mut inner_faces = Vec::new();
+
+        for (outer, inner) in outer.faces().into_iter().zip(inner.faces()) {
+            let is_negative_sweep = {
+                let u = match outer.surface().geometry().u {
+                    GlobalPath::Circle(_) | GlobalPath::Helix(_) => {
+                        return Err(HollowError::CurvedSurface.into())
+                    }
+                    GlobalPath::Line(line) => line.direction(),
+                };
+                let v = outer.surface().geometry().v;
+
+                let normal = u.cross(&v);
+
+                // The thickness of the walls is measured along the path. It
+                // would be smaller than specified, for faces that aren't swept
+                // perpendicularly.
+                let cos_angle = normal.normalize().dot(&path.normalize());
+                if Scalar::ONE - cos_angle.abs() > Scalar::from(1e-9) {
+                    return Err(HollowError::ObliqueSweep { path }.into());
+                }
+
+                cos_angle < Scalar::ZERO
+            };
+
+            // A negative sweep is the same as a positive sweep of the reversed
+            // faces. Reversing them here means the rest of the code only needs
+            // to handle positive sweeps.
+            let [outer, inner] = [outer, inner].map(|face| {
+                if is_negative_sweep {
+                    face.clone().reverse(objects)
+                } else {
+                    face.clone()
+                }
+            });
+
+            let wall = path.normalize() * thickness;
+            let rims = rims(&outer, &inner, objects)?;
+
+            if open_bottom {
+                for rim in &rims {
+                    outer_faces.push(rim.clone().reverse(objects));
+                }
+            } else {
+                outer_faces.push(outer.clone().reverse(objects));
+                inner_faces.push(inner.clone().translate(wall, objects));
+            }
+
+            if open_top {
+                for rim in rims {
+                    outer_faces.push(rim.translate(path, objects));
+                }
+            } else {
+                outer_faces.push(outer.clone().translate(path, objects));
+                inner_faces.push(
+                    inner
+                        .clone()
+                        .translate(path - wall, objects)
+                        .reverse(objects),
+                );
+            }
+
+            let mut cache = SweepCache::default();
+            for cycle in outer.all_cycles() {
+                for half_edge in cycle.half_edges() {
+                    outer_faces.push(
+                        (half_edge.clone(), outer.color())
+                            .sweep_with_cache(path, &mut cache, objects),
+                    );
+                }
+            }
+
+            // The walls of the cavity face into it. They are created by
+            // sweeping the reversed half-edges of the inner face.
+            let cavity_start = if open_bottom {
+                Vector::from([0., 0., 0.])
+            } else {
+                wall
+            };
+            let cavity_end = if open_top { path } else { path - wall };
+
+            let inner = inner.translate(cavity_start, objects);
+            let mut cache = SweepCache::default();
+            for cycle in inner.all_cycles() {
+                for half_edge in cycle.half_edges() {
+                    inner_faces.push(
+                        (half_edge.clone().reverse(objects), inner.color())
+                            .sweep_with_cache(
+                                cavity_end - cavity_start,
+                                &mut cache,
+                                objects,
+                            ),
+                    );
+                }
+            }
+        }
+
+        // If the cavity is closed, it is bounded by its own shell. Otherwise,
+        // its walls connect to the outer walls through the rims.
+        let shells = if open_bottom || open_top {
+            outer_faces.extend(inner_faces);
+            vec![outer_faces]
+        } else {
+            vec![outer_faces, inner_faces]
+        };
+
+        let shells = shells
+            .into_iter()
+            .map(|faces| {
+                let faces = faces.into_iter().map(Partial::from).collect();
+                Partial::from_partial(PartialShell { faces })
+            })
+            .collect();
+        let solid = PartialSolid { shells }.build(objects).insert(objects);
+        Ok(solid.deref().clone())
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        // Hollowing only ever removes material, so the bounding volume of the
+        // sweep is still valid.
+        self.sweep().bounding_volume()
+    }
+}
+
+/// An error that can occur when hollowing out a sweep
+#[derive(Debug, thiserror::Error)]
+pub enum HollowError {
+    /// The thickness of the walls is not valid for the sweep
+    #[error(
+        "Wall thickness {thickness} is not valid for sweep of length {length}"
+    )]
+    InvalidThickness {
+        /// The thickness of the walls
+        thickness: Scalar,
+
+        /// The length of the sweep
+        length: Scalar,
+    },
+
+    /// The swept shape has faces that are defined in curved surfaces
+    #[error("Hollowing out sweeps of curved faces is not supported")]
+    CurvedSurface,
+
+    /// The path of the sweep is not perpendicular to the swept shape
+    #[error(
+        "Hollowing out sweeps along oblique path {path:?} is not supported"
+    )]
+    ObliqueSweep {
+        /// The path of the sweep
+        path: Vector<3>,
+    },
+
+    /// The offset of the swept shape closes one of its holes
+    #[error("Offset of swept shape by wall thickness closes a hole")]
+    HoleClosed,
+}
+
+/// Create the faces that connect the outer and inner walls at a removed cap
+///
+/// The main rim is between the exteriors of the two faces. Every hole of the
+/// outer face gets an additional rim around it.
+fn rims(
+    outer: &Handle<Face>,
+    inner: &Handle<Face>,
+    objects: &mut Service<Objects>,
+) -> Result<Vec<Handle<Face>>, HollowError> {
+    let mut rims = Vec::new();
+
+    let main_rim = PartialFace {
+        exterior: Partial::from(outer.exterior().clone()),
+        interiors: vec![Partial::from(
+            inner.exterior().clone().reverse(objects),
+        )],
+        color: Some(outer.color()),
+    };
+    rims.push(main_rim);
+
+    if outer.interiors().count() != inner.interiors().count() {
+        return Err(HollowError::HoleClosed);
+    }
+    for (outer_hole, inner_hole) in outer.interiors().zip(inner.interiors()) {
+        let rim = PartialFace {
+            exterior: Partial::from(inner_hole.clone().reverse(objects)),
+            interiors: vec![Partial::from(outer_hole.clone())],
+            color: Some(outer.color()),
+        };
+        rims.push(rim);
+    }
+
+    Ok(rims
+        .into_iter()
+        .map(|rim| rim.build(objects).insert(objects))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::debug::DebugInfo;
+    use fj_kernel::{objects::Solid, services::Services};
+
+    use crate::{
+        shape_processor::ShapeProcessor, BrepError, HollowError, Shape,
+    };
+
+    fn square(min: f64, max: f64) -> fj::Sketch {
+        fj::Sketch::from_points(vec![
+            [min, min],
+            [max, min],
+            [max, max],
+            [min, max],
+        ])
+    }
+
+    fn hollow(
+        profile: fj::Shape2d,
+        open: impl IntoIterator<Item = fj::SweepCap>,
+    ) -> Result<Solid, BrepError> {
+        let mut services = Services::new();
+
+        let sweep = fj::Sweep::from_path(profile, [0., 0., 2.]);
+        let hollow = fj::Hollow::from_sweep(sweep, 0.2, open);
+
+        let solid = hollow
+            .compute_brep(&mut services.objects, &mut DebugInfo::new())?;
+
+        // The faces of the solid must be valid for triangulation too.
+        let processor = ShapeProcessor {
+            tolerance: None,
+            debug_dump: None,
+        };
+        processor
+            .process(&hollow.into())
+            .expect("Failed to process hollow");
+
+        Ok(solid)
+    }
+
+    fn with_hole() -> fj::Shape2d {
+        fj::Difference2d::from_shapes([
+            square(0., 4.).into(),
+            square(1., 3.).into(),
+        ])
+        .into()
+    }
+
+    fn assert_watertight(solid: &Solid, num_shells: usize) {
+        assert_eq!(solid.shells().count(), num_shells);
+        for shell in solid.shells() {
+            assert!(shell.is_watertight());
+        }
+    }
+
+    #[test]
+    fn closed_caps() -> Result<(), BrepError> {
+        // The cavity is bounded by its own shell.
+        let solid = hollow(square(0., 4.).into(), [])?;
+        assert_watertight(&solid, 2);
+
+        let solid = hollow(with_hole(), [])?;
+        assert_watertight(&solid, 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn open_cap() -> Result<(), BrepError> {
+        for open in [fj::SweepCap::Bottom, fj::SweepCap::Top] {
+            let solid = hollow(square(0., 4.).into(), [open])?;
+            assert_watertight(&solid, 1);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn open_cap_with_hole() -> Result<(), BrepError> {
+        for open in [fj::SweepCap::Bottom, fj::SweepCap::Top] {
+            let solid = hollow(with_hole(), [open])?;
+            assert_watertight(&solid, 1);
+        }
+
+        let solid =
+            hollow(with_hole(), [fj::SweepCap::Bottom, fj::SweepCap::Top])?;
+        assert_watertight(&solid, 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_thickness() {
+        let mut services = Services::new();
+
+        let sweep = fj::Sweep::from_path(square(0., 4.).into(), [0., 0., 2.]);
+        let hollow = fj::Hollow::from_sweep(sweep, 1., []);
+
+        let result =
+            hollow.compute_brep(&mut services.objects, &mut DebugInfo::new());
+        assert!(matches!(
+            result,
+            Err(BrepError::Hollow(HollowError::InvalidThickness { .. }))
+        ));
+    }
+
+    #[test]
+    fn oblique_sweep() {
+        let mut services = Services::new();
+
+        let sweep = fj::Sweep::from_path(square(0., 4.).into(), [1., 0., 2.]);
+        let hollow = fj::Hollow::from_sweep(sweep, 0.2, []);
+
+        let result =
+            hollow.compute_brep(&mut services.objects, &mut DebugInfo::new());
+        assert!(matches!(
+            result,
+            Err(BrepError::Hollow(HollowError::ObliqueSweep { .. }))
+        ));
+    }
+}
@@ -0,0 +1,568 @@
+use std::{collections::BTreeMap, ops::Deref};
+
+use fj_interop::{debug::DebugInfo, mesh::Color};
+use fj_kernel::{
+    algorithms::approx::{Approx, Tolerance},
+    builder::{CycleBuilder, ShellBuilder},
+    insert::Insert,
+    objects::{Objects, Sketch, Solid},
+    partial::{Partial, PartialObject, PartialShell, PartialSolid},
+    services::Service,
+};
+use fj_math::{
+    hull::{convex_hull_2d, convex_hull_3d},
+    robust, Aabb, Point, Scalar,
+};
+
+use super::{
+    offset_2d::offset_shape,
+    pattern::{transform_shape_2d, transforms_2d},
+    BrepError, Shape,
+};
+
+impl Shape for fj::Hull {
+    type Brep = Solid;
+
+    fn compute_brep(
+        &self,
+        objects: &mut Service<Objects>,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Self::Brep, BrepError> {
+        let tolerance =
+            Tolerance::from_scalar(relative_tolerance(self.bounding_volume()))
+                .map_err(|_| HullError::of_zero_size(self.shapes()))?;
+
+        let mut points = Vec::new();
+        for shape in self.shapes() {
+            for face in &shape.compute_brep(objects, debug_info)? {
+                points.extend(
+                    face.approx(tolerance)
+                        .points()
+                        .into_iter()
+                        .map(|point| point.global_form),
+                );
+            }
+        }
+
+        let triangles = convex_hull_3d(&points);
+        if triangles.is_empty() {
+            return Err(HullError::of_points(&points).into());
+        }
+
+        let mut shell = PartialShell::default();
+        for triangle in triangles {
+            let mut face = shell.add_face();
+            face.write().color = Some(Color(self.color()));
+            face.write()
+                .exterior
+                .write()
+                .update_as_triangle_from_global_points(
+                    triangle.map(|i| points[i]),
+                );
+        }
+
+        let solid = PartialSolid {
+            shells: vec![Partial::from_partial(shell)],
+        }
+        .build(objects)
+        .insert(objects);
+        Ok(solid.deref().clone())
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        // The hull is the smallest convex shape that contains all shapes, so
+        // it touches the bounding box of all shapes on every side.
+        self.shapes()
+            .iter()
+            .map(|shape| shape.bounding_volume())
+            .reduce(|a, b| a.merged(&b))
+            .unwrap_or_default()
+    }
+}
+
+impl Shape for fj::Hull2d {
+    type Brep = Sketch;
+
+    fn compute_brep(
+        &self,
+        objects: &mut Service<Objects>,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Self::Brep, BrepError> {
+        hull_sketch(self)?.compute_brep(objects, debug_info)
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        self.shapes()
+            .iter()
+            .map(|shape| shape.bounding_volume())
+            .reduce(|a, b| a.merged(&b))
+            .unwrap_or_default()
+    }
+}
+
+/// Compute the convex hull of 2D shapes, as a sketch
+///
+/// The hull is computed from approximations of the shapes. Where the hull
+/// follows an arc or circle of one of the shapes, the arc itself is used
+/// instead of its approximation.
+///
+/// Returns an error, if the hull doesn't have an area, or if any of the shapes
+/// is an offset that can't be computed.
+pub(crate) fn hull_sketch(hull: &fj::Hull2d) -> Result<fj::Sketch, BrepError> {
+    let tolerance = relative_tolerance(hull.bounding_volume());
+    if tolerance <= Scalar::ZERO {
+        return Err(HullError::of_zero_size(hull.shapes()).into());
+    }
+
+    let mut hull_points = HullPoints::default();
+    for shape in hull.shapes() {
+        hull_points.add_shape(shape, tolerance)?;
+    }
+
+    let HullPoints { points, arcs } = hull_points;
+    let (points, arcs_of_points): (Vec<_>, Vec<_>) = points.into_iter().unzip();
+
+    let corners = convex_hull_2d(&points);
+    if corners.len() < 3 {
+        return Err(HullError::of_points(&points).into());
+    }
+
+    // Each edge of the hull either follows an arc, or is a straight line. The
+    // hull only follows an arc, if it bulges outward. Otherwise, the hull
+    // happens to connect two points on an arc, without following it.
+    let edges = (0..corners.len())
+        .map(|i| {
+            let [a, b] = [corners[i], corners[(i + 1) % corners.len()]];
+
+            arcs_of_points[a].iter().copied().find(|arc| {
+                let center: Point<2> = arcs[*arc].center;
+                arcs_of_points[b].contains(arc)
+                    && robust::orient2d(
+                        points[a].into(),
+                        points[b].into(),
+                        center.into(),
+                    ) > 0.
+            })
+        })
+        .collect::<Vec<_>>();
+
+    // If the whole hull follows a single arc, the hull is that circle.
+    if let Some(Some(arc)) = edges.first() {
+        if edges.iter().all(|edge| *edge == Some(*arc)) {
+            let Arc { center, radius } = arcs[*arc];
+
+            let circle = fj::Circle::from_center_and_radius(
+                center.into(),
+                radius.into_f64(),
+            );
+            return Ok(fj::Sketch::from_circle(circle).with_color(hull.color()));
+        }
+    }
+
+    // Neighboring edges that follow the same arc become a single segment. To
+    // make that simpler, start with an edge that can't be merged with the one
+    // before it.
+    let n = edges.len();
+    let start = (0..n)
+        .find(|&i| edges[i].is_none() || edges[i] != edges[(i + n - 1) % n])
+        .expect("Hull that doesn't follow a single arc must have a start");
+
+    let mut segments: Vec<fj::SketchSegment> = Vec::new();
+    let mut previous = None;
+
+    for i in (start..start + n).map(|i| i % n) {
+        let [a, b] = [corners[i], corners[(i + 1) % n]];
+        let endpoint = points[b].into();
+
+        match edges[i] {
+            Some(arc) => {
+                let center = arcs[arc].center;
+                let [ra, rb] = [points[a] - center, points[b] - center];
+                let angle = ra.cross2d(&rb).atan2(ra.dot(&rb)).into_f64();
+
+                match segments.last_mut() {
+                    Some(fj::SketchSegment {
+                        endpoint: last_endpoint,
+                        route: fj::SketchSegmentRoute::Arc { angle: last },
+                    }) if previous == Some(arc) => {
+                        *last_endpoint = endpoint;
+                        *last = fj::Angle::from_rad(last.rad() + angle);
+                    }
+                    _ => segments.push(fj::SketchSegment {
+                        endpoint,
+                        route: fj::SketchSegmentRoute::Arc {
+                            angle: fj::Angle::from_rad(angle),
+                        },
+                    }),
+                }
+            }
+            None => segments.push(fj::SketchSegment {
+                endpoint,
+                route: fj::SketchSegmentRoute::Direct,
+            }),
+        }
+
+        previous = edges[i];
+    }
+
+    Ok(fj::Sketch::from_segments(segments).with_color(hull.color()))
+}
+
+/// An error that can occur when computing a convex hull
+#[derive(Debug, thiserror::Error)]
+pub enum HullError {
+    /// There are no points that the hull could contain
+    #[error("Hull doesn't contain any shapes")]
+    Empty,
+
+    /// The hull doesn't have a volume, or in the case of 2D shapes, an area
+    ///
+    /// This is the case, if the shapes all lie in a plane, or in the case of
+    /// 2D shapes, on a line.
+    #[error("Hull of shapes that lie in a plane or on a line has no volume")]
+    Degenerate,
+}
+
+impl HullError {
+    /// The error for shapes whose bounding volume has no size
+    ///
+    /// Such shapes are either empty, or all of their points are the same.
+    fn of_zero_size<S>(shapes: &[S]) -> Self {
+        if shapes.is_empty() {
+            Self::Empty
+        } else {
+            Self::Degenerate
+        }
+    }
+
+    /// The error for points whose hull has no volume or area
+    fn of_points<T>(points: &[T]) -> Self {
+        if points.is_empty() {
+            Self::Empty
+        } else {
+            Self::Degenerate
+        }
+    }
+}
+
+/// The tolerance used to approximate the shapes that a hull is computed from
+fn relative_tolerance(aabb: Aabb<3>) -> Scalar {
+    let size = aabb.size();
+    size.x.max(size.y).max(size.z) / 1000.
+}
+
+/// The points that a 2D hull is computed from
+///
+/// Each point is associated with the arcs it is located on. That way, the
+/// hull can follow those arcs exactly.
+#[derive(Default)]
+struct HullPoints {
+    points: BTreeMap<Point<2>, Vec<usize>>,
+    arcs: Vec<Arc>,
+}
+
+impl HullPoints {
+    fn add_shape(
+        &mut self,
+        shape: &fj::Shape2d,
+        tolerance: Scalar,
+    ) -> Result<(), BrepError> {
+        match shape {
+            fj::Shape2d::Difference(difference) => {
+                // Whatever is subtracted from the first shape can't extend
+                // beyond it, so it doesn't contribute to the hull.
+                let [a, _] = difference.shapes();
+                self.add_shape(a, tolerance)?;
+            }
+            fj::Shape2d::Group(group) => {
+                for shape in group.shapes() {
+                    self.add_shape(shape, tolerance)?;
+                }
+            }
+            fj::Shape2d::Hull(hull) => {
+                for shape in hull.shapes() {
+                    self.add_shape(shape, tolerance)?;
+                }
+            }
+            fj::Shape2d::Offset(offset) => {
+                let shape = offset_shape(
+                    offset.shape(),
+                    offset.distance(),
+                    offset.join(),
+                )?;
+                self.add_shape(&shape, tolerance)?;
+            }
+            fj::Shape2d::Pattern(pattern) => {
+                for transform in transforms_2d(pattern) {
+                    let shape = transform_shape_2d(pattern.shape(), &transform);
+                    self.add_shape(&shape, tolerance)?;
+                }
+            }
+            fj::Shape2d::Sketch(sketch) => self.add_sketch(sketch, tolerance),
+        }
+
+        Ok(())
+    }
+
+    fn add_sketch(&mut self, sketch: &fj::Sketch, tolerance: Scalar) {
+        match sketch.chain() {
+            fj::Chain::Circle(circle) => {
+                let arc = Arc {
+                    center: Point::from(circle.center()),
+                    radius: Scalar::from(circle.radius()),
+                };
+                let id = self.add_arc(arc);
+
+                let n = arc.num_segments(Scalar::TAU, tolerance);
+                for i in 0..n {
+                    let angle = Scalar::TAU * (i as f64 / n as f64);
+                    self.add_point(arc.point_at(angle), Some(id));
+                }
+            }
+            fj::Chain::PolyChain(poly_chain) => {
+                let segments = poly_chain.to_segments();
+
+                let mut start = match segments.last() {
+                    Some(segment) => Point::from(segment.endpoint),
+                    None => return,
+                };
+
+                for segment in segments {
+                    let end = Point::from(segment.endpoint);
+
+                    match segment.route {
+                        fj::SketchSegmentRoute::Direct => {
+                            self.add_point(end, None);
+                        }
+                        fj::SketchSegmentRoute::Arc { angle } => {
+                            let geometry =
+                                fj_math::Arc::from_endpoints_and_angle(
+                                    start,
+                                    end,
+                                    Scalar::from(angle.rad()),
+                                );
+                            let arc = Arc {
+                                center: geometry.center,
+                                radius: geometry.radius,
+                            };
+                            let id = self.add_arc(arc);
+
+                            // After construction, the arc goes
+                            // counter-clockwise from `start` to `end`.
+                            let [start_angle, end_angle] =
+                                [geometry.start_angle, geometry.end_angle];
+                            let n = arc.num_segments(
+                                end_angle - start_angle,
+                                tolerance,
+                            );
+
+                            self.add_point(geometry.start, Some(id));
+                            for i in 1..n {
+                                let angle = start_angle
+                                    + (end_angle - start_angle)
+                                        * (i as f64 / n as f64);
+                                self.add_point(arc.point_at(angle), Some(id));
+                            }
+                            self.add_point(geometry.end, Some(id));
+                        }
+                    }
+
+                    start = end;
+                }
+            }
+        }
+    }
+
+    fn add_arc(&mut self, arc: Arc) -> usize {
+        self.arcs.push(arc);
+        self.arcs.len() - 1
+    }
+
+    fn add_point(&mut self, point: Point<2>, arc: Option<usize>) {
+        let arcs = self.points.entry(point).or_default();
+        arcs.extend(arc);
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Arc {
+    center: Point<2>,
+    radius: Scalar,
+}
+
+impl Arc {
+    fn point_at(&self, angle: Scalar) -> Point<2> {
+        let (sin, cos) = angle.sin_cos();
+        self.center + [cos * self.radius, sin * self.radius]
+    }
+
+    /// The number of segments needed to approximate the arc
+    ///
+    /// Segments never cover more than a quarter turn, so whether the hull
+    /// connects two neighboring points along the arc or across it can be
+    /// decided from the side of the chord the center is on.
+    fn num_segments(&self, angle: Scalar, tolerance: Scalar) -> u64 {
+        let max_angle = if tolerance < self.radius {
+            (Scalar::ONE - tolerance / self.radius).acos() * 2.
+        } else {
+            Scalar::PI
+        };
+        let max_angle = max_angle.min(Scalar::PI / 2.);
+
+        (angle.abs() / max_angle).ceil().into_u64().max(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::{analysis::MeshAnalysis, debug::DebugInfo};
+    use fj_kernel::services::Services;
+    use fj_math::Scalar;
+
+    use crate::{shape_processor::ShapeProcessor, BrepError, Shape};
+
+    use super::{hull_sketch, HullError};
+
+    fn circle(center: [f64; 2], radius: f64) -> fj::Shape2d {
+        fj::Sketch::from_circle(fj::Circle::from_center_and_radius(
+            center, radius,
+        ))
+        .into()
+    }
+
+    /// Check that a 2D hull can be turned into a valid solid
+    fn check(hull: fj::Hull2d) -> Result<(), BrepError> {
+        let mut services = Services::new();
+        hull.compute_brep(&mut services.objects, &mut DebugInfo::new())?;
+
+        // The sketch that represents the hull has the same extent as the
+        // shapes it is computed from.
+        let expected = hull.bounding_volume();
+        let actual = hull_sketch(&hull)?.bounding_volume();
+        for (a, b) in [(actual.min, expected.min), (actual.max, expected.max)] {
+            assert!(a.distance_to(&b) < Scalar::from(1e-9), "{a:?} != {b:?}");
+        }
+
+        let sweep = fj::Sweep::from_path(hull.into(), [0., 0., 1.]);
+        let processor = ShapeProcessor {
+            tolerance: None,
+            debug_dump: None,
+        };
+        let shape = processor
+            .process(&sweep.into())
+            .expect("Failed to process hull");
+
+        let analysis = MeshAnalysis::of(&shape.mesh);
+        assert!(analysis.is_ok(), "{analysis}");
+
+        Ok(())
+    }
+
+    #[test]
+    fn equal_circles() -> Result<(), BrepError> {
+        let hull = fj::Hull2d::from_shapes(vec![
+            circle([0., 0.], 1.),
+            circle([5., 0.], 1.),
+        ]);
+        check(hull)
+    }
+
+    #[test]
+    fn unequal_circles() -> Result<(), BrepError> {
+        let hull = fj::Hull2d::from_shapes(vec![
+            circle([0., 0.], 1.),
+            circle([5., 0.], 2.),
+        ]);
+
+        // The hull follows the larger circle for more than a half turn.
+        let sketch = hull_sketch(&hull)?;
+        let fj::Chain::PolyChain(chain) = sketch.chain() else {
+            panic!("Expected hull to be a poly chain");
+        };
+        let arcs = chain
+            .to_segments()
+            .into_iter()
+            .filter_map(|segment| match segment.route {
+                fj::SketchSegmentRoute::Arc { angle } => Some(angle.rad()),
+                fj::SketchSegmentRoute::Direct => None,
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(arcs.len(), 2);
+        assert!(arcs.iter().any(|&angle| angle > std::f64::consts::PI));
+
+        check(hull)?;
+
+        // Here, the arc around the larger circle crosses the negative x-axis.
+        let hull = fj::Hull2d::from_shapes(vec![
+            circle([0., 0.], 2.),
+            circle([5., 0.], 1.),
+        ]);
+        check(hull)?;
+
+        let hull = fj::Hull2d::from_shapes(vec![
+            circle([0., 0.], 1.),
+            circle([0., 5.], 2.),
+        ]);
+        check(hull)
+    }
+
+    #[test]
+    fn circle_inside_circle() -> Result<(), BrepError> {
+        let hull = fj::Hull2d::from_shapes(vec![
+            circle([0., 0.], 3.),
+            circle([1., 0.], 1.),
+        ]);
+        check(hull)
+    }
+
+    #[test]
+    fn empty_hull() {
+        let mut services = Services::new();
+
+        let hull = fj::Hull2d::from_shapes(vec![]);
+        let result =
+            hull.compute_brep(&mut services.objects, &mut DebugInfo::new());
+        assert!(matches!(result, Err(BrepError::Hull(HullError::Empty))));
+
+        let hull = fj::Hull::from_shapes(vec![]);
+        let result =
+            hull.compute_brep(&mut services.objects, &mut DebugInfo::new());
+        assert!(matches!(result, Err(BrepError::Hull(HullError::Empty))));
+    }
+
+    #[test]
+    fn degenerate_hull_2d() {
+        let mut services = Services::new();
+
+        let point = fj::Sketch::from_points(vec![[1., 1.]]).into();
+        let collinear =
+            fj::Sketch::from_points(vec![[0., 0.], [1., 0.], [2., 0.]]).into();
+
+        for shape in [point, collinear] {
+            let hull = fj::Hull2d::from_shapes(vec![shape]);
+            let result =
+                hull.compute_brep(&mut services.objects, &mut DebugInfo::new());
+            assert!(matches!(
+                result,
+                Err(BrepError::Hull(HullError::Degenerate))
+            ));
+        }
+    }
+
+    #[test]
+    fn degenerate_hull_3d() {
+        let mut services = Services::new();
+
+        // Sketches all lie in the xy-plane, so their hull has no volume.
+        let hull = fj::Hull::from_shapes(vec![
+            fj::Shape::Shape2d(circle([0., 0.], 1.)),
+            fj::Shape::Shape2d(circle([5., 0.], 1.)),
+        ]);
+        let result =
+            hull.compute_brep(&mut services.objects, &mut DebugInfo::new());
+        assert!(matches!(
+            result,
+            Err(BrepError::Hull(HullError::Degenerate))
+        ));
+    }
+}
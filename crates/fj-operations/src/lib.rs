@@ -19,14 +19,28 @@
 pub mod shape_processor;
 
 mod difference_2d;
+mod fillet;
 mod group;
+mod helical_sweep;
+mod hollow;
+mod hull;
+mod mesh;
+mod offset_2d;
+mod pattern;
+mod primitive;
 mod sketch;
 mod sweep;
 mod transform;
 
+pub use self::{
+    helical_sweep::HelicalSweepError, hollow::HollowError, hull::HullError,
+    offset_2d::OffsetError, primitive::PrimitiveError,
+};
+
 use fj_interop::debug::DebugInfo;
 use fj_kernel::{
-    objects::{FaceSet, Objects, Sketch},
+    algorithms::fillet::FilletError,
+    objects::{FaceSet, Objects, Sketch, Solid},
     services::Service,
 };
 use fj_math::Aabb;
@@ -37,11 +51,14 @@ pub trait Shape {
     type Brep;
 
     /// Compute the boundary representation of the shape
+    ///
+    /// Returns an error, if the parameters of the shape's operations are not
+    /// valid for the shapes they are applied to.
     fn compute_brep(
         &self,
         objects: &mut Service<Objects>,
         debug_info: &mut DebugInfo,
-    ) -> Self::Brep;
+    ) -> Result<Self::Brep, BrepError>;
 
     /// Access the axis-aligned bounding box of a shape
     ///
@@ -57,35 +74,71 @@ impl Shape for fj::Shape {
         &self,
         objects: &mut Service<Objects>,
         debug_info: &mut DebugInfo,
-    ) -> Self::Brep {
-        match self {
+    ) -> Result<Self::Brep, BrepError> {
+        let faces = match self {
             Self::Shape2d(shape) => {
-                shape.compute_brep(objects, debug_info).faces().clone()
+                shape.compute_brep(objects, debug_info)?.faces().clone()
             }
-            Self::Group(shape) => shape.compute_brep(objects, debug_info),
-            Self::Sweep(shape) => shape
-                .compute_brep(objects, debug_info)
-                .shells()
-                .map(|shell| shell.faces().clone())
-                .reduce(|mut a, b| {
-                    a.extend(b);
-                    a
-                })
-                .unwrap_or_default(),
-            Self::Transform(shape) => shape.compute_brep(objects, debug_info),
-        }
+            Self::Fillet(shape) => {
+                faces_of(&shape.compute_brep(objects, debug_info)?)
+            }
+            Self::Group(shape) => shape.compute_brep(objects, debug_info)?,
+            Self::HelicalSweep(shape) => {
+                faces_of(&shape.compute_brep(objects, debug_info)?)
+            }
+            Self::Hollow(shape) => {
+                faces_of(&shape.compute_brep(objects, debug_info)?)
+            }
+            Self::Hull(shape) => {
+                faces_of(&shape.compute_brep(objects, debug_info)?)
+            }
+            Self::Mesh(shape) => {
+                faces_of(&shape.compute_brep(objects, debug_info)?)
+            }
+            Self::Pattern(shape) => shape.compute_brep(objects, debug_info)?,
+            Self::Primitive(shape) => {
+                faces_of(&shape.compute_brep(objects, debug_info)?)
+            }
+            Self::Sweep(shape) => {
+                faces_of(&shape.compute_brep(objects, debug_info)?)
+            }
+            Self::Transform(shape) => {
+                shape.compute_brep(objects, debug_info)?
+            }
+        };
+
+        Ok(faces)
     }
 
     fn bounding_volume(&self) -> Aabb<3> {
         match self {
+            Self::Fillet(shape) => shape.bounding_volume(),
             Self::Shape2d(shape) => shape.bounding_volume(),
             Self::Group(shape) => shape.bounding_volume(),
+            Self::HelicalSweep(shape) => shape.bounding_volume(),
+            Self::Hollow(shape) => shape.bounding_volume(),
+            Self::Hull(shape) => shape.bounding_volume(),
+            Self::Mesh(shape) => shape.bounding_volume(),
+            Self::Pattern(shape) => shape.bounding_volume(),
+            Self::Primitive(shape) => shape.bounding_volume(),
             Self::Sweep(shape) => shape.bounding_volume(),
             Self::Transform(shape) => shape.bounding_volume(),
         }
     }
 }
 
+/// Collect the faces of all shells of a solid
+fn faces_of(solid: &Solid) -> FaceSet {
+    solid
+        .shells()
+        .map(|shell| shell.faces().clone())
+        .reduce(|mut a, b| {
+            a.extend(b);
+            a
+        })
+        .unwrap_or_default()
+}
+
 impl Shape for fj::Shape2d {
     type Brep = Sketch;
 
@@ -93,9 +146,13 @@ impl Shape for fj::Shape2d {
         &self,
         objects: &mut Service<Objects>,
         debug_info: &mut DebugInfo,
-    ) -> Self::Brep {
+    ) -> Result<Self::Brep, BrepError> {
         match self {
             Self::Difference(shape) => shape.compute_brep(objects, debug_info),
+            Self::Group(shape) => shape.compute_brep(objects, debug_info),
+            Self::Hull(shape) => shape.compute_brep(objects, debug_info),
+            Self::Offset(shape) => shape.compute_brep(objects, debug_info),
+            Self::Pattern(shape) => shape.compute_brep(objects, debug_info),
             Self::Sketch(shape) => shape.compute_brep(objects, debug_info),
         }
     }
@@ -103,7 +160,40 @@ impl Shape for fj::Shape2d {
     fn bounding_volume(&self) -> Aabb<3> {
         match self {
             Self::Difference(shape) => shape.bounding_volume(),
+            Self::Group(shape) => shape.bounding_volume(),
+            Self::Hull(shape) => shape.bounding_volume(),
+            Self::Offset(shape) => shape.bounding_volume(),
+            Self::Pattern(shape) => shape.bounding_volume(),
             Self::Sketch(shape) => shape.bounding_volume(),
         }
     }
 }
+
+/// An error that can occur when computing the boundary representation of a
+/// shape
+#[derive(Debug, thiserror::Error)]
+pub enum BrepError {
+    /// Error treating the edges of a sweep
+    #[error("Error treating edges of sweep")]
+    Fillet(#[from] FilletError),
+
+    /// Error computing a helical sweep
+    #[error("Error computing helical sweep")]
+    HelicalSweep(#[from] HelicalSweepError),
+
+    /// Error hollowing out a sweep
+    #[error("Error hollowing out sweep")]
+    Hollow(#[from] HollowError),
+
+    /// Error computing a convex hull
+    #[error("Error computing convex hull")]
+    Hull(#[from] HullError),
+
+    /// Error offsetting a 2D shape
+    #[error("Error offsetting 2D shape")]
+    Offset(#[from] OffsetError),
+
+    /// Error computing a primitive
+    #[error("Error computing primitive")]
+    Primitive(#[from] PrimitiveError),
+}
@@ -0,0 +1,141 @@
+use std::{collections::BTreeMap, ops::Deref};
+
+use fj_interop::{debug::DebugInfo, mesh::Color};
+use fj_kernel::{
+    builder::{CycleBuilder, HalfEdgeBuilder, ShellBuilder},
+    insert::Insert,
+    objects::{GlobalEdge, Objects, Solid},
+    partial::{Partial, PartialObject, PartialShell, PartialSolid},
+    services::Service,
+};
+use fj_math::{Aabb, Point};
+
+use super::{BrepError, Shape};
+
+impl Shape for fj::Mesh {
+    type Brep = Solid;
+
+    fn compute_brep(
+        &self,
+        objects: &mut Service<Objects>,
+        _: &mut DebugInfo,
+    ) -> Result<Self::Brep, BrepError> {
+        // Each triangle becomes a planar face. That way, the mesh can be
+        // processed like any other solid. Triangles that share vertices or
+        // edges, by position, share the respective global objects, so a
+        // closed mesh results in a watertight shell.
+        let mut global_vertices = BTreeMap::new();
+        let mut global_edges: BTreeMap<_, Partial<GlobalEdge>> =
+            BTreeMap::new();
+
+        let mut shell = PartialShell::default();
+        for triangle in self.triangles() {
+            let points = triangle.map(Point::from);
+
+            let mut face = shell.add_face();
+            face.write().color = Some(Color(self.color()));
+            let half_edges = face
+                .write()
+                .exterior
+                .write()
+                .update_as_triangle_from_global_points(points);
+
+            // Each half-edge shares its end vertex with the start vertex of
+            // the next one, so updating the start vertices covers all of them.
+            for (half_edge, point) in half_edges.iter().zip(points) {
+                let [vertex, _] = &half_edge.read().vertices;
+                let mut surface_vertex = vertex.1.clone();
+
+                let global_vertex = global_vertices
+                    .entry(point)
+                    .or_insert_with(|| {
+                        surface_vertex.read().global_form.clone()
+                    })
+                    .clone();
+                surface_vertex.write().global_form = global_vertex;
+            }
+
+            for (i, mut half_edge) in half_edges.into_iter().enumerate() {
+                let mut key = [points[i], points[(i + 1) % 3]];
+                key.sort();
+
+                match global_edges.get(&key) {
+                    Some(global_edge) => {
+                        let global_curve = global_edge.read().curve.clone();
+
+                        let mut half_edge = half_edge.write();
+                        half_edge.curve.write().global_form = global_curve;
+                        half_edge.global_form = global_edge.clone();
+                    }
+                    None => {
+                        let global_edge = half_edge.write().infer_global_form();
+                        global_edges.insert(key, global_edge);
+                    }
+                }
+            }
+        }
+
+        let solid = PartialSolid {
+            shells: vec![Partial::from_partial(shell)],
+        }
+        .build(objects)
+        .insert(objects);
+        Ok(solid.deref().clone())
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        if self.triangles().is_empty() {
+            return Aabb::default();
+        }
+
+        Aabb::<3>::from_points(self.triangles().iter().flatten().copied())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::debug::DebugInfo;
+    use fj_kernel::services::Services;
+
+    use crate::{BrepError, Shape};
+
+    #[test]
+    fn closed_mesh_is_watertight() -> Result<(), BrepError> {
+        let [a, b, c, d] =
+            [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.], [0., 0., 1.]];
+        let tetrahedron = fj::Mesh::from_triangles(vec![
+            [a, c, b],
+            [a, b, d],
+            [b, c, d],
+            [c, a, d],
+        ]);
+
+        let mut services = Services::new();
+        let solid = tetrahedron
+            .compute_brep(&mut services.objects, &mut DebugInfo::new())?;
+
+        // Every edge is shared by two faces, and every vertex by three.
+        let mut global_edges = Vec::new();
+        let mut global_vertices = Vec::new();
+        for shell in solid.shells() {
+            assert!(shell.is_watertight());
+
+            for face in shell.faces() {
+                for half_edge in face.exterior().half_edges() {
+                    global_edges.push(half_edge.global_form().id());
+                    global_vertices
+                        .push(half_edge.start_vertex().global_form().id());
+                }
+            }
+        }
+
+        for ids in [&mut global_edges, &mut global_vertices] {
+            ids.sort();
+            ids.dedup();
+        }
+        assert_eq!(global_edges.len(), 6);
+        assert_eq!(global_vertices.len(), 4);
+
+        Ok(())
+    }
+}
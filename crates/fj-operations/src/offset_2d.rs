@@ -0,0 +1,829 @@
+use std::f64::consts::TAU;
+
+use fj_interop::debug::DebugInfo;
+use fj_kernel::{
+    objects::{Objects, Sketch},
+    services::Service,
+};
+use fj_math::{Aabb, Point, Scalar, Vector};
+
+use super::{hull::hull_sketch, BrepError, Shape};
+
+impl Shape for fj::Offset2d {
+    type Brep = Sketch;
+
+    fn compute_brep(
+        &self,
+        objects: &mut Service<Objects>,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Self::Brep, BrepError> {
+        offset_shape(self.shape(), self.distance(), self.join())?
+            .compute_brep(objects, debug_info)
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        match offset_shape(self.shape(), self.distance(), self.join()) {
+            Ok(shape) => shape.bounding_volume(),
+
+            // The error is reported when computing the boundary
+            // representation. The original shape is as good a guess as any.
+            Err(_) => self.shape().bounding_volume(),
+        }
+    }
+}
+
+/// Offset the outline of a shape
+///
+/// Positive distances move the outline outward, negative distances inward.
+/// The offset is computed on the level of the `fj` API, meaning the result is
+/// a shape that is made up of new sketches.
+///
+/// Returns an error, if the offset is too large for the shape, meaning an
+/// inward offset would remove a circle or arc, or the outline collapses. Also
+/// returns an error, if the shape contains a hull that can't be computed.
+pub(crate) fn offset_shape(
+    shape: &fj::Shape2d,
+    distance: f64,
+    join: fj::OffsetJoin,
+) -> Result<fj::Shape2d, BrepError> {
+    let shape = match shape {
+        fj::Shape2d::Difference(difference) => {
+            let [a, b] = difference.shapes();
+
+            // Whatever is subtracted from `a` needs to move in the opposite
+            // direction.
+            fj::Difference2d::from_shapes([
+                offset_shape(a, distance, join)?,
+                offset_shape(b, -distance, join)?,
+            ])
+            .into()
+        }
+        fj::Shape2d::Group(group) => fj::Group2d::from_shapes(
+            group
+                .shapes()
+                .iter()
+                .map(|shape| offset_shape(shape, distance, join))
+                .collect::<Result<_, _>>()?,
+        )
+        .into(),
+        fj::Shape2d::Hull(hull) => {
+            offset_sketch(&hull_sketch(hull)?, distance, join)?.into()
+        }
+        fj::Shape2d::Offset(offset) => {
+            let shape =
+                offset_shape(offset.shape(), offset.distance(), offset.join())?;
+            offset_shape(&shape, distance, join)?
+        }
+        fj::Shape2d::Pattern(pattern) => {
+            // Offsetting commutes with moving and rotating, so the instances
+            // of the pattern can be offset individually.
+            fj::Pattern2d::from_shape(
+                offset_shape(pattern.shape(), distance, join)?,
+                pattern.kind(),
+                pattern.count(),
+            )
+            .into()
+        }
+        fj::Shape2d::Sketch(sketch) => {
+            offset_sketch(sketch, distance, join)?.into()
+        }
+    };
+
+    Ok(shape)
+}
+
+fn offset_sketch(
+    sketch: &fj::Sketch,
+    distance: f64,
+    join: fj::OffsetJoin,
+) -> Result<fj::Sketch, OffsetError> {
+    let offset = match sketch.chain() {
+        fj::Chain::Circle(circle) => {
+            let radius = circle.radius() + distance;
+            if radius <= 0. {
+                return Err(OffsetError::CircleRemoved {
+                    distance: Scalar::from(distance),
+                    radius: Scalar::from(circle.radius()),
+                });
+            }
+
+            fj::Sketch::from_circle(fj::Circle::from_center_and_radius(
+                circle.center(),
+                radius,
+            ))
+        }
+        fj::Chain::PolyChain(poly_chain) => fj::Sketch::from_segments(
+            offset_poly_chain(poly_chain, Scalar::from(distance), join)?,
+        ),
+    };
+
+    Ok(offset.with_color(sketch.color()))
+}
+
+fn offset_poly_chain(
+    poly_chain: &fj::PolyChain,
+    distance: Scalar,
+    join: fj::OffsetJoin,
+) -> Result<Vec<fj::SketchSegment>, OffsetError> {
+    let segments = poly_chain.to_segments();
+    if segments.is_empty() {
+        return Err(OffsetError::EmptySketch);
+    }
+
+    let mut start = Point::from(segments[segments.len() - 1].endpoint);
+    let elements = segments
+        .iter()
+        .map(|segment| {
+            let element = Element::from_segment(start, segment);
+            start = element.end;
+            element
+        })
+        .collect::<Vec<_>>();
+
+    // Offsets are computed to the left of each element. For a counter-
+    // clockwise outline, that's the inside.
+    let area = elements
+        .iter()
+        .fold(Scalar::ZERO, |area, element| area + element.signed_area());
+    let left = if area > Scalar::ZERO {
+        -distance
+    } else {
+        distance
+    };
+
+    let mut offset = elements
+        .iter()
+        .map(|element| element.offset(left))
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut joins = vec![Vec::new(); elements.len()];
+
+    for i in 0..elements.len() {
+        let j = (i + 1) % elements.len();
+
+        let [end, start] = [offset[i].end, offset[j].start];
+
+        let turn = {
+            let incoming = elements[i].end_tangent();
+            let outgoing = elements[j].start_tangent();
+            let turn =
+                incoming.cross2d(&outgoing).atan2(incoming.dot(&outgoing));
+
+            // If an element doubles back on the previous one, the direction
+            // of the turn is arbitrary. The offset elements can only be
+            // connected around the tip of the resulting spike, so the corner
+            // is treated as convex.
+            if Scalar::PI - turn.abs() < Scalar::from(EPSILON) {
+                if left > Scalar::ZERO {
+                    -Scalar::PI
+                } else {
+                    Scalar::PI
+                }
+            } else {
+                turn
+            }
+        };
+
+        if turn.abs() < Scalar::from(EPSILON)
+            || (start - end).magnitude() < Scalar::from(EPSILON)
+        {
+            // The elements continue smoothly into each other.
+            offset[j].start = end;
+            continue;
+        }
+
+        if turn * left < Scalar::ZERO {
+            // The corner is convex, relative to the direction of the offset.
+            // There's a gap between the offset elements that needs to be
+            // closed.
+            let corner = elements[i].end;
+
+            match join {
+                fj::OffsetJoin::Round => joins[i].push(Element {
+                    start: end,
+                    end: start,
+                    kind: ElementKind::Arc {
+                        center: corner,
+                        radius: left.abs(),
+                        ccw: turn > Scalar::ZERO,
+                    },
+                }),
+                fj::OffsetJoin::Miter => {
+                    let incoming =
+                        Element::line(end, end + offset[i].end_tangent());
+                    let outgoing =
+                        Element::line(start, start + offset[j].start_tangent());
+
+                    // At very sharp corners, the tip of the miter would be far
+                    // away from the corner, or the extended elements wouldn't
+                    // meet at all. Those corners are beveled instead.
+                    match intersect(&incoming, &outgoing, corner) {
+                        Some(tip)
+                            if tip.distance_to(&corner)
+                                <= left.abs() * MITER_LIMIT =>
+                        {
+                            joins[i].push(Element::line(end, tip));
+                            joins[i].push(Element::line(tip, start));
+                        }
+                        _ => joins[i].push(Element::line(end, start)),
+                    }
+                }
+            }
+        } else {
+            // The corner is concave, relative to the direction of the offset.
+            // The offset elements overlap and need to be trimmed.
+            let near = end + (start - end) / 2.;
+            let intersection = intersect(&offset[i], &offset[j], near).ok_or(
+                OffsetError::TooLarge {
+                    distance,
+                    corner: elements[i].end,
+                },
+            )?;
+
+            offset[i].end = intersection;
+            offset[j].start = intersection;
+        }
+    }
+
+    // Trimming the offset elements at concave corners can flip them around,
+    // if the offset is larger than the elements are long. At that point, the
+    // outline has collapsed (at least locally).
+    for (element, offset) in elements.iter().zip(&offset) {
+        if element.is_reversed_by(offset) {
+            return Err(OffsetError::TooLarge {
+                distance,
+                corner: element.start,
+            });
+        }
+    }
+
+    let segments = offset
+        .into_iter()
+        .zip(joins)
+        .flat_map(|(element, join)| [element].into_iter().chain(join))
+        .map(|element| element.to_segment())
+        .collect();
+
+    Ok(segments)
+}
+
+/// An error that can occur when offsetting a 2D shape
+#[derive(Debug, thiserror::Error)]
+pub enum OffsetError {
+    /// The offset removes a circle
+    #[error("Offset by {distance} removes circle of radius {radius}")]
+    CircleRemoved {
+        /// The distance of the offset
+        distance: Scalar,
+
+        /// The radius of the circle
+        radius: Scalar,
+    },
+
+    /// The offset removes an arc
+    #[error("Offset by {distance} removes arc of radius {radius}")]
+    ArcRemoved {
+        /// The distance of the offset
+        distance: Scalar,
+
+        /// The radius of the arc
+        radius: Scalar,
+    },
+
+    /// The offset is too large for the sketch, and its outline collapses
+    #[error(
+        "Offset by {distance} is too large for sketch (outline collapses near \
+        {corner:?})"
+    )]
+    TooLarge {
+        /// The distance of the offset
+        distance: Scalar,
+
+        /// The corner of the sketch near which the outline collapses
+        corner: Point<2>,
+    },
+
+    /// The sketch has no segments
+    #[error("Attempted to compute an offset of an empty sketch")]
+    EmptySketch,
+}
+
+/// The epsilon used to decide whether elements continue into each other
+const EPSILON: f64 = 1e-9;
+
+/// The maximum distance of a miter's tip from the corner, relative to the
+/// offset distance
+///
+/// This is the same as the default miter limit of SVG.
+const MITER_LIMIT: f64 = 4.;
+
+#[derive(Clone, Copy, Debug)]
+struct Element {
+    start: Point<2>,
+    end: Point<2>,
+    kind: ElementKind,
+}
+
+impl Element {
+    fn line(start: Point<2>, end: Point<2>) -> Self {
+        Self {
+            start,
+            end,
+            kind: ElementKind::Line,
+        }
+    }
+
+    fn from_segment(start: Point<2>, segment: &fj::SketchSegment) -> Self {
+        let end = Point::from(segment.endpoint);
+
+        let kind = match segment.route {
+            fj::SketchSegmentRoute::Direct => ElementKind::Line,
+            fj::SketchSegmentRoute::Arc { angle } => {
+                let angle = angle.rad();
+
+                // The center is on the perpendicular bisector of the chord,
+                // to the left of it for counter-clockwise arcs.
+                let chord = end - start;
+                let left = Vector::from([-chord.v, chord.u]);
+                let center =
+                    start + chord / 2. + left / (2. * (angle / 2.).tan());
+
+                ElementKind::Arc {
+                    center,
+                    radius: (start - center).magnitude(),
+                    ccw: angle > 0.,
+                }
+            }
+        };
+
+        Self { start, end, kind }
+    }
+
+    /// The signed angle covered by an arc
+    ///
+    /// Returns zero for lines.
+    fn angle(&self) -> Scalar {
+        match self.kind {
+            ElementKind::Line => Scalar::ZERO,
+            ElementKind::Arc { center, ccw, .. } => {
+                let [start, end] = [self.start, self.end].map(|point| {
+                    let radius = point - center;
+                    radius.v.atan2(radius.u)
+                });
+
+                let mut angle = (end - start).into_f64();
+                if ccw {
+                    while angle <= 0. {
+                        angle += TAU;
+                    }
+                } else {
+                    while angle >= 0. {
+                        angle -= TAU;
+                    }
+                }
+
+                Scalar::from(angle)
+            }
+        }
+    }
+
+    /// The signed area between the element and the origin
+    ///
+    /// Summing up this value for all elements of a closed chain results in
+    /// the signed area of the chain, which is positive, if the chain is
+    /// counter-clockwise.
+    fn signed_area(&self) -> Scalar {
+        let [start, end] = [self.start, self.end].map(|point| point.coords);
+        let triangle = start.cross2d(&end) / 2.;
+
+        match self.kind {
+            ElementKind::Line => triangle,
+            ElementKind::Arc { radius, .. } => {
+                let angle = self.angle().into_f64();
+                let segment = radius * radius / 2. * (angle - angle.sin());
+                triangle + segment
+            }
+        }
+    }
+
+    fn start_tangent(&self) -> Vector<2> {
+        self.tangent_at(self.start)
+    }
+
+    fn end_tangent(&self) -> Vector<2> {
+        self.tangent_at(self.end)
+    }
+
+    fn tangent_at(&self, point: Point<2>) -> Vector<2> {
+        match self.kind {
+            ElementKind::Line => (self.end - self.start).normalize(),
+            ElementKind::Arc { center, ccw, .. } => {
+                let radial = (point - center).normalize();
+                let tangent = Vector::from([-radial.v, radial.u]);
+
+                if ccw {
+                    tangent
+                } else {
+                    -tangent
+                }
+            }
+        }
+    }
+
+    /// Indicate whether the direction of an offset element is reversed
+    ///
+    /// The direction of an arc is fixed, so if its start and end have been
+    /// trimmed past each other, it covers a larger angle than the original
+    /// instead.
+    fn is_reversed_by(&self, offset: &Self) -> bool {
+        match self.kind {
+            ElementKind::Line => {
+                (offset.end - offset.start).dot(&(self.end - self.start))
+                    <= Scalar::ZERO
+            }
+            ElementKind::Arc { .. } => {
+                offset.angle().abs()
+                    > self.angle().abs() + Scalar::from(EPSILON)
+            }
+        }
+    }
+
+    /// Offset the element to its left by the given distance
+    fn offset(&self, distance: Scalar) -> Result<Self, OffsetError> {
+        let offset = match self.kind {
+            ElementKind::Line => {
+                let direction = (self.end - self.start).normalize();
+                let left = Vector::from([-direction.v, direction.u]);
+
+                Self::line(
+                    self.start + left * distance,
+                    self.end + left * distance,
+                )
+            }
+            ElementKind::Arc {
+                center,
+                radius,
+                ccw,
+            } => {
+                // The left side of a counter-clockwise arc is the inside.
+                let offset_radius = if ccw {
+                    radius - distance
+                } else {
+                    radius + distance
+                };
+                if offset_radius <= Scalar::ZERO {
+                    return Err(OffsetError::ArcRemoved { distance, radius });
+                }
+
+                let [start, end] = [self.start, self.end].map(|point| {
+                    center + (point - center) * (offset_radius / radius)
+                });
+
+                Self {
+                    start,
+                    end,
+                    kind: ElementKind::Arc {
+                        center,
+                        radius: offset_radius,
+                        ccw,
+                    },
+                }
+            }
+        };
+
+        Ok(offset)
+    }
+
+    fn to_segment(self) -> fj::SketchSegment {
+        let route = match self.kind {
+            ElementKind::Line => fj::SketchSegmentRoute::Direct,
+            ElementKind::Arc { .. } => fj::SketchSegmentRoute::Arc {
+                angle: fj::Angle::from_rad(self.angle().into_f64()),
+            },
+        };
+
+        fj::SketchSegment {
+            endpoint: self.end.into(),
+            route,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum ElementKind {
+    Line,
+    Arc {
+        center: Point<2>,
+        radius: Scalar,
+        ccw: bool,
+    },
+}
+
+/// Intersect the curves that two elements are defined on
+///
+/// Lines are treated as infinite, arcs as full circles. If there are multiple
+/// intersections, the one closest to `near` is returned.
+fn intersect(a: &Element, b: &Element, near: Point<2>) -> Option<Point<2>> {
+    let candidates = match (a.kind, b.kind) {
+        (ElementKind::Line, ElementKind::Line) => {
+            let da = a.end - a.start;
+            let db = b.end - b.start;
+
+            let denominator = da.cross2d(&db);
+            if denominator.abs() < Scalar::from(EPSILON) {
+                return None;
+            }
+
+            let t = (b.start - a.start).cross2d(&db) / denominator;
+            vec![a.start + da * t]
+        }
+        (ElementKind::Line, ElementKind::Arc { center, radius, .. }) => {
+            intersect_line_circle(a, center, radius)
+        }
+        (ElementKind::Arc { center, radius, .. }, ElementKind::Line) => {
+            intersect_line_circle(b, center, radius)
+        }
+        (
+            ElementKind::Arc {
+                center: ca,
+                radius: ra,
+                ..
+            },
+            ElementKind::Arc {
+                center: cb,
+                radius: rb,
+                ..
+            },
+        ) => {
+            let between = cb - ca;
+            let distance = between.magnitude();
+
+            if distance < Scalar::from(EPSILON)
+                || distance > ra + rb
+                || distance < (ra - rb).abs()
+            {
+                return None;
+            }
+
+            let along =
+                (ra * ra - rb * rb + distance * distance) / (distance * 2.);
+            let across = (ra * ra - along * along)
+                .max(Scalar::ZERO)
+                .into_f64()
+                .sqrt();
+
+            let direction = between / distance;
+            let perpendicular = Vector::from([-direction.v, direction.u]);
+            let base = ca + direction * along;
+
+            vec![base + perpendicular * across, base - perpendicular * across]
+        }
+    };
+
+    candidates
+        .into_iter()
+        .min_by_key(|point| point.distance_to(&near))
+}
+
+fn intersect_line_circle(
+    line: &Element,
+    center: Point<2>,
+    radius: Scalar,
+) -> Vec<Point<2>> {
+    let direction = (line.end - line.start).normalize();
+    let from_center = line.start - center;
+
+    let b = from_center.dot(&direction);
+    let c = from_center.dot(&from_center) - radius * radius;
+
+    let discriminant = b * b - c;
+    if discriminant < Scalar::ZERO {
+        return Vec::new();
+    }
+
+    let root = discriminant.into_f64().sqrt();
+    [-b + root, -b - root]
+        .into_iter()
+        .map(|t| line.start + direction * t)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use fj_math::{Point, Scalar};
+
+    use crate::BrepError;
+
+    use super::{offset_shape, Element, OffsetError, MITER_LIMIT};
+
+    const SQUARE: [[f64; 2]; 4] = [[0., 0.], [2., 0.], [2., 2.], [0., 2.]];
+
+    #[test]
+    fn polygon_outward() -> Result<(), BrepError> {
+        let square = polygon(SQUARE);
+        let clockwise = polygon(SQUARE.into_iter().rev());
+
+        for square in [square, clockwise] {
+            let miter = offset_shape(&square, 1., fj::OffsetJoin::Miter)?;
+            let round = offset_shape(&square, 1., fj::OffsetJoin::Round)?;
+
+            assert_area(&miter, 16.);
+            assert_area(&round, 12. + PI);
+        }
+
+        // The concave corner of this L-shape needs to be trimmed.
+        let l_shape = polygon([
+            [0., 0.],
+            [2., 0.],
+            [2., 1.],
+            [1., 1.],
+            [1., 2.],
+            [0., 2.],
+        ]);
+        let offset = offset_shape(&l_shape, 0.25, fj::OffsetJoin::Miter)?;
+        assert_area(&offset, 5.25);
+
+        Ok(())
+    }
+
+    #[test]
+    fn polygon_inward() -> Result<(), BrepError> {
+        let square = polygon(SQUARE);
+
+        for join in [fj::OffsetJoin::Miter, fj::OffsetJoin::Round] {
+            let offset = offset_shape(&square, -0.5, join)?;
+            assert_area(&offset, 1.);
+        }
+
+        // A half-disk with a radius of 1, attached to a 2x2 square.
+        let rounded = fj::Sketch::from_segments(vec![
+            line([2., 0.]),
+            fj::SketchSegment {
+                endpoint: [2., 2.],
+                route: fj::SketchSegmentRoute::Arc {
+                    angle: fj::Angle::from_rad(PI),
+                },
+            },
+            line([0., 2.]),
+            line([0., 0.]),
+        ])
+        .into();
+        let offset = offset_shape(&rounded, -0.5, fj::OffsetJoin::Round)?;
+        assert_area(&offset, 1.5 + PI / 8.);
+
+        Ok(())
+    }
+
+    #[test]
+    fn polygon_collapse() {
+        let square = polygon(SQUARE);
+
+        for distance in [-1., -1.5] {
+            let offset = offset_shape(&square, distance, fj::OffsetJoin::Miter);
+            assert!(matches!(
+                offset,
+                Err(BrepError::Offset(OffsetError::TooLarge { .. }))
+            ));
+        }
+
+        let empty = fj::Sketch::from_segments(vec![]).into();
+        let offset = offset_shape(&empty, 1., fj::OffsetJoin::Miter);
+        assert!(matches!(
+            offset,
+            Err(BrepError::Offset(OffsetError::EmptySketch))
+        ));
+    }
+
+    #[test]
+    fn circle() -> Result<(), BrepError> {
+        let circle = fj::Sketch::from_circle(
+            fj::Circle::from_center_and_radius([3., 4.], 2.),
+        )
+        .into();
+
+        for (distance, radius) in [(1., 3.), (-1., 1.)] {
+            let offset =
+                offset_shape(&circle, distance, fj::OffsetJoin::Miter)?;
+
+            let fj::Shape2d::Sketch(sketch) = offset else {
+                panic!("Expected offset of sketch to be a sketch");
+            };
+            let fj::Chain::Circle(offset) = sketch.chain() else {
+                panic!("Expected offset of circle to be a circle");
+            };
+
+            assert_eq!(offset.center(), [3., 4.]);
+            assert_eq!(offset.radius(), radius);
+        }
+
+        for distance in [-2., -3.] {
+            let offset = offset_shape(&circle, distance, fj::OffsetJoin::Round);
+            assert!(matches!(
+                offset,
+                Err(BrepError::Offset(OffsetError::CircleRemoved { .. }))
+            ));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn arc_collapse() {
+        let rounded = fj::Sketch::from_segments(vec![
+            line([2., 0.]),
+            fj::SketchSegment {
+                endpoint: [2., 2.],
+                route: fj::SketchSegmentRoute::Arc {
+                    angle: fj::Angle::from_rad(PI),
+                },
+            },
+            line([0., 2.]),
+            line([0., 0.]),
+        ])
+        .into();
+
+        let offset = offset_shape(&rounded, -1., fj::OffsetJoin::Round);
+        assert!(matches!(
+            offset,
+            Err(BrepError::Offset(OffsetError::ArcRemoved { .. }))
+        ));
+    }
+
+    #[test]
+    fn spike() -> Result<(), BrepError> {
+        // The segments of the spike double back on each other at its tip.
+        let spike = polygon([
+            [0., 0.],
+            [2., 0.],
+            [2., 2.],
+            [1., 2.],
+            [1., 3.],
+            [1., 2.],
+            [0., 2.],
+        ]);
+
+        let miter = offset_shape(&spike, 0.5, fj::OffsetJoin::Miter)?;
+        let round = offset_shape(&spike, 0.5, fj::OffsetJoin::Round)?;
+
+        assert_area(&miter, 9. + 0.5);
+        assert_area(&round, 8. + PI / 4. + 0.5 + PI / 8.);
+
+        Ok(())
+    }
+
+    #[test]
+    fn miter_limit() -> Result<(), BrepError> {
+        // The tip of this triangle is so sharp, that a miter would extend 10
+        // times the offset distance beyond it.
+        let triangle = polygon([[0., 0.], [1., 0.], [0.5, 10.]]);
+
+        let offset = offset_shape(&triangle, 0.1, fj::OffsetJoin::Miter)?;
+
+        let fj::Shape2d::Sketch(sketch) = offset else {
+            panic!("Expected offset of sketch to be a sketch");
+        };
+        let fj::Chain::PolyChain(poly_chain) = sketch.chain() else {
+            panic!("Expected offset of polygon to be a polygon");
+        };
+        for segment in poly_chain.to_segments() {
+            let [_, y] = segment.endpoint;
+            assert!(y < 10. + 0.1 * MITER_LIMIT, "{y} is beyond miter limit");
+        }
+
+        Ok(())
+    }
+
+    fn polygon(points: impl IntoIterator<Item = [f64; 2]>) -> fj::Shape2d {
+        fj::Sketch::from_points(points.into_iter().collect()).into()
+    }
+
+    fn line(endpoint: [f64; 2]) -> fj::SketchSegment {
+        fj::SketchSegment {
+            endpoint,
+            route: fj::SketchSegmentRoute::Direct,
+        }
+    }
+
+    fn assert_area(shape: &fj::Shape2d, expected: f64) {
+        let fj::Shape2d::Sketch(sketch) = shape else {
+            panic!("Expected offset of sketch to be a sketch");
+        };
+        let fj::Chain::PolyChain(poly_chain) = sketch.chain() else {
+            panic!("Expected offset of polygon to be a polygon");
+        };
+
+        let segments = poly_chain.to_segments();
+        let mut start = Point::from(segments[segments.len() - 1].endpoint);
+        let area = segments.iter().fold(Scalar::ZERO, |area, segment| {
+            let element = Element::from_segment(start, segment);
+            start = element.end;
+            area + element.signed_area()
+        });
+
+        assert!(
+            (area.abs() - expected).abs() < Scalar::from(1e-9),
+            "Expected area {expected}, got {area}"
+        );
+    }
+}
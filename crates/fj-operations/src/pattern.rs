@@ -0,0 +1,342 @@
+use std::ops::Deref;
+
+use fj_interop::debug::DebugInfo;
+use fj_kernel::{
+    algorithms::transform::TransformObject,
+    insert::Insert,
+    objects::{FaceSet, Objects, Sketch},
+    partial::{Partial, PartialObject, PartialSketch},
+    services::Service,
+};
+use fj_math::{Aabb, Point, Transform, Vector};
+
+use super::{BrepError, Shape};
+
+impl Shape for fj::Pattern {
+    type Brep = FaceSet;
+
+    fn compute_brep(
+        &self,
+        objects: &mut Service<Objects>,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Self::Brep, BrepError> {
+        let original = self.shape().compute_brep(objects, debug_info)?;
+
+        let mut faces = FaceSet::new();
+        for transform in transforms(self) {
+            faces.extend(original.clone().transform(&transform, objects));
+        }
+
+        Ok(faces)
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        let original = self.shape().bounding_volume();
+
+        transforms(self)
+            .map(|transform| transform.transform_aabb(&original))
+            .reduce(|a, b| a.merged(&b))
+            .unwrap_or_default()
+    }
+}
+
+impl Shape for fj::Pattern2d {
+    type Brep = Sketch;
+
+    fn compute_brep(
+        &self,
+        objects: &mut Service<Objects>,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Self::Brep, BrepError> {
+        // The instances are created on the level of the `fj` API. That way,
+        // they are all defined in the same surface as the original shape,
+        // which is required, if they are to be used in a 2D difference.
+        let mut faces = Vec::new();
+        for transform in transforms_2d(self) {
+            let instance = transform_shape_2d(self.shape(), &transform)
+                .compute_brep(objects, debug_info)?;
+            faces.extend(
+                instance.faces().into_iter().cloned().map(Partial::from),
+            );
+        }
+
+        let sketch = PartialSketch { faces }.build(objects).insert(objects);
+        Ok(sketch.deref().clone())
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        let original = self.shape().bounding_volume();
+
+        transforms_2d(self)
+            .map(|transform| transform.transform_aabb(&original))
+            .reduce(|a, b| a.merged(&b))
+            .unwrap_or_default()
+    }
+}
+
+fn transforms(pattern: &fj::Pattern) -> impl Iterator<Item = Transform> {
+    let kind = pattern.kind();
+
+    (0..pattern.count()).map(move |i| {
+        let i = f64::from(i);
+
+        match kind {
+            fj::PatternKind::Linear { offset } => {
+                Transform::translation(Vector::from(offset) * i)
+            }
+            fj::PatternKind::Circular { axis, angle } => {
+                let axis = Vector::from(axis).normalize();
+                Transform::rotation(axis * angle.rad() * i)
+            }
+        }
+    })
+}
+
+pub(crate) fn transforms_2d(
+    pattern: &fj::Pattern2d,
+) -> impl Iterator<Item = Transform> {
+    let kind = pattern.kind();
+
+    (0..pattern.count()).map(move |i| {
+        let i = f64::from(i);
+
+        match kind {
+            fj::PatternKind2d::Linear { offset: [x, y] } => {
+                Transform::translation([x * i, y * i, 0.])
+            }
+            fj::PatternKind2d::Circular {
+                center: [x, y],
+                angle,
+            } => {
+                Transform::translation([x, y, 0.])
+                    * Transform::rotation(Vector::from([
+                        0.,
+                        0.,
+                        angle.rad() * i,
+                    ]))
+                    * Transform::translation([-x, -y, 0.])
+            }
+        }
+    })
+}
+
+/// Apply a transform within the xy-plane to a 2D shape
+///
+/// The transform must not move anything out of the xy-plane, and must not
+/// mirror anything. Otherwise, arcs would end up with the wrong direction.
+pub(crate) fn transform_shape_2d(
+    shape: &fj::Shape2d,
+    transform: &Transform,
+) -> fj::Shape2d {
+    match shape {
+        fj::Shape2d::Difference(difference) => {
+            let [a, b] = difference.shapes();
+
+            fj::Difference2d::from_shapes([
+                transform_shape_2d(a, transform),
+                transform_shape_2d(b, transform),
+            ])
+            .into()
+        }
+        fj::Shape2d::Group(group) => fj::Group2d::from_shapes(
+            group
+                .shapes()
+                .iter()
+                .map(|shape| transform_shape_2d(shape, transform))
+                .collect(),
+        )
+        .into(),
+        fj::Shape2d::Hull(hull) => fj::Hull2d::from_shapes(
+            hull.shapes()
+                .iter()
+                .map(|shape| transform_shape_2d(shape, transform))
+                .collect(),
+        )
+        .into(),
+        fj::Shape2d::Offset(offset) => fj::Offset2d::from_shape(
+            transform_shape_2d(offset.shape(), transform),
+            offset.distance(),
+            offset.join(),
+        )
+        .into(),
+        fj::Shape2d::Pattern(pattern) => {
+            // Moving and rotating a pattern is the same as creating the same
+            // pattern of the moved and rotated shape, with the pattern itself
+            // moved and rotated accordingly.
+            let kind = match pattern.kind() {
+                fj::PatternKind2d::Linear { offset } => {
+                    let offset = transform
+                        .transform_vector(&Vector::from(offset).to_xyz());
+                    fj::PatternKind2d::Linear {
+                        offset: [offset.x.into_f64(), offset.y.into_f64()],
+                    }
+                }
+                fj::PatternKind2d::Circular { center, angle } => {
+                    fj::PatternKind2d::Circular {
+                        center: transform_point_2d(center, transform),
+                        angle,
+                    }
+                }
+            };
+
+            fj::Pattern2d::from_shape(
+                transform_shape_2d(pattern.shape(), transform),
+                kind,
+                pattern.count(),
+            )
+            .into()
+        }
+        fj::Shape2d::Sketch(sketch) => {
+            transform_sketch(sketch, transform).into()
+        }
+    }
+}
+
+fn transform_sketch(sketch: &fj::Sketch, transform: &Transform) -> fj::Sketch {
+    let transformed = match sketch.chain() {
+        fj::Chain::Circle(circle) => {
+            fj::Sketch::from_circle(fj::Circle::from_center_and_radius(
+                transform_point_2d(circle.center(), transform),
+                circle.radius(),
+            ))
+        }
+        fj::Chain::PolyChain(poly_chain) => {
+            let segments = poly_chain
+                .to_segments()
+                .into_iter()
+                .map(|fj::SketchSegment { endpoint, route }| {
+                    fj::SketchSegment {
+                        endpoint: transform_point_2d(endpoint, transform),
+                        route,
+                    }
+                })
+                .collect();
+
+            fj::Sketch::from_segments(segments)
+        }
+    };
+
+    transformed.with_color(sketch.color())
+}
+
+fn transform_point_2d(point: [f64; 2], transform: &Transform) -> [f64; 2] {
+    let point = transform.transform_point(&Point::from(point).to_xyz());
+    [point.x.into_f64(), point.y.into_f64()]
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::debug::DebugInfo;
+    use fj_kernel::services::Services;
+    use fj_math::{Aabb, Point, Scalar};
+
+    use crate::{BrepError, Shape};
+
+    fn square() -> fj::Sketch {
+        fj::Sketch::from_points(vec![[1., 0.], [2., 0.], [2., 1.], [1., 1.]])
+    }
+
+    fn assert_aabb(aabb: Aabb<3>, min: [f64; 3], max: [f64; 3]) {
+        let [min, max] = [min, max].map(Point::from);
+        assert!(
+            aabb.min.distance_to(&min) < Scalar::from(1e-12)
+                && aabb.max.distance_to(&max) < Scalar::from(1e-12),
+            "Unexpected bounding volume: {aabb:?}"
+        );
+    }
+
+    #[test]
+    fn pattern_2d() -> Result<(), BrepError> {
+        let mut services = Services::new();
+
+        let linear = fj::Pattern2d::from_shape(
+            square().into(),
+            fj::PatternKind2d::Linear { offset: [2., 1.] },
+            3,
+        );
+        let sketch = linear
+            .compute_brep(&mut services.objects, &mut DebugInfo::new())?;
+        assert_eq!(sketch.faces().into_iter().count(), 3);
+        assert_aabb(linear.bounding_volume(), [1., 0., 0.], [6., 3., 0.]);
+
+        let circular = fj::Pattern2d::from_shape(
+            square().into(),
+            fj::PatternKind2d::Circular {
+                center: [0., 0.],
+                angle: fj::Angle::from_deg(90.),
+            },
+            4,
+        );
+        let sketch = circular
+            .compute_brep(&mut services.objects, &mut DebugInfo::new())?;
+        assert_eq!(sketch.faces().into_iter().count(), 4);
+        assert_aabb(circular.bounding_volume(), [-2., -2., 0.], [2., 2., 0.]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn pattern_3d() -> Result<(), BrepError> {
+        let mut services = Services::new();
+
+        let sweep = fj::Sweep::from_path(square().into(), [0., 0., 1.]);
+
+        let linear = fj::Pattern::from_shape(
+            sweep.clone().into(),
+            fj::PatternKind::Linear {
+                offset: [0., 0., 2.],
+            },
+            3,
+        );
+        let faces = linear
+            .compute_brep(&mut services.objects, &mut DebugInfo::new())?;
+        assert_eq!(faces.into_iter().count(), 3 * 6);
+        assert_aabb(linear.bounding_volume(), [1., 0., 0.], [2., 1., 5.]);
+
+        let circular = fj::Pattern::from_shape(
+            sweep.into(),
+            fj::PatternKind::Circular {
+                axis: [0., 0., 1.],
+                angle: fj::Angle::from_deg(90.),
+            },
+            4,
+        );
+        let faces = circular
+            .compute_brep(&mut services.objects, &mut DebugInfo::new())?;
+        assert_eq!(faces.into_iter().count(), 4 * 6);
+        assert_aabb(circular.bounding_volume(), [-2., -2., 0.], [2., 2., 1.]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn empty_pattern() -> Result<(), BrepError> {
+        let mut services = Services::new();
+
+        let pattern_2d = fj::Pattern2d::from_shape(
+            square().into(),
+            fj::PatternKind2d::Linear { offset: [2., 0.] },
+            0,
+        );
+        let sketch = pattern_2d
+            .compute_brep(&mut services.objects, &mut DebugInfo::new())?;
+        let aabb = pattern_2d.bounding_volume();
+        assert_eq!(sketch.faces().into_iter().count(), 0);
+        assert_eq!(aabb.min, aabb.max);
+
+        let pattern = fj::Pattern::from_shape(
+            fj::Sweep::from_path(square().into(), [0., 0., 1.]).into(),
+            fj::PatternKind::Linear {
+                offset: [2., 0., 0.],
+            },
+            0,
+        );
+        let faces = pattern
+            .compute_brep(&mut services.objects, &mut DebugInfo::new())?;
+        let aabb = pattern.bounding_volume();
+        assert_eq!(faces.into_iter().count(), 0);
+        assert_eq!(aabb.min, aabb.max);
+
+        Ok(())
+    }
+}
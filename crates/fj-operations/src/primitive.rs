@@ -0,0 +1,250 @@
+use std::ops::Deref;
+
+use fj_interop::{debug::DebugInfo, mesh::Color};
+use fj_kernel::{
+    builder::SolidBuilder,
+    insert::Insert,
+    objects::{Objects, Solid},
+    partial::{PartialObject, PartialSolid},
+    services::Service,
+};
+use fj_math::{Aabb, Point};
+
+use super::{BrepError, Shape};
+
+impl Shape for fj::Primitive {
+    type Brep = Solid;
+
+    fn compute_brep(
+        &self,
+        objects: &mut Service<Objects>,
+        _: &mut DebugInfo,
+    ) -> Result<Self::Brep, BrepError> {
+        validate(self)?;
+
+        let mut solid = PartialSolid::default();
+
+        let mut shell = match self {
+            Self::Cone(cone) => {
+                solid.add_cone(cone.radius(), cone.height(), cone.tolerance())
+            }
+            Self::Cuboid(cuboid) => solid.add_cuboid(cuboid.size()),
+            Self::Cylinder(cylinder) => {
+                solid.add_cylinder(cylinder.radius(), cylinder.height())
+            }
+            Self::Sphere(sphere) => {
+                solid.add_sphere(sphere.radius(), sphere.tolerance())
+            }
+            Self::Torus(torus) => solid.add_torus(
+                torus.major_radius(),
+                torus.minor_radius(),
+                torus.tolerance(),
+            ),
+        };
+
+        for face in &mut shell.write().faces {
+            face.write().color = Some(Color(self.color()));
+        }
+
+        let solid = solid.build(objects).insert(objects);
+        Ok(solid.deref().clone())
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        let [min, max] = match self {
+            Self::Cone(cone) => {
+                let r = cone.radius();
+                [[-r, -r, 0.], [r, r, cone.height()]]
+            }
+            Self::Cuboid(cuboid) => {
+                let [x, y, z] = cuboid.size().map(|s| s / 2.);
+                [[-x, -y, -z], [x, y, z]]
+            }
+            Self::Cylinder(cylinder) => {
+                let r = cylinder.radius();
+                [[-r, -r, 0.], [r, r, cylinder.height()]]
+            }
+            Self::Sphere(sphere) => {
+                let r = sphere.radius();
+                [[-r, -r, -r], [r, r, r]]
+            }
+            Self::Torus(torus) => {
+                let r = torus.major_radius() + torus.minor_radius();
+                let z = torus.minor_radius();
+                [[-r, -r, -z], [r, r, z]]
+            }
+        };
+
+        Aabb {
+            min: Point::from(min),
+            max: Point::from(max),
+        }
+    }
+}
+
+/// Check that the dimensions of a primitive describe a valid solid
+fn validate(primitive: &fj::Primitive) -> Result<(), PrimitiveError> {
+    let radius = |radius: f64| {
+        if is_positive(radius) {
+            Ok(())
+        } else {
+            Err(PrimitiveError::InvalidRadius { radius })
+        }
+    };
+    let height = |height: f64| {
+        if is_positive(height) {
+            Ok(())
+        } else {
+            Err(PrimitiveError::InvalidHeight { height })
+        }
+    };
+    let tolerance = |tolerance: f64| {
+        if is_positive(tolerance) {
+            Ok(())
+        } else {
+            Err(PrimitiveError::InvalidTolerance { tolerance })
+        }
+    };
+
+    match primitive {
+        fj::Primitive::Cone(cone) => {
+            radius(cone.radius())?;
+            height(cone.height())?;
+            tolerance(cone.tolerance())?;
+        }
+        fj::Primitive::Cuboid(cuboid) => {
+            let size = cuboid.size();
+            if !size.into_iter().all(is_positive) {
+                return Err(PrimitiveError::InvalidSize { size });
+            }
+        }
+        fj::Primitive::Cylinder(cylinder) => {
+            radius(cylinder.radius())?;
+            height(cylinder.height())?;
+        }
+        fj::Primitive::Sphere(sphere) => {
+            radius(sphere.radius())?;
+            tolerance(sphere.tolerance())?;
+        }
+        fj::Primitive::Torus(torus) => {
+            radius(torus.major_radius())?;
+            radius(torus.minor_radius())?;
+            tolerance(torus.tolerance())?;
+
+            if torus.minor_radius() >= torus.major_radius() {
+                return Err(PrimitiveError::TubeTooWide {
+                    major_radius: torus.major_radius(),
+                    minor_radius: torus.minor_radius(),
+                });
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn is_positive(value: f64) -> bool {
+    value > 0. && value.is_finite()
+}
+
+/// An error that can occur when computing a primitive
+#[derive(Debug, thiserror::Error)]
+pub enum PrimitiveError {
+    /// A size of a cuboid is not positive
+    #[error("Size {size:?} of cuboid is not positive")]
+    InvalidSize {
+        /// The size of the cuboid
+        size: [f64; 3],
+    },
+
+    /// A radius is not positive
+    #[error("Radius {radius} of primitive is not positive")]
+    InvalidRadius {
+        /// The radius
+        radius: f64,
+    },
+
+    /// The height of a cylinder or cone is not positive
+    #[error("Height {height} of primitive is not positive")]
+    InvalidHeight {
+        /// The height
+        height: f64,
+    },
+
+    /// The tolerance of an approximated primitive is not positive
+    ///
+    /// By default, the tolerance is relative to the radius, so this is also
+    /// the case if the radius is not positive.
+    #[error("Tolerance {tolerance} of primitive is not positive")]
+    InvalidTolerance {
+        /// The tolerance
+        tolerance: f64,
+    },
+
+    /// The tube of a torus is not smaller than the torus' major radius
+    ///
+    /// The torus would intersect itself at its center.
+    #[error(
+        "Minor radius {minor_radius} of torus is not smaller than major \
+        radius {major_radius}"
+    )]
+    TubeTooWide {
+        /// The major radius of the torus
+        major_radius: f64,
+
+        /// The minor radius of the torus
+        minor_radius: f64,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::debug::DebugInfo;
+    use fj_kernel::services::Services;
+
+    use crate::{BrepError, Shape};
+
+    #[test]
+    fn invalid_dimensions() {
+        let primitives: [fj::Primitive; 9] = [
+            fj::Cuboid::from_size([1., 0., 1.]).into(),
+            fj::Cylinder::from_radius_and_height(-1., 1.).into(),
+            fj::Cylinder::from_radius_and_height(1., 0.).into(),
+            fj::Cone::from_radius_and_height(1., f64::NAN).into(),
+            fj::Cone::from_radius_and_height(1., 1.)
+                .with_tolerance(0.)
+                .into(),
+            fj::Sphere::from_radius(0.).into(),
+            fj::Sphere::from_radius(f64::INFINITY).into(),
+            fj::Torus::from_radii(1., -0.5).into(),
+            fj::Torus::from_radii(1., 1.).into(),
+        ];
+
+        for primitive in primitives {
+            let mut services = Services::new();
+            let result = primitive
+                .compute_brep(&mut services.objects, &mut DebugInfo::new());
+
+            assert!(
+                matches!(result, Err(BrepError::Primitive(_))),
+                "{primitive:?} should be invalid"
+            );
+        }
+    }
+
+    #[test]
+    fn tolerance_larger_than_primitive() -> Result<(), BrepError> {
+        let mut services = Services::new();
+
+        let sphere: fj::Primitive =
+            fj::Sphere::from_radius(1.).with_tolerance(10.).into();
+        let solid = sphere
+            .compute_brep(&mut services.objects, &mut DebugInfo::new())?;
+
+        for shell in solid.shells() {
+            assert!(shell.is_watertight());
+        }
+
+        Ok(())
+    }
+}
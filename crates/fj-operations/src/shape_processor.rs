@@ -1,27 +1,51 @@
 //! API for processing shapes
 
-use fj_interop::{debug::DebugInfo, processed_shape::ProcessedShape};
+use std::{collections::BTreeMap, fs::File, io, path::PathBuf};
+
+use fj_interop::{
+    bvh::MeshBvh,
+    debug::DebugInfo,
+    mesh::{FaceId, Mesh},
+    processed_shape::{FaceInfo, ProcessedShape, SurfaceKind},
+};
 use fj_kernel::{
     algorithms::{
         approx::{InvalidTolerance, Tolerance},
         triangulate::Triangulate,
     },
+    geometry::path::GlobalPath,
+    objects::FaceSet,
     services::Services,
     validate::ValidationError,
 };
-use fj_math::Scalar;
+use fj_math::{Point, Scalar};
 
-use crate::Shape as _;
+use crate::{BrepError, Shape as _};
 
 /// Processes an [`fj::Shape`] into a [`ProcessedShape`]
 pub struct ShapeProcessor {
     /// The tolerance value used for creating the triangle mesh
     pub tolerance: Option<Tolerance>,
+
+    /// The file that the debug info is written to, after each processing
+    pub debug_dump: Option<PathBuf>,
 }
 
 impl ShapeProcessor {
     /// Process an [`fj::Shape`] into [`ProcessedShape`]
     pub fn process(&self, shape: &fj::Shape) -> Result<ProcessedShape, Error> {
+        let (processed_shape, _) = self.process_with_brep(shape)?;
+        Ok(processed_shape)
+    }
+
+    /// Process an [`fj::Shape`] into [`ProcessedShape`], keeping its B-rep
+    ///
+    /// Unlike the triangle mesh, the faces of the B-rep represent the shape
+    /// exactly. This is required for exporting to some file formats.
+    pub fn process_with_brep(
+        &self,
+        shape: &fj::Shape,
+    ) -> Result<(ProcessedShape, FaceSet), Error> {
         let aabb = shape.bounding_volume();
 
         let tolerance = match self.tolerance {
@@ -44,17 +68,59 @@ impl ShapeProcessor {
 
         let mut services = Services::new();
         let mut debug_info = DebugInfo::new();
-        let shape = shape.compute_brep(&mut services.objects, &mut debug_info);
-        let mesh = (&shape, tolerance).triangulate();
+        let shape =
+            shape.compute_brep(&mut services.objects, &mut debug_info)?;
+        let mesh =
+            (&shape, tolerance).triangulate_with_debug_info(&mut debug_info);
+        let faces = face_infos(&shape, &mesh);
 
-        Ok(ProcessedShape {
+        if let Some(path) = &self.debug_dump {
+            debug_info.write_to(io::BufWriter::new(File::create(path)?))?;
+        }
+
+        let processed_shape = ProcessedShape {
             aabb,
+            bvh: MeshBvh::new(&mesh),
             mesh,
+            faces,
             debug_info,
-        })
+        };
+
+        Ok((processed_shape, shape))
     }
 }
 
+fn face_infos(
+    faces: &FaceSet,
+    mesh: &Mesh<Point<3>>,
+) -> BTreeMap<FaceId, FaceInfo> {
+    faces
+        .into_iter()
+        .map(|face| {
+            let id = FaceId::from(face.id());
+
+            let surface = match face.surface().geometry().u {
+                GlobalPath::Line(_) => SurfaceKind::Plane,
+                GlobalPath::Circle(_) => SurfaceKind::Cylinder,
+                GlobalPath::Helix(_) => SurfaceKind::Helical,
+            };
+            let area = mesh
+                .triangles_of_face(id)
+                .fold(Scalar::ZERO, |area, triangle| {
+                    area + triangle.inner.area()
+                });
+
+            let info = FaceInfo {
+                color: face.color(),
+                surface,
+                area,
+            };
+
+            (id, info)
+        })
+        .collect()
+}
+
 /// A shape processing error
 #[derive(Debug, thiserror::Error)]
 pub enum Error {
@@ -62,7 +128,15 @@ pub enum Error {
     #[error("Error converting to shape")]
     ToShape(#[from] ValidationError),
 
+    /// Error computing the boundary representation of the shape
+    #[error("Error computing boundary representation")]
+    Brep(#[from] BrepError),
+
     /// Model has zero size
     #[error("Model has zero size")]
     Extent(#[from] InvalidTolerance),
+
+    /// Error writing the debug info to a file
+    #[error("Error writing debug info")]
+    DebugDump(#[from] io::Error),
 }
@@ -13,7 +13,7 @@ use fj_kernel::{
 };
 use fj_math::{Aabb, Point};
 
-use super::Shape;
+use super::{BrepError, Shape};
 
 impl Shape for fj::Sketch {
     type Brep = Sketch;
@@ -22,7 +22,7 @@ impl Shape for fj::Sketch {
         &self,
         objects: &mut Service<Objects>,
         _: &mut DebugInfo,
-    ) -> Self::Brep {
+    ) -> Result<Self::Brep, BrepError> {
         let surface = objects.surfaces.xy_plane();
 
         let face = match self.chain() {
@@ -38,7 +38,10 @@ impl Shape for fj::Sketch {
                         vertex.1.write().surface = surface.clone();
                     }
 
-                    half_edge.update_as_circle_from_radius(circle.radius());
+                    half_edge.update_as_circle_from_center_and_radius(
+                        circle.center(),
+                        circle.radius(),
+                    );
 
                     Partial::from_partial(half_edge)
                 };
@@ -68,30 +71,26 @@ impl Shape for fj::Sketch {
                     };
                     let mut line_segments = vec![];
                     let mut arcs = vec![];
-                    poly_chain.to_segments().into_iter().for_each(
-                        |fj::SketchSegment { endpoint, route }| {
-                            let endpoint = Point::from(endpoint);
-                            match route {
-                                fj::SketchSegmentRoute::Direct => {
-                                    line_segments.push(
-                                        cycle
-                                            .add_half_edge_from_point_to_start(
-                                                endpoint,
-                                            ),
-                                    );
-                                }
-                                fj::SketchSegmentRoute::Arc { angle } => {
-                                    arcs.push((
-                                        cycle
-                                            .add_half_edge_from_point_to_start(
-                                                endpoint,
-                                            ),
-                                        angle,
-                                    ));
-                                }
+
+                    // Each segment starts at the endpoint of the previous one,
+                    // and its route leads from there to its own endpoint.
+                    let mut start = segments[segments.len() - 1].endpoint;
+                    for fj::SketchSegment { endpoint, route } in segments {
+                        let half_edge = cycle
+                            .add_half_edge_from_point_to_start(Point::from(
+                                start,
+                            ));
+                        match route {
+                            fj::SketchSegmentRoute::Direct => {
+                                line_segments.push(half_edge);
                             }
-                        },
-                    );
+                            fj::SketchSegmentRoute::Arc { angle } => {
+                                arcs.push((half_edge, angle));
+                            }
+                        }
+
+                        start = endpoint;
+                    }
                     line_segments.into_iter().for_each(|mut half_edge| {
                         half_edge.write().update_as_line_segment()
                     });
@@ -114,15 +113,20 @@ impl Shape for fj::Sketch {
         }
         .build(objects)
         .insert(objects);
-        sketch.deref().clone()
+        Ok(sketch.deref().clone())
     }
 
     fn bounding_volume(&self) -> Aabb<3> {
         match self.chain() {
-            fj::Chain::Circle(circle) => Aabb {
-                min: Point::from([-circle.radius(), -circle.radius(), 0.0]),
-                max: Point::from([circle.radius(), circle.radius(), 0.0]),
-            },
+            fj::Chain::Circle(circle) => {
+                let [x, y] = circle.center();
+                let r = circle.radius();
+
+                Aabb {
+                    min: Point::from([x - r, y - r, 0.0]),
+                    max: Point::from([x + r, y + r, 0.0]),
+                }
+            }
             fj::Chain::PolyChain(poly_chain) => {
                 let segments = poly_chain.to_segments();
                 assert!(
@@ -143,25 +147,24 @@ impl Shape for fj::Sketch {
                                 segment.endpoint,
                                 fj_math::Scalar::from_f64(angle.rad()),
                             );
-                            for circle_minmax_angle in
-                                [0., PI / 2., PI, 3. * PI / 2.]
-                            {
-                                let mm_angle = fj_math::Scalar::from_f64(
-                                    circle_minmax_angle,
+
+                            // The arc's extremes are at multiples of a quarter
+                            // turn. Its angles aren't normalized to a single
+                            // turn, so consider all multiples in between.
+                            let [first, last] =
+                                [arc.start_angle, arc.end_angle]
+                                    .map(|angle| angle.into_f64() / (PI / 2.));
+                            let [first, last] =
+                                [first.floor() as i64 + 1, last.ceil() as i64];
+                            for i in first..last {
+                                let mm_angle = i as f64 * PI / 2.;
+                                points.push(
+                                    arc.center
+                                        + [
+                                            arc.radius * mm_angle.cos(),
+                                            arc.radius * mm_angle.sin(),
+                                        ],
                                 );
-                                if arc.start_angle < mm_angle
-                                    && mm_angle < arc.end_angle
-                                {
-                                    points.push(
-                                        arc.center
-                                            + [
-                                                arc.radius
-                                                    * circle_minmax_angle.cos(),
-                                                arc.radius
-                                                    * circle_minmax_angle.sin(),
-                                            ],
-                                    );
-                                }
                             }
                         }
                     }
@@ -174,3 +177,66 @@ impl Shape for fj::Sketch {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use fj_interop::debug::DebugInfo;
+    use fj_kernel::{geometry::path::SurfacePath, services::Services};
+    use fj_math::{Point, Scalar};
+
+    use crate::Shape;
+
+    #[test]
+    fn routes_lead_to_endpoints() -> Result<(), crate::BrepError> {
+        // The sketch starts at a corner where the polygon's winding can only
+        // be determined by taking the closing edge into account.
+        let sketch = fj::Sketch::from_segments(vec![
+            fj::SketchSegment {
+                endpoint: [0., 0.],
+                route: fj::SketchSegmentRoute::Direct,
+            },
+            fj::SketchSegment {
+                endpoint: [2., 0.],
+                route: fj::SketchSegmentRoute::Direct,
+            },
+            fj::SketchSegment {
+                endpoint: [2., 2.],
+                route: fj::SketchSegmentRoute::Arc {
+                    angle: fj::Angle::from_rad(PI),
+                },
+            },
+            fj::SketchSegment {
+                endpoint: [0., 2.],
+                route: fj::SketchSegmentRoute::Direct,
+            },
+        ]);
+
+        let mut services = Services::new();
+        let sketch = sketch
+            .compute_brep(&mut services.objects, &mut DebugInfo::new())?;
+
+        let face = sketch.faces().into_iter().next().expect("Expected face");
+        let circles = face
+            .exterior()
+            .half_edges()
+            .filter_map(|half_edge| match half_edge.curve().path() {
+                SurfacePath::Circle(circle) => {
+                    Some((half_edge.start_vertex().position(), circle))
+                }
+                SurfacePath::Line(_) => None,
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(circles.len(), 1);
+        let (start, circle) = circles[0];
+        assert_eq!(start, Point::from([2., 0.]));
+        assert!(
+            circle.center().distance_to(&Point::from([2., 1.]))
+                < Scalar::from(1e-12)
+        );
+
+        Ok(())
+    }
+}
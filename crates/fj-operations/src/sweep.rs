@@ -1,15 +1,15 @@
 use std::ops::Deref;
 
-use fj_interop::debug::DebugInfo;
+use fj_interop::debug::{DebugCategory, DebugInfo};
 use fj_kernel::{
     algorithms::sweep::Sweep,
     insert::Insert,
     objects::{Objects, Solid},
     services::Service,
 };
-use fj_math::{Aabb, Vector};
+use fj_math::{Aabb, Segment, Vector};
 
-use super::Shape;
+use super::{BrepError, Shape};
 
 impl Shape for fj::Sweep {
     type Brep = Solid;
@@ -18,14 +18,28 @@ impl Shape for fj::Sweep {
         &self,
         objects: &mut Service<Objects>,
         debug_info: &mut DebugInfo,
-    ) -> Self::Brep {
-        let sketch = self.shape().compute_brep(objects, debug_info);
+    ) -> Result<Self::Brep, BrepError> {
+        let sketch = self.shape().compute_brep(objects, debug_info)?;
         let sketch = sketch.insert(objects);
 
         let path = Vector::from(self.path());
 
+        // Show the path that each vertex of the sketch is swept along.
+        for face in sketch.faces() {
+            for cycle in face.all_cycles() {
+                for half_edge in cycle.half_edges() {
+                    let start =
+                        half_edge.start_vertex().global_form().position();
+                    debug_info.push(
+                        DebugCategory::Sweep,
+                        Segment::from_points([start, start + path]),
+                    );
+                }
+            }
+        }
+
         let solid = sketch.sweep(path, objects);
-        solid.deref().clone()
+        Ok(solid.deref().clone())
     }
 
     fn bounding_volume(&self) -> Aabb<3> {
@@ -39,3 +53,48 @@ impl Shape for fj::Sweep {
             ))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use fj::syntax::*;
+    use fj_interop::{analysis::MeshAnalysis, debug::DebugInfo};
+    use fj_kernel::services::Services;
+
+    use crate::{shape_processor::ShapeProcessor, BrepError, Shape};
+
+    #[test]
+    fn glyphs() -> Result<(), BrepError> {
+        // Glyphs with holes, multiple outlines, and curves. Depending on the
+        // direction of the sweep, either the top or bottom faces are reversed.
+        for text in ["i", "o", "B", "%", "M6x20"] {
+            for path in [[0., 0., 1.], [0., 0., -1.]] {
+                let sweep = fj::Text::new(text, 10.).sweep(path);
+
+                let mut services = Services::new();
+                let solid = sweep.compute_brep(
+                    &mut services.objects,
+                    &mut DebugInfo::new(),
+                )?;
+                for shell in solid.shells() {
+                    assert!(
+                        shell.is_watertight(),
+                        "`{text}` is not watertight"
+                    );
+                }
+
+                let processor = ShapeProcessor {
+                    tolerance: None,
+                    debug_dump: None,
+                };
+                let shape = processor
+                    .process(&sweep.into())
+                    .expect("Failed to process glyphs");
+
+                let analysis = MeshAnalysis::of(&shape.mesh);
+                assert!(analysis.is_ok(), "`{text}`: {analysis}");
+            }
+        }
+
+        Ok(())
+    }
+}
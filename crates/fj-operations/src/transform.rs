@@ -6,7 +6,7 @@ use fj_kernel::{
 };
 use fj_math::{Aabb, Transform, Vector};
 
-use super::Shape;
+use super::{BrepError, Shape};
 
 impl Shape for fj::Transform {
     type Brep = FaceSet;
@@ -15,10 +15,11 @@ impl Shape for fj::Transform {
         &self,
         objects: &mut Service<Objects>,
         debug_info: &mut DebugInfo,
-    ) -> Self::Brep {
-        self.shape
-            .compute_brep(objects, debug_info)
-            .transform(&make_transform(self), objects)
+    ) -> Result<Self::Brep, BrepError> {
+        Ok(self
+            .shape
+            .compute_brep(objects, debug_info)?
+            .transform(&make_transform(self), objects))
     }
 
     fn bounding_volume(&self) -> Aabb<3> {
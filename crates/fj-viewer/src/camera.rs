@@ -1,7 +1,7 @@
 //! Viewer camera module
 use std::f64::consts::FRAC_PI_2;
 
-use fj_interop::{mesh::Mesh, processed_shape::ProcessedShape};
+use fj_interop::{bvh::MeshBvh, processed_shape::ProcessedShape};
 use fj_math::{Aabb, Point, Scalar, Transform, Vector};
 
 use crate::screen::NormalizedScreenPosition;
@@ -84,36 +84,23 @@ impl Camera {
         cursor: Option<NormalizedScreenPosition>,
         shape: &ProcessedShape,
     ) -> FocusPoint {
-        self.calculate_focus_point(cursor, &shape.mesh)
+        self.calculate_focus_point(cursor, &shape.bvh)
             .unwrap_or_else(|| FocusPoint(shape.aabb.center()))
     }
 
     fn calculate_focus_point(
         &self,
         cursor: Option<NormalizedScreenPosition>,
-        mesh: &Mesh<Point<3>>,
+        bvh: &MeshBvh,
     ) -> Option<FocusPoint> {
         // Transform camera and cursor positions to model space.
         let origin = self.position();
         let cursor = self.cursor_to_model_space(cursor?);
         let dir = (cursor - origin).normalize();
 
-        let mut min_t = None;
+        let (_, t) = bvh.cast_ray(origin, dir, f64::INFINITY)?;
 
-        for triangle in mesh.triangles() {
-            let t =
-                triangle
-                    .inner
-                    .cast_local_ray(origin, dir, f64::INFINITY, true);
-
-            if let Some(t) = t {
-                if t <= min_t.unwrap_or(t) {
-                    min_t = Some(t);
-                }
-            }
-        }
-
-        Some(FocusPoint(origin + dir * min_t?))
+        Some(FocusPoint(origin + dir * t))
     }
 
     /// Access the transform from camera to model space.
@@ -1,3 +1,7 @@
+use std::collections::BTreeSet;
+
+use fj_interop::debug::DebugCategory;
+
 /// High level configuration for rendering the active model
 #[derive(Debug)]
 pub struct DrawConfig {
@@ -9,6 +13,9 @@ pub struct DrawConfig {
 
     /// Toggle for displaying model debug information
     pub draw_debug: bool,
+
+    /// The categories of debug geometry that are displayed
+    pub debug_categories: BTreeSet<DebugCategory>,
 }
 
 impl Default for DrawConfig {
@@ -17,6 +24,7 @@ impl Default for DrawConfig {
             draw_model: true,
             draw_mesh: false,
             draw_debug: false,
+            debug_categories: DebugCategory::ALL.into_iter().collect(),
         }
     }
 }
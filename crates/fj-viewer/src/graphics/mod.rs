@@ -15,5 +15,7 @@ pub use self::{
     renderer::{DrawError, Renderer, RendererInitError},
 };
 
+pub(crate) use self::vertices::Vertices;
+
 pub const DEPTH_FORMAT: wgpu::TextureFormat = wgpu::TextureFormat::Depth32Float;
 pub const SAMPLE_COUNT: u32 = 4;
@@ -1,6 +1,8 @@
+use std::collections::BTreeSet;
+
 use bytemuck::{Pod, Zeroable};
 use fj_interop::{
-    debug::DebugInfo,
+    debug::{DebugCategory, DebugInfo, DebugPrimitive},
     mesh::{Index, Mesh},
 };
 use fj_math::{Point, Vector};
@@ -77,14 +79,13 @@ impl From<&Mesh<fj_math::Point<3>>> for Vertices {
         let mut m = Mesh::new();
 
         for triangle in mesh.triangles() {
-            let [a, b, c] = triangle.inner.points();
-
-            let normal = (b - a).cross(&(c - a)).normalize();
             let color = triangle.color;
 
-            m.push_vertex((a, normal, color));
-            m.push_vertex((b, normal, color));
-            m.push_vertex((c, normal, color));
+            for (point, normal) in
+                triangle.inner.points().into_iter().zip(triangle.normals)
+            {
+                m.push_vertex((point, normal, color));
+            }
         }
 
         let vertices = m
@@ -102,8 +103,16 @@ impl From<&Mesh<fj_math::Point<3>>> for Vertices {
     }
 }
 
-impl From<&DebugInfo> for Vertices {
-    fn from(debug_info: &DebugInfo) -> Self {
+impl Vertices {
+    /// Create the vertices for the debug info of a shape
+    ///
+    /// Only debug geometry of the provided categories is included. The mesh
+    /// is required to highlight faces.
+    pub fn debug(
+        debug_info: &DebugInfo,
+        mesh: &Mesh<Point<3>>,
+        categories: &BTreeSet<DebugCategory>,
+    ) -> Self {
         let mut self_ = Self::empty();
 
         for triangle_edge_check in &debug_info.triangle_edge_checks {
@@ -128,6 +137,47 @@ impl From<&DebugInfo> for Vertices {
             }
         }
 
+        for geometry in &debug_info.geometry {
+            if !categories.contains(&geometry.category) {
+                continue;
+            }
+
+            let normal = [0.; 3];
+            let color = match geometry.category {
+                DebugCategory::Approximation => [0., 0., 1., 1.],
+                DebugCategory::Triangulation => [1., 0., 1., 1.],
+                DebugCategory::Intersection => [1., 0.5, 0., 1.],
+                DebugCategory::Sweep => [0., 0.5, 0., 1.],
+            };
+
+            match &geometry.primitive {
+                DebugPrimitive::Point(point) => {
+                    self_.push_cross(*point, normal, color);
+                }
+                DebugPrimitive::Segment(segment) => {
+                    self_.push_line(segment.points(), normal, color);
+                }
+                DebugPrimitive::Polyline(points) => {
+                    for line in points.windows(2) {
+                        self_.push_line([line[0], line[1]], normal, color);
+                    }
+                }
+                DebugPrimitive::Label { position, .. } => {
+                    // Text can't be rendered here. The label's text is
+                    // available in dumps of the debug info.
+                    self_.push_cross(*position, normal, color);
+                }
+                DebugPrimitive::Face(face) => {
+                    for triangle in mesh.triangles_of_face(*face) {
+                        let [a, b, c] = triangle.inner.points();
+                        for line in [[a, b], [b, c], [c, a]] {
+                            self_.push_line(line, normal, color);
+                        }
+                    }
+                }
+            }
+        }
+
         self_
     }
 }
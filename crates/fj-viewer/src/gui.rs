@@ -22,6 +22,7 @@ use std::env::current_dir;
 #[cfg(not(target_arch = "wasm32"))]
 use rfd::FileDialog;
 
+use fj_interop::debug::DebugCategory;
 use fj_math::{Aabb, Scalar};
 
 use crate::{
@@ -114,6 +115,24 @@ impl Gui {
                     .on_disabled_hover_text(
                         "Rendering device does not have line rendering feature support"
                     );
+                ui.add_enabled_ui(config.draw_debug, |ui| {
+                    ui.indent("fj-debug-categories", |ui| {
+                        for category in DebugCategory::ALL {
+                            let mut enabled =
+                                config.debug_categories.contains(&category);
+                            let checkbox =
+                                ui.checkbox(&mut enabled, category.to_string());
+
+                            if checkbox.changed() {
+                                if enabled {
+                                    config.debug_categories.insert(category);
+                                } else {
+                                    config.debug_categories.remove(&category);
+                                }
+                            }
+                        }
+                    });
+                });
                 ui.add_space(16.0);
                 ui.strong(bounding_box_size);
             });
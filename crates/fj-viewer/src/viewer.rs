@@ -5,9 +5,9 @@ use fj_math::Aabb;
 use tracing::warn;
 
 use crate::{
-    camera::FocusPoint, gui::Gui, Camera, DrawConfig, GuiState, InputEvent,
-    InputHandler, NormalizedScreenPosition, Renderer, RendererInitError,
-    Screen, ScreenSize,
+    camera::FocusPoint, graphics::Vertices, gui::Gui, Camera, DrawConfig,
+    GuiState, InputEvent, InputHandler, NormalizedScreenPosition, Renderer,
+    RendererInitError, Screen, ScreenSize,
 };
 
 /// The Fornjot model viewer
@@ -76,13 +76,29 @@ impl Viewer {
 
     /// Handle the shape being updated
     pub fn handle_shape_update(&mut self, shape: ProcessedShape) {
-        self.renderer
-            .update_geometry((&shape.mesh).into(), (&shape.debug_info).into());
-
         let aabb = shape.aabb;
         if self.shape.replace(shape).is_none() {
             self.camera.init_planes(&aabb);
         }
+
+        self.update_geometry();
+    }
+
+    /// Update the geometry of the renderer from the shape
+    ///
+    /// Needs to happen whenever the shape, or the debug geometry that is
+    /// displayed, changes.
+    fn update_geometry(&mut self) {
+        if let Some(shape) = &self.shape {
+            self.renderer.update_geometry(
+                (&shape.mesh).into(),
+                Vertices::debug(
+                    &shape.debug_info,
+                    &shape.mesh,
+                    &self.draw_config.debug_categories,
+                ),
+            );
+        }
     }
 
     /// Handle an input event
@@ -128,6 +144,8 @@ impl Viewer {
 
         self.camera.update_planes(&aabb);
 
+        let debug_categories = self.draw_config.debug_categories.clone();
+
         let new_model_path = self.gui.update(
             pixels_per_point,
             egui_input,
@@ -137,6 +155,10 @@ impl Viewer {
             gui_state,
         );
 
+        if self.draw_config.debug_categories != debug_categories {
+            self.update_geometry();
+        }
+
         if let Err(err) = self.renderer.draw(
             &self.camera,
             &self.draw_config,
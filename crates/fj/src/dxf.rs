@@ -0,0 +1,665 @@
+use std::{error::Error, f64::consts::TAU, fmt, fs, io, path::Path};
+
+use crate::{outline::Outline, Shape, Shape2d};
+
+/// Shapes imported from a DXF file
+///
+/// `LINE`, `ARC`, `CIRCLE`, and `LWPOLYLINE` entities are imported. Lines and
+/// arcs are joined into closed contours, wherever they share endpoints. Arcs,
+/// including those of polylines, are kept exact. Contours that are nested
+/// within other contours are holes.
+///
+/// Coordinates are converted into millimeters, using the units declared in
+/// the header of the file. Files that don't declare units are assumed to use
+/// millimeters.
+///
+/// # Examples
+///
+/// ``` rust
+/// use fj::syntax::*;
+///
+/// let dxf = fj::Dxf::parse(
+///     "0\nSECTION\n2\nENTITIES\n\
+///     0\nCIRCLE\n8\n0\n10\n0.0\n20\n0.0\n40\n5.0\n\
+///     0\nENDSEC\n0\nEOF\n",
+/// )?;
+/// let disc = dxf.sweep([0., 0., 1.]);
+/// # let _ = disc;
+/// # Ok::<(), fj::DxfError>(())
+/// ```
+///
+/// # Limitations
+///
+/// Only the `ENTITIES` section is imported, so entities within blocks are
+/// ignored. So are all other kinds of entities. Lines and arcs that don't form
+/// closed contours are an error.
+#[derive(Clone, Debug)]
+pub struct Dxf {
+    contours: Vec<Contour>,
+    color: [u8; 4],
+}
+
+impl Dxf {
+    /// Load a DXF file
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, DxfError> {
+        let data = fs::read_to_string(path)
+            .map_err(|err| DxfError(DxfErrorKind::Io(err)))?;
+        Self::parse(&data)
+    }
+
+    /// Parse the contents of a DXF file
+    pub fn parse(data: &str) -> Result<Self, DxfError> {
+        let groups = parse_groups(data)?;
+
+        let mut scale = 1.;
+        let mut contours = Vec::new();
+        let mut segments = Vec::new();
+
+        let mut section = "";
+        let mut i = 0;
+        while i < groups.len() {
+            let group = &groups[i];
+            i += 1;
+
+            match (group.code, group.value) {
+                (0, "SECTION") => {
+                    section = groups
+                        .get(i)
+                        .filter(|group| group.code == 2)
+                        .map(|group| group.value)
+                        .unwrap_or_default();
+                }
+                (0, "ENDSEC") => section = "",
+                (9, "$INSUNITS") if section == "HEADER" => {
+                    if let Some(group) = groups.get(i) {
+                        scale = units_to_mm(group.parse()?);
+                    }
+                }
+                (0, kind) if section == "ENTITIES" => {
+                    let end = groups[i..]
+                        .iter()
+                        .position(|group| group.code == 0)
+                        .map_or(groups.len(), |n| i + n);
+                    let entity = Entity {
+                        groups: &groups[i..end],
+                    };
+                    i = end;
+
+                    match kind {
+                        "LINE" => segments.push(entity.line()?),
+                        "ARC" => segments.push(entity.arc()?),
+                        "CIRCLE" => contours.push(entity.circle()?),
+                        "LWPOLYLINE" => {
+                            let (polyline, closed) = entity.polyline()?;
+                            if closed {
+                                contours.push(Contour { segments: polyline });
+                            } else {
+                                segments.extend(polyline);
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        contours.extend(join_segments(segments)?);
+
+        for contour in &mut contours {
+            contour.scale(scale);
+        }
+
+        Ok(Self {
+            contours,
+            color: [255, 0, 0, 255],
+        })
+    }
+
+    /// Set the rendering color of the imported shapes in RGBA
+    pub fn with_color(mut self, color: [u8; 4]) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Get the rendering color of the imported shapes in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.color
+    }
+
+    /// Convert the imported contours into shapes
+    ///
+    /// Each outer contour becomes a [`crate::Sketch`]. Holes are subtracted
+    /// from it using a [`crate::Difference2d`]. All of them are combined into
+    /// a [`crate::Group2d`].
+    pub fn to_shape(&self) -> Shape2d {
+        // Arcs are kept exact, so the tolerance only affects how accurately
+        // it is decided which contours are nested in which.
+        let extent = self
+            .contours
+            .iter()
+            .flat_map(|contour| &contour.segments)
+            .flat_map(|segment| segment.start.into_iter().chain(segment.end))
+            .fold(0., |extent: f64, coord| extent.max(coord.abs()));
+        let mut outline = Outline::new(extent / 1000.);
+
+        for contour in &self.contours {
+            let Some(first) = contour.segments.first() else {
+                continue;
+            };
+            outline.move_to(first.start);
+
+            for segment in &contour.segments {
+                match segment.route {
+                    Route::Line => outline.line_to(segment.end),
+                    Route::Arc { center, angle }
+                        if segment.start == segment.end =>
+                    {
+                        // A single arc can't start and end at the same point,
+                        // so full circles are split in two.
+                        let [x, y] = [
+                            segment.start[0] - center[0],
+                            segment.start[1] - center[1],
+                        ];
+                        let (sin, cos) = (angle / 2.).sin_cos();
+                        let middle = [
+                            center[0] + x * cos - y * sin,
+                            center[1] + x * sin + y * cos,
+                        ];
+
+                        outline.arc_to(center, angle / 2., middle);
+                        outline.arc_to(center, angle / 2., segment.end);
+                    }
+                    Route::Arc { center, angle } => {
+                        outline.arc_to(center, angle, segment.end);
+                    }
+                }
+            }
+
+            outline.close();
+        }
+
+        outline.into_shape(self.color)
+    }
+}
+
+impl From<Dxf> for Shape {
+    fn from(shape: Dxf) -> Self {
+        Self::Shape2d(shape.into())
+    }
+}
+
+impl From<Dxf> for Shape2d {
+    fn from(shape: Dxf) -> Self {
+        shape.to_shape()
+    }
+}
+
+/// Error importing a DXF file
+#[derive(Debug)]
+pub struct DxfError(DxfErrorKind);
+
+#[derive(Debug)]
+enum DxfErrorKind {
+    Io(io::Error),
+    Syntax { line: usize, message: String },
+    OpenContour { point: [f64; 2] },
+}
+
+impl fmt::Display for DxfError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            DxfErrorKind::Io(err) => {
+                write!(f, "Failed to read DXF file: {err}")
+            }
+            DxfErrorKind::Syntax { line, message } => {
+                write!(f, "Failed to parse DXF file at line {line}: {message}")
+            }
+            DxfErrorKind::OpenContour { point: [x, y] } => {
+                write!(f, "Contour in DXF file is not closed at ({x}, {y})")
+            }
+        }
+    }
+}
+
+impl Error for DxfError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.0 {
+            DxfErrorKind::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+/// A closed contour
+#[derive(Clone, Debug)]
+struct Contour {
+    segments: Vec<Segment>,
+}
+
+impl Contour {
+    fn scale(&mut self, factor: f64) {
+        let scale = |point: &mut [f64; 2]| *point = point.map(|c| c * factor);
+
+        for segment in &mut self.segments {
+            scale(&mut segment.start);
+            scale(&mut segment.end);
+            if let Route::Arc { center, .. } = &mut segment.route {
+                scale(center);
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Segment {
+    start: [f64; 2],
+    end: [f64; 2],
+    route: Route,
+}
+
+impl Segment {
+    fn reversed(self) -> Self {
+        let route = match self.route {
+            Route::Line => Route::Line,
+            Route::Arc { center, angle } => Route::Arc {
+                center,
+                angle: -angle,
+            },
+        };
+
+        Self {
+            start: self.end,
+            end: self.start,
+            route,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Route {
+    Line,
+
+    /// A circular arc, counter-clockwise if the angle is positive
+    Arc {
+        center: [f64; 2],
+        angle: f64,
+    },
+}
+
+/// Join lines and arcs into closed contours
+fn join_segments(segments: Vec<Segment>) -> Result<Vec<Contour>, DxfError> {
+    // Endpoints in DXF files are often written with limited precision, so
+    // they're matched within a tolerance.
+    let extent = segments
+        .iter()
+        .flat_map(|segment| segment.start.into_iter().chain(segment.end))
+        .fold(0., |extent: f64, coord| extent.max(coord.abs()));
+    let epsilon = extent * 1e-6;
+    let matches =
+        |a: [f64; 2], b: [f64; 2]| (a[0] - b[0]).hypot(a[1] - b[1]) <= epsilon;
+
+    let mut remaining = segments;
+    let mut contours = Vec::new();
+
+    while !remaining.is_empty() {
+        let first = remaining.remove(0);
+        let start = first.start;
+        let mut segments = vec![first];
+
+        loop {
+            let last = segments.last_mut().expect("Contour is not empty");
+            if matches(last.end, start) {
+                last.end = start;
+                break;
+            }
+
+            let end = last.end;
+            let next = remaining.iter().enumerate().find_map(|(i, segment)| {
+                if matches(segment.start, end) {
+                    Some((i, *segment))
+                } else if matches(segment.end, end) {
+                    Some((i, segment.reversed()))
+                } else {
+                    None
+                }
+            });
+            let Some((i, mut next)) = next else {
+                return Err(DxfError(DxfErrorKind::OpenContour { point: end }));
+            };
+
+            remaining.remove(i);
+            next.start = end;
+            segments.push(next);
+        }
+
+        contours.push(Contour { segments });
+    }
+
+    Ok(contours)
+}
+
+fn units_to_mm(units: i64) -> f64 {
+    match units {
+        1 => 25.4,
+        2 => 304.8,
+        5 => 10.,
+        6 => 1000.,
+        _ => 1.,
+    }
+}
+
+/// A group code and its value, which DXF files are made up of
+struct Group<'r> {
+    code: i32,
+    value: &'r str,
+    line: usize,
+}
+
+impl Group<'_> {
+    fn parse<T: std::str::FromStr>(&self) -> Result<T, DxfError> {
+        self.value.parse().map_err(|_| {
+            syntax_error(
+                self.line + 1,
+                format!(
+                    "Invalid value `{}` of group {}",
+                    self.value, self.code
+                ),
+            )
+        })
+    }
+}
+
+fn parse_groups(data: &str) -> Result<Vec<Group<'_>>, DxfError> {
+    let lines = data.lines().map(str::trim).collect::<Vec<_>>();
+
+    lines
+        .chunks(2)
+        .enumerate()
+        .filter(|(_, chunk)| chunk != &[""])
+        .map(|(i, chunk)| {
+            let line = i * 2 + 1;
+            let [code, value] = chunk else {
+                return Err(syntax_error(line, "Missing group value".into()));
+            };
+            let code = code.parse().map_err(|_| {
+                syntax_error(line, format!("Invalid group code `{code}`"))
+            })?;
+
+            Ok(Group { code, value, line })
+        })
+        .collect()
+}
+
+fn syntax_error(line: usize, message: String) -> DxfError {
+    DxfError(DxfErrorKind::Syntax { line, message })
+}
+
+/// The groups of an entity, following its type
+struct Entity<'r> {
+    groups: &'r [Group<'r>],
+}
+
+impl Entity<'_> {
+    fn value(&self, code: i32) -> Result<f64, DxfError> {
+        self.groups
+            .iter()
+            .find(|group| group.code == code)
+            .map_or(Ok(0.), Group::parse)
+    }
+
+    fn point(&self, [x, y]: [i32; 2]) -> Result<[f64; 2], DxfError> {
+        Ok([self.value(x)?, self.value(y)?])
+    }
+
+    /// Whether the entity is mirrored
+    ///
+    /// Arcs, circles, and polylines are defined in a coordinate system that
+    /// is derived from their extrusion direction. Entities in the xy-plane
+    /// either point up, in which case that coordinate system is the world
+    /// coordinate system, or down, which mirrors the x-axis.
+    fn mirrored(&self) -> Result<bool, DxfError> {
+        let z = self
+            .groups
+            .iter()
+            .find(|group| group.code == 230)
+            .map_or(Ok(1.), Group::parse::<f64>)?;
+        Ok(z < 0.)
+    }
+
+    fn line(&self) -> Result<Segment, DxfError> {
+        Ok(Segment {
+            start: self.point([10, 20])?,
+            end: self.point([11, 21])?,
+            route: Route::Line,
+        })
+    }
+
+    fn arc(&self) -> Result<Segment, DxfError> {
+        let center = self.point([10, 20])?;
+        let radius = self.value(40)?;
+        let [start_angle, end_angle] =
+            [self.value(50)?, self.value(51)?].map(f64::to_radians);
+
+        let mut angle = (end_angle - start_angle).rem_euclid(TAU);
+        if angle == 0. {
+            angle = TAU;
+        }
+
+        let point = |angle: f64| {
+            let (sin, cos) = angle.sin_cos();
+            [center[0] + radius * cos, center[1] + radius * sin]
+        };
+        let segment = Segment {
+            start: point(start_angle),
+            end: point(end_angle),
+            route: Route::Arc { center, angle },
+        };
+
+        Ok(if self.mirrored()? {
+            mirror(segment)
+        } else {
+            segment
+        })
+    }
+
+    fn circle(&self) -> Result<Contour, DxfError> {
+        let center = self.point([10, 20])?;
+        let radius = self.value(40)?;
+
+        let start = [center[0] + radius, center[1]];
+        let segment = Segment {
+            start,
+            end: start,
+            route: Route::Arc { center, angle: TAU },
+        };
+
+        let segment = if self.mirrored()? {
+            mirror(segment)
+        } else {
+            segment
+        };
+        Ok(Contour {
+            segments: vec![segment],
+        })
+    }
+
+    /// Parse a polyline, and whether it is closed
+    fn polyline(&self) -> Result<(Vec<Segment>, bool), DxfError> {
+        let flags = self
+            .groups
+            .iter()
+            .find(|group| group.code == 70)
+            .map_or(Ok(0), Group::parse::<i64>)?;
+        let closed = flags & 1 != 0;
+
+        // Each vertex starts with its x-coordinate. The bulge of a vertex
+        // defines the arc that leads to the next one.
+        let mut vertices: Vec<([f64; 2], f64)> = Vec::new();
+        for group in self.groups {
+            match group.code {
+                10 => vertices.push(([group.parse()?, 0.], 0.)),
+                20 | 42 => {
+                    let Some((point, bulge)) = vertices.last_mut() else {
+                        return Err(syntax_error(
+                            group.line,
+                            "Polyline vertex without x-coordinate".into(),
+                        ));
+                    };
+                    if group.code == 20 {
+                        point[1] = group.parse()?;
+                    } else {
+                        *bulge = group.parse()?;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let n = vertices.len();
+        let num_segments = if closed { n } else { n.saturating_sub(1) };
+        let mirrored = self.mirrored()?;
+
+        let segments = (0..num_segments)
+            .map(|i| {
+                let (start, bulge) = vertices[i];
+                let (end, _) = vertices[(i + 1) % n];
+
+                let route = if bulge == 0. {
+                    Route::Line
+                } else {
+                    let angle = 4. * bulge.atan();
+
+                    // The center is to the left of the chord for
+                    // counter-clockwise arcs, and to the right otherwise.
+                    let [dx, dy] = [end[0] - start[0], end[1] - start[1]];
+                    let distance = 0.5 / (angle / 2.).tan();
+                    let center = [
+                        (start[0] + end[0]) / 2. - dy * distance,
+                        (start[1] + end[1]) / 2. + dx * distance,
+                    ];
+
+                    Route::Arc { center, angle }
+                };
+
+                let segment = Segment { start, end, route };
+                if mirrored {
+                    mirror(segment)
+                } else {
+                    segment
+                }
+            })
+            .collect();
+
+        Ok((segments, closed))
+    }
+}
+
+/// Mirror a segment along the y-axis
+fn mirror(segment: Segment) -> Segment {
+    let mirror = |[x, y]: [f64; 2]| [-x, y];
+
+    let route = match segment.route {
+        Route::Line => Route::Line,
+        Route::Arc { center, angle } => Route::Arc {
+            center: mirror(center),
+            angle: -angle,
+        },
+    };
+
+    Segment {
+        start: mirror(segment.start),
+        end: mirror(segment.end),
+        route,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Chain, Shape2d, SketchSegmentRoute};
+
+    use super::Dxf;
+
+    fn entities(entities: &str) -> String {
+        format!("0\nSECTION\n2\nENTITIES\n{entities}0\nENDSEC\n0\nEOF\n")
+    }
+
+    fn shapes(data: &str) -> Vec<Shape2d> {
+        let Shape2d::Group(group) = Dxf::parse(data).unwrap().to_shape() else {
+            panic!("Expected DXF to be converted into a group");
+        };
+        group.shapes().to_vec()
+    }
+
+    #[test]
+    fn lines_and_arcs_are_joined() {
+        // A slot, with the arcs and lines in no particular order or
+        // direction.
+        let shapes = shapes(&entities(
+            "0\nLINE\n10\n0\n20\n0\n11\n10\n21\n0\n\
+            0\nLINE\n10\n0\n20\n4\n11\n10\n21\n4\n\
+            0\nARC\n10\n10\n20\n2\n40\n2\n50\n270\n51\n90\n\
+            0\nARC\n10\n0\n20\n2\n40\n2\n50\n90\n51\n270\n",
+        ));
+
+        let [Shape2d::Sketch(sketch)] = shapes.as_slice() else {
+            panic!("Expected a single sketch");
+        };
+        let Chain::PolyChain(poly_chain) = sketch.chain() else {
+            panic!("Expected sketch to be made up of segments");
+        };
+
+        let segments = poly_chain.to_segments();
+        assert_eq!(segments.len(), 4);
+        assert_eq!(
+            segments
+                .iter()
+                .filter(|segment| matches!(
+                    segment.route,
+                    SketchSegmentRoute::Arc { .. }
+                ))
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn polyline_with_hole() {
+        let shapes = shapes(&entities(
+            "0\nLWPOLYLINE\n90\n4\n70\n1\n\
+            10\n0\n20\n0\n10\n10\n20\n0\n10\n10\n20\n10\n10\n0\n20\n10\n\
+            0\nCIRCLE\n10\n5\n20\n5\n40\n2\n",
+        ));
+
+        assert!(matches!(shapes.as_slice(), [Shape2d::Difference(_)]));
+    }
+
+    #[test]
+    fn units() {
+        let data = format!(
+            "0\nSECTION\n2\nHEADER\n9\n$INSUNITS\n70\n1\n0\nENDSEC\n{}",
+            entities(
+                "0\nLWPOLYLINE\n90\n3\n70\n1\n\
+                10\n0\n20\n0\n10\n1\n20\n0\n10\n1\n20\n1\n",
+            )
+        );
+        let shapes = shapes(&data);
+
+        let [Shape2d::Sketch(sketch)] = shapes.as_slice() else {
+            panic!("Expected a single sketch");
+        };
+        let Chain::PolyChain(poly_chain) = sketch.chain() else {
+            panic!("Expected sketch to be made up of segments");
+        };
+        assert!(poly_chain
+            .to_segments()
+            .iter()
+            .any(|segment| segment.endpoint == [25.4, 25.4]));
+    }
+
+    #[test]
+    fn open_contour() {
+        let data = entities("0\nLINE\n10\n0\n20\n0\n11\n10\n21\n0\n");
+        assert!(Dxf::parse(&data).is_err());
+    }
+}
@@ -0,0 +1,140 @@
+use crate::{abi::ffi_safe, Shape, Sweep};
+
+/// A sweep with rounded or chamfered edges
+///
+/// The edges that can be treated are the ones that run along the path of the
+/// sweep. Each of them starts at a corner of the swept 2D shape, which is why
+/// those edges are selected by the index of that corner (see
+/// [`EdgeSelection`]).
+///
+/// # Examples
+///
+/// Convenient syntax for this operation is available through [`crate::syntax`].
+///
+/// ``` rust
+/// use fj::syntax::*;
+///
+/// # let sketch = fj::Sketch::from_points(vec![[0., 0.], [1., 0.], [0., 1.]]);
+/// # let sweep = sketch.sweep([0., 0., 1.]);
+///
+/// // `sweep` can be anything that converts to `fj::Sweep`
+/// let rounded = sweep.fillet(fj::EdgeSelection::all(), 0.1);
+/// let chamfered = sweep.chamfer(fj::EdgeSelection::from_indices([0]), 0.1);
+/// ```
+///
+/// # Limitations
+///
+/// This is not a general operation for treating the edges of a solid. The
+/// treatment is applied to the corners of the swept 2D shape, selected by their
+/// index, before it is swept. Edges of the resulting solid can't be selected
+/// directly.
+///
+/// Only edges that run along the path of the sweep can be treated that way,
+/// and only if both of the faces that meet at them are planar. Edges that don't
+/// meet these requirements, like the edges around the caps of the sweep, are
+/// left as they are.
+///
+/// Processing the shape fails, if the radius or distance isn't positive, or if
+/// the treated edges are too close together for it (i.e. if the treatments of
+/// the corners of the 2D shape don't fit on the segments between them).
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Fillet {
+    /// The sweep whose edges are treated
+    sweep: Sweep,
+
+    /// The edges that are treated
+    edges: EdgeSelection,
+
+    /// The treatment that is applied to the edges
+    kind: FilletKind,
+}
+
+impl Fillet {
+    /// Create a `Fillet` from a sweep, an edge selection, and a treatment
+    pub fn from_sweep(
+        sweep: Sweep,
+        edges: EdgeSelection,
+        kind: FilletKind,
+    ) -> Self {
+        Self { sweep, edges, kind }
+    }
+
+    /// Access the sweep whose edges are treated
+    pub fn sweep(&self) -> &Sweep {
+        &self.sweep
+    }
+
+    /// Access the edges that are treated
+    pub fn edges(&self) -> &EdgeSelection {
+        &self.edges
+    }
+
+    /// Access the treatment that is applied to the edges
+    pub fn kind(&self) -> FilletKind {
+        self.kind
+    }
+}
+
+impl From<Fillet> for Shape {
+    fn from(shape: Fillet) -> Self {
+        Self::Fillet(shape)
+    }
+}
+
+/// A selection of edges of a sweep
+///
+/// Edges are identified by the index of the corner of the swept 2D shape that
+/// they start at. Corners are counted in the order in which they are defined,
+/// starting with the exterior of the shape. The corners of any holes follow
+/// after that.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct EdgeSelection {
+    all: bool,
+    indices: ffi_safe::Vec<usize>,
+}
+
+impl EdgeSelection {
+    /// Select all edges
+    pub fn all() -> Self {
+        Self {
+            all: true,
+            indices: ffi_safe::Vec::default(),
+        }
+    }
+
+    /// Select the edges with the provided indices
+    pub fn from_indices(indices: impl IntoIterator<Item = usize>) -> Self {
+        Self {
+            all: false,
+            indices: indices.into_iter().collect(),
+        }
+    }
+
+    /// Indicate whether the edge with the provided index is selected
+    pub fn contains(&self, index: usize) -> bool {
+        self.all || self.indices.contains(&index)
+    }
+}
+
+/// The treatment that is applied to the edges of a [`Fillet`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub enum FilletKind {
+    /// Round off the edges with the given radius
+    Round {
+        /// The radius of the rounded edge
+        radius: f64,
+    },
+
+    /// Cut off the edges at the given distance
+    Chamfer {
+        /// The distance from the edge at which it is cut off, measured on both
+        /// adjacent faces
+        distance: f64,
+    },
+}
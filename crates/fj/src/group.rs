@@ -1,4 +1,4 @@
-use crate::Shape;
+use crate::{abi::ffi_safe, Shape, Shape2d};
 
 /// A group of two 3-dimensional shapes
 ///
@@ -37,3 +37,66 @@ impl From<Group> for Shape {
         Self::Group(Box::new(shape))
     }
 }
+
+/// A group of 2-dimensional shapes
+///
+/// Like a [`Group`], this is a collection of disjoint shapes, not a union.
+///
+/// # Examples
+///
+/// Convenient syntax for this operation is available through [`crate::syntax`].
+///
+/// ``` rust
+/// # let a = fj::Sketch::from_points(vec![[0., 0.], [1., 0.], [0., 1.]]);
+/// # let b = fj::Sketch::from_points(vec![[2., 0.], [3., 0.], [2., 1.]]);
+/// use fj::syntax::*;
+///
+/// // `a` and `b` can be anything that converts to `fj::Shape2d`
+/// let group = a.group_2d(&b);
+/// ```
+///
+/// # Limitations
+///
+/// Whether the shapes in the group touch or overlap is not currently checked.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Group2d {
+    shapes: ffi_safe::Vec<Shape2d>,
+}
+
+impl Group2d {
+    /// Create a `Group2d` from the shapes it contains
+    pub fn from_shapes(shapes: Vec<Shape2d>) -> Self {
+        Self {
+            shapes: shapes.into(),
+        }
+    }
+
+    /// Get the rendering color of the group in RGBA
+    ///
+    /// This is the color of the first shape.
+    pub fn color(&self) -> [u8; 4] {
+        self.shapes
+            .first()
+            .map(Shape2d::color)
+            .unwrap_or([255, 0, 0, 255])
+    }
+
+    /// Access the shapes in the group
+    pub fn shapes(&self) -> &[Shape2d] {
+        &self.shapes
+    }
+}
+
+impl From<Group2d> for Shape {
+    fn from(shape: Group2d) -> Self {
+        Self::Shape2d(shape.into())
+    }
+}
+
+impl From<Group2d> for Shape2d {
+    fn from(shape: Group2d) -> Self {
+        Self::Group(Box::new(shape))
+    }
+}
@@ -0,0 +1,229 @@
+use crate::{primitive::DEFAULT_RELATIVE_TOLERANCE, Shape, Shape2d, Sketch};
+
+/// A sweep of a 2-dimensional shape along a helix around the z-axis
+///
+/// The shape is a profile that is defined in its own xy-plane. The profile's
+/// x-axis points away from the z-axis, its y-axis points along the z-axis. The
+/// origin of the profile starts out at `radius` on the x-axis, and follows the
+/// helix from there.
+///
+/// # Examples
+///
+/// Convenient syntax for this operation is available through [`crate::syntax`].
+///
+/// ``` rust
+/// # let profile = fj::Sketch::from_circle(fj::Circle::from_radius(0.5));
+/// use fj::syntax::*;
+///
+/// // `profile` can be anything that converts to `fj::Shape2d`
+/// let spring = profile.helical_sweep(5., 2., 10.);
+/// ```
+///
+/// # Limitations
+///
+/// Straight segments of the profile are swept exactly. Curved segments are
+/// approximated by straight ones, which deviate from the actual profile no
+/// more than a given tolerance. By default, that tolerance is 1% of the
+/// radius.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct HelicalSweep {
+    /// The 2-dimensional profile being swept
+    shape: Shape2d,
+
+    /// The distance of the profile's origin from the z-axis
+    radius: f64,
+
+    /// The distance along the z-axis that the helix advances per turn
+    pitch: f64,
+
+    /// The number of turns
+    turns: f64,
+
+    /// The maximum deviation of the approximated sweep from the actual one
+    tolerance: f64,
+}
+
+impl HelicalSweep {
+    /// Create a `HelicalSweep` from a profile, radius, pitch, and turns
+    ///
+    /// The helix winds counter-clockwise, as seen from above. A positive pitch
+    /// results in a right-handed helix that advances along the positive
+    /// z-axis. A negative pitch results in a left-handed helix that advances
+    /// along the negative z-axis.
+    pub fn from_shape(
+        shape: Shape2d,
+        radius: f64,
+        pitch: f64,
+        turns: f64,
+    ) -> Self {
+        Self {
+            shape,
+            radius,
+            pitch,
+            turns,
+            tolerance: radius * DEFAULT_RELATIVE_TOLERANCE,
+        }
+    }
+
+    /// Set the maximum deviation of the approximated sweep from the actual one
+    pub fn with_tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Access the profile being swept
+    pub fn shape(&self) -> &Shape2d {
+        &self.shape
+    }
+
+    /// Access the distance of the profile's origin from the z-axis
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+
+    /// Access the pitch of the helix
+    pub fn pitch(&self) -> f64 {
+        self.pitch
+    }
+
+    /// Access the number of turns
+    pub fn turns(&self) -> f64 {
+        self.turns
+    }
+
+    /// Access the tolerance of the approximated sweep
+    pub fn tolerance(&self) -> f64 {
+        self.tolerance
+    }
+}
+
+impl From<HelicalSweep> for Shape {
+    fn from(shape: HelicalSweep) -> Self {
+        Self::HelicalSweep(shape)
+    }
+}
+
+/// An ISO metric screw thread
+///
+/// Creates the thread itself, which can be combined with the core of a bolt
+/// or the body of a nut. The thread follows the basic profile from ISO 68-1,
+/// without any tolerance class applied.
+///
+/// # Examples
+///
+/// ``` rust
+/// // An M6 bolt with a 10 mm long thread
+/// let thread = fj::IsoMetricThread::new(6., 1.);
+///
+/// let core = fj::Cylinder::from_radius_and_height(
+///     thread.minor_diameter() / 2.,
+///     10.,
+/// );
+/// let bolt = fj::Group {
+///     a: core.into(),
+///     b: thread.external(10.).into(),
+/// };
+/// # let _ = bolt;
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct IsoMetricThread {
+    nominal_diameter: f64,
+    pitch: f64,
+}
+
+impl IsoMetricThread {
+    /// Create a thread from its nominal diameter and pitch
+    ///
+    /// For an M6 thread, for example, the nominal diameter is 6 mm, and the
+    /// standard (coarse) pitch is 1 mm.
+    pub fn new(nominal_diameter: f64, pitch: f64) -> Self {
+        Self {
+            nominal_diameter,
+            pitch,
+        }
+    }
+
+    /// Access the nominal (major) diameter of the thread
+    pub fn nominal_diameter(&self) -> f64 {
+        self.nominal_diameter
+    }
+
+    /// Access the pitch of the thread
+    pub fn pitch(&self) -> f64 {
+        self.pitch
+    }
+
+    /// The height of the fundamental triangle of the thread profile
+    pub fn fundamental_triangle_height(&self) -> f64 {
+        3f64.sqrt() / 2. * self.pitch
+    }
+
+    /// The minor diameter of the thread
+    pub fn minor_diameter(&self) -> f64 {
+        self.nominal_diameter - 5. / 4. * self.fundamental_triangle_height()
+    }
+
+    /// The profile of a single tooth of an external thread
+    ///
+    /// The origin of the profile is at the root of the tooth, which points
+    /// toward positive x.
+    pub fn external_profile(&self) -> Sketch {
+        let h = self.profile_height();
+        let p = self.pitch;
+
+        Sketch::from_points(vec![
+            [0., -3. / 8. * p],
+            [h, -p / 16.],
+            [h, p / 16.],
+            [0., 3. / 8. * p],
+        ])
+    }
+
+    /// The profile of a single tooth of an internal thread
+    ///
+    /// The origin of the profile is at the root of the tooth, which points
+    /// toward negative x.
+    pub fn internal_profile(&self) -> Sketch {
+        let h = self.profile_height();
+        let p = self.pitch;
+
+        Sketch::from_points(vec![
+            [0., -3. / 8. * p],
+            [0., 3. / 8. * p],
+            [-h, p / 16.],
+            [-h, -p / 16.],
+        ])
+    }
+
+    /// Create an external thread of the given length, as used on a bolt
+    ///
+    /// The teeth start at the minor diameter and point outward.
+    pub fn external(&self, length: f64) -> HelicalSweep {
+        HelicalSweep::from_shape(
+            self.external_profile().into(),
+            self.minor_diameter() / 2.,
+            self.pitch,
+            length / self.pitch,
+        )
+    }
+
+    /// Create an internal thread of the given length, as used in a nut
+    ///
+    /// The teeth start at the nominal diameter and point inward.
+    pub fn internal(&self, length: f64) -> HelicalSweep {
+        HelicalSweep::from_shape(
+            self.internal_profile().into(),
+            self.nominal_diameter / 2.,
+            self.pitch,
+            length / self.pitch,
+        )
+    }
+
+    fn profile_height(&self) -> f64 {
+        5. / 8. * self.fundamental_triangle_height()
+    }
+}
@@ -0,0 +1,90 @@
+use crate::{abi::ffi_safe, Shape, Sweep};
+
+/// A sweep that has been hollowed out, leaving walls of a given thickness
+///
+/// The walls are created by offsetting the faces of the sweep inward. Caps of
+/// the sweep (the faces at its start and end) can be removed, to create an
+/// open part, like an enclosure.
+///
+/// # Examples
+///
+/// Convenient syntax for this operation is available through [`crate::syntax`].
+///
+/// ``` rust
+/// use fj::syntax::*;
+///
+/// # let sketch = fj::Sketch::from_points(vec![[0., 0.], [4., 0.], [0., 4.]]);
+/// # let sweep = sketch.sweep([0., 0., 2.]);
+/// // `sweep` can be anything that converts to `fj::Sweep`
+/// let enclosure = sweep.hollow(0.2, [fj::SweepCap::Top]);
+/// ```
+///
+/// # Limitations
+///
+/// The thickness must be small enough that the offset of the swept 2D shape
+/// is valid (see [`crate::Offset2d`]), and that the walls at the caps don't
+/// meet each other. Otherwise, processing the shape fails with an error.
+///
+/// Only sweeps along a path that is perpendicular to the swept 2D shape are
+/// supported.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Hollow {
+    /// The sweep that is hollowed out
+    sweep: Sweep,
+
+    /// The thickness of the walls
+    thickness: f64,
+
+    /// The caps of the sweep that are removed
+    open: ffi_safe::Vec<SweepCap>,
+}
+
+impl Hollow {
+    /// Create a `Hollow` from a sweep, a wall thickness, and the caps to remove
+    pub fn from_sweep(
+        sweep: Sweep,
+        thickness: f64,
+        open: impl IntoIterator<Item = SweepCap>,
+    ) -> Self {
+        Self {
+            sweep,
+            thickness,
+            open: open.into_iter().collect(),
+        }
+    }
+
+    /// Access the sweep that is hollowed out
+    pub fn sweep(&self) -> &Sweep {
+        &self.sweep
+    }
+
+    /// Access the thickness of the walls
+    pub fn thickness(&self) -> f64 {
+        self.thickness
+    }
+
+    /// Indicate whether the provided cap is removed
+    pub fn is_open(&self, cap: SweepCap) -> bool {
+        self.open.contains(&cap)
+    }
+}
+
+impl From<Hollow> for Shape {
+    fn from(shape: Hollow) -> Self {
+        Self::Hollow(shape)
+    }
+}
+
+/// One of the caps of a [`Sweep`]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub enum SweepCap {
+    /// The cap at the start of the sweep, where the swept 2D shape is
+    Bottom,
+
+    /// The cap at the end of the sweep
+    Top,
+}
@@ -0,0 +1,126 @@
+use crate::{abi::ffi_safe, Shape, Shape2d};
+
+/// The convex hull of 3-dimensional shapes
+///
+/// The hull is the smallest convex solid that contains all of the shapes.
+///
+/// # Examples
+///
+/// Convenient syntax for this operation is available through [`crate::syntax`].
+///
+/// ``` rust
+/// # let a = fj::Sphere::from_radius(1.);
+/// # let b = fj::Cuboid::from_size([1., 1., 1.]);
+/// use fj::syntax::*;
+///
+/// // `a` and `b` can be anything that converts to `fj::Shape`
+/// let hull = a.hull(&b);
+/// ```
+///
+/// # Limitations
+///
+/// The hull is computed from the approximated vertices of the shapes, and is
+/// made up of flat triangles. It doesn't follow curved surfaces exactly.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Hull {
+    shapes: ffi_safe::Vec<Shape>,
+    color: [u8; 4],
+}
+
+impl Hull {
+    /// Create a `Hull` from the shapes it is supposed to contain
+    pub fn from_shapes(shapes: Vec<Shape>) -> Self {
+        Self {
+            shapes: shapes.into(),
+            color: [255, 0, 0, 255],
+        }
+    }
+
+    /// Set the rendering color of the hull in RGBA
+    pub fn with_color(mut self, color: [u8; 4]) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Access the shapes that the hull contains
+    pub fn shapes(&self) -> &[Shape] {
+        &self.shapes
+    }
+
+    /// Get the rendering color of the hull in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.color
+    }
+}
+
+impl From<Hull> for Shape {
+    fn from(shape: Hull) -> Self {
+        Self::Hull(shape)
+    }
+}
+
+/// The convex hull of 2-dimensional shapes
+///
+/// The hull is the smallest convex shape that contains all of the shapes.
+/// Where the hull follows an arc or circle of one of the shapes, the arc is
+/// kept, which makes hulls of circles a convenient way to create slots or
+/// rounded links.
+///
+/// # Examples
+///
+/// Convenient syntax for this operation is available through [`crate::syntax`].
+///
+/// ``` rust
+/// # let a = fj::Sketch::from_circle(fj::Circle::from_radius(1.));
+/// # let b = fj::Sketch::from_circle(
+/// #     fj::Circle::from_center_and_radius([5., 0.], 1.),
+/// # );
+/// use fj::syntax::*;
+///
+/// // `a` and `b` can be anything that converts to `fj::Shape2d`
+/// let slot = a.hull_2d(&b);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Hull2d {
+    shapes: ffi_safe::Vec<Shape2d>,
+}
+
+impl Hull2d {
+    /// Create a `Hull2d` from the shapes it is supposed to contain
+    pub fn from_shapes(shapes: Vec<Shape2d>) -> Self {
+        Self {
+            shapes: shapes.into(),
+        }
+    }
+
+    /// Get the rendering color of the hull in RGBA
+    ///
+    /// This is the color of the first shape.
+    pub fn color(&self) -> [u8; 4] {
+        self.shapes
+            .first()
+            .map(Shape2d::color)
+            .unwrap_or([255, 0, 0, 255])
+    }
+
+    /// Access the shapes that the hull contains
+    pub fn shapes(&self) -> &[Shape2d] {
+        &self.shapes
+    }
+}
+
+impl From<Hull2d> for Shape {
+    fn from(shape: Hull2d) -> Self {
+        Self::Shape2d(shape.into())
+    }
+}
+
+impl From<Hull2d> for Shape2d {
+    fn from(shape: Hull2d) -> Self {
+        Self::Hull(Box::new(shape))
+    }
+}
@@ -23,16 +23,46 @@ pub mod syntax;
 #[doc(hidden)]
 pub mod abi;
 mod angle;
+mod dxf;
+mod fillet;
 mod group;
+mod helical_sweep;
+mod hollow;
+mod hull;
+mod mesh;
 pub mod models;
+mod outline;
+mod pattern;
+mod primitive;
 mod shape_2d;
+#[cfg(feature = "svg")]
+mod svg;
 mod sweep;
+#[cfg(feature = "text")]
+mod text;
 mod transform;
 pub mod version;
 
 pub use self::{
-    angle::*, group::Group, shape_2d::*, sweep::Sweep, transform::Transform,
+    angle::*,
+    dxf::{Dxf, DxfError},
+    fillet::{EdgeSelection, Fillet, FilletKind},
+    group::{Group, Group2d},
+    helical_sweep::{HelicalSweep, IsoMetricThread},
+    hollow::{Hollow, SweepCap},
+    hull::{Hull, Hull2d},
+    mesh::{Mesh, MeshError},
+    pattern::{Pattern, Pattern2d, PatternKind, PatternKind2d},
+    primitive::{Cone, Cuboid, Cylinder, Primitive, Sphere, Torus},
+    shape_2d::*,
+    sweep::Sweep,
+    transform::Transform,
 };
+
+#[cfg(feature = "svg")]
+pub use self::svg::{Svg, SvgError};
+#[cfg(feature = "text")]
+pub use self::text::{Font, FontError, Text};
 pub use fj_proc::*;
 
 /// A shape
@@ -40,9 +70,30 @@ pub use fj_proc::*;
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 pub enum Shape {
+    /// A sweep with rounded or chamfered edges
+    Fillet(Fillet),
+
     /// A group of two 3-dimensional shapes
     Group(Box<Group>),
 
+    /// A sweep of a 2-dimensional shape along a helix
+    HelicalSweep(HelicalSweep),
+
+    /// A hollowed-out sweep
+    Hollow(Hollow),
+
+    /// The convex hull of 3-dimensional shapes
+    Hull(Hull),
+
+    /// A triangle mesh, imported from an STL or 3MF file
+    Mesh(Mesh),
+
+    /// A pattern of a 3-dimensional shape
+    Pattern(Box<Pattern>),
+
+    /// A primitive solid
+    Primitive(Primitive),
+
     /// A 2D shape
     Shape2d(Shape2d),
 
@@ -0,0 +1,611 @@
+use std::{error::Error, fmt, fs, io, path::Path};
+
+#[cfg(feature = "3mf")]
+use std::{
+    collections::HashMap,
+    io::{Cursor, Read},
+};
+
+use crate::{abi::ffi_safe, Shape};
+
+/// A triangle mesh imported from an STL or 3MF file
+///
+/// Meshes can be used like any other shape. They can be grouped and
+/// transformed, and they are exported along with the rest of the model. Each
+/// triangle becomes a flat face.
+///
+/// Coordinates are in millimeters. STL files have no units, so they are
+/// assumed to be in millimeters. The coordinates of 3MF files are converted
+/// from the unit they declare.
+///
+/// # Examples
+///
+/// ``` rust
+/// use fj::syntax::*;
+///
+/// let triangle = fj::Mesh::from_triangles(vec![
+///     [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]],
+/// ]);
+/// let moved = triangle.translate([0., 0., 1.]);
+/// # let _ = moved;
+/// ```
+///
+/// ``` rust no_run
+/// let part = fj::Mesh::from_file("vendor-part.stl")?.with_color([0, 0, 255, 255]);
+/// # Ok::<(), fj::MeshError>(())
+/// ```
+///
+/// # Limitations
+///
+/// Colors and materials are not imported, so the whole mesh has a single
+/// color. Triangles that have no area are skipped.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Mesh {
+    triangles: ffi_safe::Vec<[[f64; 3]; 3]>,
+    color: [u8; 4],
+}
+
+impl Mesh {
+    /// Create a `Mesh` from its triangles
+    ///
+    /// The vertices of each triangle are expected to be ordered
+    /// counter-clockwise, when looking at the front of the triangle.
+    pub fn from_triangles(triangles: Vec<[[f64; 3]; 3]>) -> Self {
+        let triangles = triangles
+            .into_iter()
+            .filter(|&triangle| !is_degenerate(triangle))
+            .collect::<Vec<_>>();
+
+        Self {
+            triangles: triangles.into(),
+            color: [255, 0, 0, 255],
+        }
+    }
+
+    /// Load an STL or 3MF file
+    ///
+    /// The format is chosen based on the extension of the path. Loading 3MF
+    /// files requires the `3mf` feature.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, MeshError> {
+        let path = path.as_ref();
+
+        let extension = path
+            .extension()
+            .map(|extension| extension.to_string_lossy().to_ascii_lowercase())
+            .unwrap_or_default();
+        let data =
+            fs::read(path).map_err(|err| MeshError(MeshErrorKind::Io(err)))?;
+
+        match extension.as_str() {
+            "stl" => Self::parse_stl(&data),
+            #[cfg(feature = "3mf")]
+            "3mf" => Self::parse_3mf(&data),
+            _ => Err(MeshError(MeshErrorKind::UnknownFormat(extension))),
+        }
+    }
+
+    /// Parse the contents of an STL file, which can be binary or ASCII
+    pub fn parse_stl(data: &[u8]) -> Result<Self, MeshError> {
+        // ASCII files start with `solid`, but some binary files do too. The
+        // size of binary files is determined by their triangle count, which
+        // makes them easy to recognize.
+        let is_binary = data.len() >= 84 && {
+            let count =
+                u32::from_le_bytes([data[80], data[81], data[82], data[83]]);
+            data.len() as u64 == 84 + 50 * u64::from(count)
+        };
+
+        let triangles = if is_binary {
+            data[84..]
+                .chunks_exact(50)
+                .map(|triangle| {
+                    // Each triangle starts with its normal, which is ignored.
+                    // The vertex order already defines the front side.
+                    [0, 1, 2].map(|vertex| {
+                        [0, 1, 2].map(|coord| {
+                            let offset = 12 + vertex * 12 + coord * 4;
+                            let bytes = &triangle[offset..offset + 4];
+                            f64::from(f32::from_le_bytes([
+                                bytes[0], bytes[1], bytes[2], bytes[3],
+                            ]))
+                        })
+                    })
+                })
+                .collect()
+        } else if data.starts_with(b"solid") {
+            parse_ascii_stl(data)?
+        } else {
+            return Err(stl_error("not a valid binary or ASCII STL file"));
+        };
+
+        Ok(Self::from_triangles(triangles))
+    }
+
+    /// Parse the contents of a 3MF file
+    ///
+    /// All items of the build are imported, including the components that
+    /// objects are made of, with their transforms applied.
+    #[cfg(feature = "3mf")]
+    pub fn parse_3mf(data: &[u8]) -> Result<Self, MeshError> {
+        let mut archive = zip::ZipArchive::new(Cursor::new(data))
+            .map_err(|err| MeshError(MeshErrorKind::Zip(err)))?;
+
+        let model_path = match read_entry(&mut archive, "_rels/.rels") {
+            Ok(rels) => model_path(&rels)?,
+            Err(_) => String::from("3D/3dmodel.model"),
+        };
+        let model = read_entry(&mut archive, &model_path)?;
+
+        let document = roxmltree::Document::parse(&model)
+            .map_err(|err| MeshError(MeshErrorKind::Xml(err)))?;
+        let root = document.root_element();
+
+        let scale = match root.attribute("unit").unwrap_or("millimeter") {
+            "micron" => 0.001,
+            "millimeter" => 1.,
+            "centimeter" => 10.,
+            "inch" => 25.4,
+            "foot" => 304.8,
+            "meter" => 1000.,
+            unit => {
+                return Err(three_mf_error(format!("unknown unit `{unit}`")))
+            }
+        };
+
+        let objects = root
+            .descendants()
+            .filter(|node| node.has_tag_name("object"))
+            .map(|object| Ok((attribute(object, "id")?, object)))
+            .collect::<Result<HashMap<&str, _>, MeshError>>()?;
+
+        let items = root
+            .descendants()
+            .filter(|node| node.has_tag_name("item"))
+            .collect::<Vec<_>>();
+
+        let mut triangles = Vec::new();
+        for item in items {
+            let transform = Transform::parse(item.attribute("transform"))?
+                .then(&Transform::scale(scale));
+            add_object(
+                &objects,
+                attribute(item, "objectid")?,
+                &transform,
+                &mut triangles,
+                0,
+            )?;
+        }
+
+        Ok(Self::from_triangles(triangles))
+    }
+
+    /// Set the rendering color of the mesh in RGBA
+    pub fn with_color(mut self, color: [u8; 4]) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Access the triangles of the mesh
+    pub fn triangles(&self) -> &[[[f64; 3]; 3]] {
+        &self.triangles
+    }
+
+    /// Get the rendering color of the mesh in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.color
+    }
+}
+
+impl From<Mesh> for Shape {
+    fn from(shape: Mesh) -> Self {
+        Self::Mesh(shape)
+    }
+}
+
+/// Error importing a mesh
+#[derive(Debug)]
+pub struct MeshError(MeshErrorKind);
+
+#[derive(Debug)]
+enum MeshErrorKind {
+    Io(io::Error),
+    UnknownFormat(String),
+    Stl(String),
+    #[cfg(feature = "3mf")]
+    Zip(zip::result::ZipError),
+    #[cfg(feature = "3mf")]
+    Xml(roxmltree::Error),
+    #[cfg(feature = "3mf")]
+    ThreeMf(String),
+}
+
+impl fmt::Display for MeshError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            MeshErrorKind::Io(err) => {
+                write!(f, "Failed to read mesh file: {err}")
+            }
+            MeshErrorKind::UnknownFormat(extension) => {
+                write!(f, "Unknown mesh format `{extension}`")
+            }
+            MeshErrorKind::Stl(message) => {
+                write!(f, "Failed to parse STL file: {message}")
+            }
+            #[cfg(feature = "3mf")]
+            MeshErrorKind::Zip(err) => {
+                write!(f, "Failed to read 3MF package: {err}")
+            }
+            #[cfg(feature = "3mf")]
+            MeshErrorKind::Xml(err) => {
+                write!(f, "Failed to parse 3MF model: {err}")
+            }
+            #[cfg(feature = "3mf")]
+            MeshErrorKind::ThreeMf(message) => {
+                write!(f, "Failed to parse 3MF model: {message}")
+            }
+        }
+    }
+}
+
+impl Error for MeshError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.0 {
+            MeshErrorKind::Io(err) => Some(err),
+            #[cfg(feature = "3mf")]
+            MeshErrorKind::Zip(err) => Some(err),
+            #[cfg(feature = "3mf")]
+            MeshErrorKind::Xml(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+fn stl_error(message: impl Into<String>) -> MeshError {
+    MeshError(MeshErrorKind::Stl(message.into()))
+}
+
+#[cfg(feature = "3mf")]
+fn three_mf_error(message: impl Into<String>) -> MeshError {
+    MeshError(MeshErrorKind::ThreeMf(message.into()))
+}
+
+fn is_degenerate([a, b, c]: [[f64; 3]; 3]) -> bool {
+    let [u, v] = [b, c].map(|p| [p[0] - a[0], p[1] - a[1], p[2] - a[2]]);
+    let normal = [
+        u[1] * v[2] - u[2] * v[1],
+        u[2] * v[0] - u[0] * v[2],
+        u[0] * v[1] - u[1] * v[0],
+    ];
+    normal == [0.; 3]
+}
+
+fn parse_ascii_stl(data: &[u8]) -> Result<Vec<[[f64; 3]; 3]>, MeshError> {
+    let data = std::str::from_utf8(data)
+        .map_err(|_| stl_error("ASCII file is not valid UTF-8"))?;
+
+    let mut vertices = Vec::new();
+    let mut tokens = data.split_whitespace();
+    while let Some(token) = tokens.next() {
+        if token != "vertex" {
+            continue;
+        }
+
+        let mut vertex = [0.; 3];
+        for coord in &mut vertex {
+            *coord = tokens
+                .next()
+                .and_then(|token| token.parse().ok())
+                .ok_or_else(|| stl_error("invalid vertex"))?;
+        }
+        vertices.push(vertex);
+    }
+
+    if vertices.len() % 3 != 0 {
+        return Err(stl_error("facets must have three vertices each"));
+    }
+
+    Ok(vertices
+        .chunks_exact(3)
+        .map(|triangle| [triangle[0], triangle[1], triangle[2]])
+        .collect())
+}
+
+#[cfg(feature = "3mf")]
+fn read_entry(
+    archive: &mut zip::ZipArchive<Cursor<&[u8]>>,
+    name: &str,
+) -> Result<String, MeshError> {
+    let mut entry = archive
+        .by_name(name)
+        .map_err(|err| MeshError(MeshErrorKind::Zip(err)))?;
+
+    let mut contents = String::new();
+    entry
+        .read_to_string(&mut contents)
+        .map_err(|err| MeshError(MeshErrorKind::Io(err)))?;
+
+    Ok(contents)
+}
+
+/// Find the path of the model in the relationships of a 3MF package
+#[cfg(feature = "3mf")]
+fn model_path(rels: &str) -> Result<String, MeshError> {
+    let document = roxmltree::Document::parse(rels)
+        .map_err(|err| MeshError(MeshErrorKind::Xml(err)))?;
+
+    let target = document
+        .descendants()
+        .filter(|node| node.has_tag_name("Relationship"))
+        .find(|node| {
+            matches!(
+                node.attribute("Type"),
+                Some(kind) if kind.ends_with("/3dmodel")
+            )
+        })
+        .and_then(|node| node.attribute("Target"))
+        .ok_or_else(|| three_mf_error("package contains no model"))?;
+
+    Ok(target.trim_start_matches('/').to_owned())
+}
+
+#[cfg(feature = "3mf")]
+fn attribute<'a>(
+    node: roxmltree::Node<'a, '_>,
+    name: &str,
+) -> Result<&'a str, MeshError> {
+    node.attribute(name).ok_or_else(|| {
+        three_mf_error(format!(
+            "`{}` element is missing `{name}` attribute",
+            node.tag_name().name()
+        ))
+    })
+}
+
+#[cfg(feature = "3mf")]
+fn add_object(
+    objects: &HashMap<&str, roxmltree::Node>,
+    id: &str,
+    transform: &Transform,
+    triangles: &mut Vec<[[f64; 3]; 3]>,
+    depth: usize,
+) -> Result<(), MeshError> {
+    // Components can't refer to the object they're part of, but a malformed
+    // file could do that anyway.
+    if depth > 32 {
+        return Err(three_mf_error("components are nested too deeply"));
+    }
+
+    let object = objects
+        .get(id)
+        .ok_or_else(|| three_mf_error(format!("unknown object `{id}`")))?;
+
+    for child in object.children().filter(|node| node.is_element()) {
+        match child.tag_name().name() {
+            "mesh" => add_mesh(child, transform, triangles)?,
+            "components" => {
+                for component in child
+                    .children()
+                    .filter(|node| node.has_tag_name("component"))
+                {
+                    let transform =
+                        Transform::parse(component.attribute("transform"))?
+                            .then(transform);
+                    add_object(
+                        objects,
+                        attribute(component, "objectid")?,
+                        &transform,
+                        triangles,
+                        depth + 1,
+                    )?;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "3mf")]
+fn add_mesh(
+    mesh: roxmltree::Node,
+    transform: &Transform,
+    triangles: &mut Vec<[[f64; 3]; 3]>,
+) -> Result<(), MeshError> {
+    let elements = |name: &'static str| {
+        mesh.descendants()
+            .filter(move |node| node.has_tag_name(name))
+    };
+
+    let vertices = elements("vertex")
+        .map(|vertex| {
+            let mut point = [0.; 3];
+            for (coord, name) in point.iter_mut().zip(["x", "y", "z"]) {
+                *coord = number(attribute(vertex, name)?)?;
+            }
+            Ok(transform.point(point))
+        })
+        .collect::<Result<Vec<_>, MeshError>>()?;
+
+    for triangle in elements("triangle") {
+        let mut indices = [0; 3];
+        for (index, name) in indices.iter_mut().zip(["v1", "v2", "v3"]) {
+            *index = attribute(triangle, name)?
+                .parse::<usize>()
+                .ok()
+                .filter(|&index| index < vertices.len())
+                .ok_or_else(|| three_mf_error("invalid vertex index"))?;
+        }
+
+        triangles.push(indices.map(|index| vertices[index]));
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "3mf")]
+fn number(value: &str) -> Result<f64, MeshError> {
+    value
+        .parse()
+        .map_err(|_| three_mf_error(format!("invalid number `{value}`")))
+}
+
+/// An affine transform, as used by 3MF
+///
+/// The columns of the 3x3 matrix are stored first, followed by the
+/// translation. Points are treated as row vectors.
+#[cfg(feature = "3mf")]
+struct Transform([f64; 12]);
+
+#[cfg(feature = "3mf")]
+impl Transform {
+    fn parse(transform: Option<&str>) -> Result<Self, MeshError> {
+        let Some(transform) = transform else {
+            return Ok(Self::scale(1.));
+        };
+
+        let values = transform
+            .split_whitespace()
+            .map(number)
+            .collect::<Result<Vec<_>, _>>()?;
+        let values = values
+            .try_into()
+            .map_err(|_| three_mf_error("transforms must have 12 values"))?;
+
+        Ok(Self(values))
+    }
+
+    fn scale(factor: f64) -> Self {
+        let mut values = [0.; 12];
+        values[0] = factor;
+        values[4] = factor;
+        values[8] = factor;
+        Self(values)
+    }
+
+    fn point(&self, [x, y, z]: [f64; 3]) -> [f64; 3] {
+        let m = &self.0;
+        [0, 1, 2].map(|i| x * m[i] + y * m[3 + i] + z * m[6 + i] + m[9 + i])
+    }
+
+    /// Combine this transform with another one, which is applied afterwards
+    fn then(&self, other: &Self) -> Self {
+        let m = &self.0;
+
+        let mut values = [0.; 12];
+        for row in 0..4 {
+            let [x, y, z] = [m[row * 3], m[row * 3 + 1], m[row * 3 + 2]];
+            let transformed = if row == 3 {
+                other.point([x, y, z])
+            } else {
+                let mut vector = other.point([x, y, z]);
+                let origin = other.point([0.; 3]);
+                for (coord, origin) in vector.iter_mut().zip(origin) {
+                    *coord -= origin;
+                }
+                vector
+            };
+            values[row * 3..row * 3 + 3].copy_from_slice(&transformed);
+        }
+
+        Self(values)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "3mf")]
+    use std::io::{Cursor, Write};
+
+    use super::Mesh;
+
+    #[test]
+    fn binary_stl_is_parsed() {
+        let mut data = vec![0; 80];
+        data.extend(1u32.to_le_bytes());
+        for value in [0., 0., 1., 0., 0., 0., 1., 0., 0., 0., 1., 0.] {
+            data.extend((value as f32).to_le_bytes());
+        }
+        data.extend([0, 0]);
+
+        let mesh = Mesh::parse_stl(&data).unwrap();
+        assert_eq!(
+            mesh.triangles(),
+            [[[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]]]
+        );
+    }
+
+    #[test]
+    fn ascii_stl_is_parsed() {
+        let data = "solid test
+            facet normal 0 0 1
+                outer loop
+                    vertex 0 0 0
+                    vertex 1 0 0
+                    vertex 0 1 0
+                endloop
+            endfacet
+            facet normal 0 0 1
+                outer loop
+                    vertex 0 0 0
+                    vertex 0 0 0
+                    vertex 0 0 0
+                endloop
+            endfacet
+        endsolid test";
+
+        // The second facet has no area, so it's skipped.
+        let mesh = Mesh::parse_stl(data.as_bytes()).unwrap();
+        assert_eq!(
+            mesh.triangles(),
+            [[[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]]]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "3mf")]
+    fn three_mf_is_parsed() {
+        let model = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <model unit="centimeter" xmlns="http://schemas.microsoft.com/3dmanufacturing/core/2015/02">
+                <resources>
+                    <object id="1" type="model">
+                        <mesh>
+                            <vertices>
+                                <vertex x="0" y="0" z="0"/>
+                                <vertex x="1" y="0" z="0"/>
+                                <vertex x="0" y="1" z="0"/>
+                            </vertices>
+                            <triangles>
+                                <triangle v1="0" v2="1" v3="2"/>
+                            </triangles>
+                        </mesh>
+                    </object>
+                    <object id="2" type="model">
+                        <components>
+                            <component objectid="1" transform="1 0 0 0 1 0 0 0 1 0 0 1"/>
+                        </components>
+                    </object>
+                </resources>
+                <build>
+                    <item objectid="2" transform="1 0 0 0 1 0 0 0 1 1 0 0"/>
+                </build>
+            </model>"#;
+
+        let mut archive = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        archive
+            .start_file("3D/3dmodel.model", Default::default())
+            .unwrap();
+        archive.write_all(model.as_bytes()).unwrap();
+        let data = archive.finish().unwrap().into_inner();
+
+        // The component is moved up, the item is moved along the x-axis, and
+        // then everything is converted from centimeters.
+        let mesh = Mesh::parse_3mf(&data).unwrap();
+        assert_eq!(
+            mesh.triangles(),
+            [[[10., 0., 10.], [20., 0., 10.], [10., 10., 10.]]]
+        );
+    }
+}
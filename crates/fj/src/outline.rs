@@ -0,0 +1,450 @@
+//! Conversion of outlines into shapes
+//!
+//! Fonts and SVG files describe shapes as outlines, which are made up of
+//! closed contours of lines and curves. This module converts those into a
+//! [`Shape2d`].
+
+use std::f64::consts::FRAC_PI_2;
+
+use crate::{
+    Angle, Difference2d, Group2d, Shape2d, Sketch, SketchSegment,
+    SketchSegmentRoute,
+};
+
+/// Collects the contours of an outline
+///
+/// Bézier curves are approximated by straight lines. Circular arcs are kept
+/// exact.
+pub(crate) struct Outline {
+    contours: Vec<Contour>,
+    current: Contour,
+    tolerance: f64,
+}
+
+impl Outline {
+    /// Create an empty outline
+    ///
+    /// Approximated curves deviate from the actual ones by no more than
+    /// `tolerance`.
+    pub(crate) fn new(tolerance: f64) -> Self {
+        Self {
+            contours: Vec::new(),
+            current: Contour::default(),
+            tolerance,
+        }
+    }
+
+    /// Access the point that the next segment starts at
+    pub(crate) fn current_point(&self) -> [f64; 2] {
+        self.current
+            .polygon
+            .last()
+            .copied()
+            .unwrap_or(self.current.start)
+    }
+
+    /// Start a new contour at the given point
+    pub(crate) fn move_to(&mut self, point: [f64; 2]) {
+        self.close();
+        self.current.start = point;
+        self.current.polygon.push(point);
+    }
+
+    pub(crate) fn line_to(&mut self, point: [f64; 2]) {
+        if point == self.current_point() {
+            return;
+        }
+
+        self.current.segments.push(SketchSegment {
+            endpoint: point,
+            route: SketchSegmentRoute::Direct,
+        });
+        self.current.polygon.push(point);
+    }
+
+    #[cfg(any(feature = "svg", feature = "text"))]
+    pub(crate) fn quad_to(&mut self, control: [f64; 2], point: [f64; 2]) {
+        let p0 = self.current_point();
+        let [p1, p2] = [control, point];
+
+        let max_deviation = second_difference(p0, p1, p2) / 4.;
+        self.curve_to(max_deviation, |t| {
+            let s = 1. - t;
+            lerp([s * s, 2. * s * t, t * t], [p0, p1, p2])
+        });
+    }
+
+    #[cfg(any(feature = "svg", feature = "text"))]
+    pub(crate) fn cubic_to(
+        &mut self,
+        control_a: [f64; 2],
+        control_b: [f64; 2],
+        point: [f64; 2],
+    ) {
+        let p0 = self.current_point();
+        let [p1, p2, p3] = [control_a, control_b, point];
+
+        let max_deviation = f64::max(
+            second_difference(p0, p1, p2),
+            second_difference(p1, p2, p3),
+        ) * 3.
+            / 4.;
+        self.curve_to(max_deviation, |t| {
+            let s = 1. - t;
+            lerp(
+                [s * s * s, 3. * s * s * t, 3. * s * t * t, t * t * t],
+                [p0, p1, p2, p3],
+            )
+        });
+    }
+
+    /// Add a circular arc around `center`
+    ///
+    /// A positive angle means the arc is counter-clockwise.
+    pub(crate) fn arc_to(
+        &mut self,
+        center: [f64; 2],
+        angle: f64,
+        point: [f64; 2],
+    ) {
+        let start = self.current_point();
+        if point == start {
+            return;
+        }
+
+        let [x, y] = [start[0] - center[0], start[1] - center[1]];
+        let radius = x.hypot(y);
+
+        // The approximation is only used to decide how contours are nested,
+        // but that still requires it to be accurate.
+        let max_angle = if self.tolerance < radius {
+            2. * (1. - self.tolerance / radius).acos()
+        } else {
+            FRAC_PI_2
+        };
+        let max_angle = max_angle.min(FRAC_PI_2);
+        let n = (angle.abs() / max_angle).ceil().max(1.) as u32;
+
+        for i in 1..n {
+            let (sin, cos) = (angle * f64::from(i) / f64::from(n)).sin_cos();
+            self.current.polygon.push([
+                center[0] + x * cos - y * sin,
+                center[1] + x * sin + y * cos,
+            ]);
+        }
+
+        self.current.segments.push(SketchSegment {
+            endpoint: point,
+            route: SketchSegmentRoute::Arc {
+                angle: Angle::from_rad(angle),
+            },
+        });
+        self.current.polygon.push(point);
+    }
+
+    /// Close the current contour
+    ///
+    /// If it doesn't end where it started, a straight line is added. Contours
+    /// that don't enclose an area are ignored.
+    pub(crate) fn close(&mut self) {
+        let start = self.current.start;
+        self.line_to(start);
+
+        let mut contour = std::mem::take(&mut self.current);
+        contour.polygon.pop();
+        contour.normalize(self.tolerance);
+
+        if contour.segments.len() >= 2 && signed_area(&contour.polygon) != 0. {
+            self.contours.push(contour);
+        }
+    }
+
+    /// Convert the outline into a shape
+    ///
+    /// Whether a contour is a boundary or a hole is decided by how many other
+    /// contours it is nested in, as file formats differ in how they orient
+    /// their contours. Holes are subtracted from their boundary using a
+    /// [`Difference2d`], and all boundaries are combined into a [`Group2d`].
+    pub(crate) fn into_shape(mut self, color: [u8; 4]) -> Shape2d {
+        self.close();
+        let contours = self.contours;
+
+        let containers = contours
+            .iter()
+            .enumerate()
+            .map(|(i, contour)| {
+                (0..contours.len())
+                    .filter(|&j| {
+                        j != i
+                            && contains(
+                                &contours[j].polygon,
+                                contour.polygon[0],
+                            )
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let mut shapes = Vec::new();
+        for (i, contour) in contours.iter().enumerate() {
+            let depth = containers[i].len();
+            if depth % 2 == 1 {
+                continue;
+            }
+
+            let mut holes = (0..contours.len())
+                .filter(|&j| {
+                    containers[j].len() == depth + 1
+                        && containers[j].contains(&i)
+                })
+                .map(|j| contours[j].to_sketch(color).into())
+                .collect::<Vec<Shape2d>>();
+
+            let boundary = contour.to_sketch(color).into();
+            let shape = match holes.len() {
+                0 => boundary,
+                1 => Difference2d::from_shapes([boundary, holes.remove(0)])
+                    .into(),
+                _ => Difference2d::from_shapes([
+                    boundary,
+                    Group2d::from_shapes(holes).into(),
+                ])
+                .into(),
+            };
+
+            shapes.push(shape);
+        }
+
+        Group2d::from_shapes(shapes).into()
+    }
+
+    #[cfg(any(feature = "svg", feature = "text"))]
+    fn curve_to(
+        &mut self,
+        max_deviation: f64,
+        point_at: impl Fn(f64) -> [f64; 2],
+    ) {
+        // The deviation of an approximation decreases with the square of the
+        // number of segments.
+        let n = (max_deviation / self.tolerance).sqrt().ceil().max(1.);
+
+        for i in 1..=n as u32 {
+            self.line_to(point_at(f64::from(i) / n));
+        }
+    }
+}
+
+#[derive(Default)]
+struct Contour {
+    start: [f64; 2],
+    segments: Vec<SketchSegment>,
+
+    /// An approximation of the contour, used to decide how contours are nested
+    polygon: Vec<[f64; 2]>,
+}
+
+impl Contour {
+    /// Remove degenerate segments, and start at the lowest-leftmost vertex
+    ///
+    /// Outlines can contain points that are (almost) identical to the previous
+    /// one, or that are in the middle of a straight line. Those don't change
+    /// the shape, but result in invalid or needlessly complex geometry.
+    /// Points closer to the previous one than `tolerance` are removed, as are
+    /// segments that continue a straight line in the same or the opposite
+    /// direction.
+    ///
+    /// Where a contour starts depends on the file it comes from. Starting at
+    /// the lowest-leftmost vertex instead makes the result predictable.
+    fn normalize(&mut self, tolerance: f64) {
+        let segments = &mut self.segments;
+
+        // Duplicates are removed first. Otherwise, a duplicate that is close
+        // to a corner could make it look like the corner is a straight line.
+        remove_segments(segments, |start, segment, _| match segment.route {
+            SketchSegmentRoute::Direct => {
+                distance(start, segment.endpoint) <= tolerance
+            }
+            SketchSegmentRoute::Arc { .. } => segment.endpoint == start,
+        });
+        remove_segments(segments, |start, segment, next| {
+            segment.route == SketchSegmentRoute::Direct
+                && next.route == SketchSegmentRoute::Direct
+                && is_collinear(start, segment.endpoint, next.endpoint)
+        });
+
+        let lowest = (0..segments.len()).min_by(|&a, &b| {
+            let [ax, ay] = segments[a].endpoint;
+            let [bx, by] = segments[b].endpoint;
+            ay.total_cmp(&by).then(ax.total_cmp(&bx))
+        });
+        if let Some(i) = lowest {
+            segments.rotate_left(i + 1);
+        }
+    }
+
+    /// Convert the contour into a counter-clockwise sketch
+    fn to_sketch(&self, color: [u8; 4]) -> Sketch {
+        let mut segments = self.segments.clone();
+
+        if signed_area(&self.polygon) < 0. {
+            // Each segment ends where the next one starts, so reversing the
+            // contour moves every route to the previous endpoint.
+            let n = segments.len();
+            segments = (0..n)
+                .rev()
+                .map(|i| SketchSegment {
+                    endpoint: self.segments[(i + n - 1) % n].endpoint,
+                    route: match self.segments[i].route {
+                        SketchSegmentRoute::Direct => {
+                            SketchSegmentRoute::Direct
+                        }
+                        SketchSegmentRoute::Arc { angle } => {
+                            SketchSegmentRoute::Arc {
+                                angle: Angle::from_rad(-angle.rad()),
+                            }
+                        }
+                    },
+                })
+                .collect();
+        }
+
+        Sketch::from_segments(segments).with_color(color)
+    }
+}
+
+/// The magnitude of `a - 2b + c`, which bounds the curvature of a curve
+#[cfg(any(feature = "svg", feature = "text"))]
+fn second_difference(a: [f64; 2], b: [f64; 2], c: [f64; 2]) -> f64 {
+    let x = a[0] - 2. * b[0] + c[0];
+    let y = a[1] - 2. * b[1] + c[1];
+    x.hypot(y)
+}
+
+#[cfg(any(feature = "svg", feature = "text"))]
+fn lerp<const N: usize>(weights: [f64; N], points: [[f64; 2]; N]) -> [f64; 2] {
+    weights
+        .into_iter()
+        .zip(points)
+        .fold([0., 0.], |[x, y], (w, [px, py])| [x + w * px, y + w * py])
+}
+
+/// Remove segments from a closed contour, until none match the predicate
+///
+/// The predicate is called with the start point of a segment, the segment,
+/// and the segment after it.
+fn remove_segments(
+    segments: &mut Vec<SketchSegment>,
+    is_degenerate: impl Fn([f64; 2], &SketchSegment, &SketchSegment) -> bool,
+) {
+    while segments.len() >= 2 {
+        let n = segments.len();
+
+        let degenerate = (0..n).find(|&i| {
+            let start = segments[(i + n - 1) % n].endpoint;
+            is_degenerate(start, &segments[i], &segments[(i + 1) % n])
+        });
+
+        match degenerate {
+            Some(i) => {
+                segments.remove(i);
+            }
+            None => break,
+        }
+    }
+}
+
+fn distance([ax, ay]: [f64; 2], [bx, by]: [f64; 2]) -> f64 {
+    (bx - ax).hypot(by - ay)
+}
+
+/// Check whether `b` is on the line through `a` and `c`
+///
+/// The direction doesn't matter, so `c` can also be between `a` and `b`.
+fn is_collinear(a: [f64; 2], b: [f64; 2], c: [f64; 2]) -> bool {
+    let [ux, uy] = [b[0] - a[0], b[1] - a[1]];
+    let [vx, vy] = [c[0] - b[0], c[1] - b[1]];
+
+    // The cross product is the sine of the angle between both segments,
+    // scaled by their lengths.
+    let cross = ux * vy - uy * vx;
+    cross.abs() <= 1e-9 * ux.hypot(uy) * vx.hypot(vy)
+}
+
+/// Check whether a polygon contains a point, using the even-odd rule
+fn contains(polygon: &[[f64; 2]], [x, y]: [f64; 2]) -> bool {
+    let mut inside = false;
+
+    for (i, &[ax, ay]) in polygon.iter().enumerate() {
+        let [bx, by] = polygon[(i + 1) % polygon.len()];
+
+        if (ay > y) != (by > y) && x < ax + (y - ay) / (by - ay) * (bx - ax) {
+            inside = !inside;
+        }
+    }
+
+    inside
+}
+
+fn signed_area(polygon: &[[f64; 2]]) -> f64 {
+    let mut area = 0.;
+
+    for (i, &[ax, ay]) in polygon.iter().enumerate() {
+        let [bx, by] = polygon[(i + 1) % polygon.len()];
+        area += ax * by - bx * ay;
+    }
+
+    area / 2.
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Chain, Shape2d};
+
+    use super::Outline;
+
+    #[test]
+    fn normalize_contour() {
+        // A clockwise square, which starts at its top-right corner, with a
+        // point in the middle of a line, and a duplicate point.
+        let mut outline = Outline::new(0.01);
+        outline.move_to([1., 1.]);
+        outline.line_to([1., 0.5]);
+        outline.line_to([1., 0.]);
+        outline.line_to([1., 1e-6]);
+        outline.line_to([0., 0.]);
+        outline.line_to([0., 1.]);
+
+        let Shape2d::Group(group) = outline.into_shape([255, 0, 0, 255]) else {
+            panic!("Expected outline to be converted into a group");
+        };
+        let [Shape2d::Sketch(sketch)] = group.shapes() else {
+            panic!("Expected outline to consist of a single sketch");
+        };
+        let Chain::PolyChain(chain) = sketch.chain() else {
+            panic!("Expected sketch to be a poly chain");
+        };
+
+        // The sketch starts at the lowest-leftmost corner, and is
+        // counter-clockwise.
+        let endpoints = chain
+            .to_segments()
+            .into_iter()
+            .map(|segment| segment.endpoint)
+            .collect::<Vec<_>>();
+        assert_eq!(endpoints, [[1., 0.], [1., 1.], [0., 1.], [0., 0.]]);
+    }
+
+    #[test]
+    fn ignore_degenerate_contour() {
+        // A spike that goes out and comes back on itself.
+        let mut outline = Outline::new(0.01);
+        outline.move_to([0., 0.]);
+        outline.line_to([1., 0.]);
+        outline.line_to([2., 0.]);
+
+        let Shape2d::Group(group) = outline.into_shape([255, 0, 0, 255]) else {
+            panic!("Expected outline to be converted into a group");
+        };
+        assert!(group.shapes().is_empty());
+    }
+}
@@ -0,0 +1,177 @@
+use crate::{Angle, Shape, Shape2d};
+
+/// A pattern of a 3-dimensional shape
+///
+/// The pattern consists of `count` instances of the shape. The first instance
+/// is the original shape, each further one is transformed relative to the
+/// previous one, as defined by the [`PatternKind`].
+///
+/// # Examples
+///
+/// Convenient syntax for this operation is available through [`crate::syntax`].
+///
+/// ``` rust
+/// # let shape = fj::Sketch::from_points(vec![[2., 0.], [3., 0.], [2., 1.]]);
+/// use fj::syntax::*;
+///
+/// // `shape` can be anything that converts to `fj::Shape`
+/// let row = shape.linear_pattern([2., 0., 0.], 4);
+/// let ring = shape.circular_pattern([0., 0., 1.], 6);
+/// ```
+///
+/// # Limitations
+///
+/// Like in a [`crate::Group`], the instances are not allowed to touch or
+/// overlap. This is not currently checked.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Pattern {
+    shape: Shape,
+    kind: PatternKind,
+    count: u32,
+}
+
+impl Pattern {
+    /// Create a `Pattern` from a shape, the kind of pattern, and a count
+    pub fn from_shape(shape: Shape, kind: PatternKind, count: u32) -> Self {
+        Self { shape, kind, count }
+    }
+
+    /// Access the shape that is repeated
+    pub fn shape(&self) -> &Shape {
+        &self.shape
+    }
+
+    /// Access the kind of pattern
+    pub fn kind(&self) -> PatternKind {
+        self.kind
+    }
+
+    /// Access the number of instances in the pattern
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+}
+
+impl From<Pattern> for Shape {
+    fn from(shape: Pattern) -> Self {
+        Self::Pattern(Box::new(shape))
+    }
+}
+
+/// The kind of a [`Pattern`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub enum PatternKind {
+    /// Instances are placed along a straight line
+    Linear {
+        /// The offset between neighboring instances
+        offset: [f64; 3],
+    },
+
+    /// Instances are placed around an axis that goes through the origin
+    Circular {
+        /// The axis that the instances are rotated around
+        axis: [f64; 3],
+
+        /// The angle between neighboring instances
+        angle: Angle,
+    },
+}
+
+/// A pattern of a 2-dimensional shape
+///
+/// The pattern consists of `count` instances of the shape. The first instance
+/// is the original shape, each further one is transformed relative to the
+/// previous one, as defined by the [`PatternKind2d`].
+///
+/// All instances stay in the plane of the original shape. This means a
+/// pattern can be subtracted from another shape, for example to create a
+/// circle of bolt holes.
+///
+/// # Examples
+///
+/// Convenient syntax for this operation is available through [`crate::syntax`].
+///
+/// ``` rust
+/// # let hole = fj::Sketch::from_circle(
+/// #     fj::Circle::from_center_and_radius([5., 0.], 0.5)
+/// # );
+/// use fj::syntax::*;
+///
+/// // `hole` can be anything that converts to `fj::Shape2d`
+/// let grid = hole
+///     .linear_pattern_2d([2., 0.], 4)
+///     .linear_pattern_2d([0., 2.], 3);
+/// let bolt_circle = hole.circular_pattern_2d([0., 0.], 6);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Pattern2d {
+    shape: Shape2d,
+    kind: PatternKind2d,
+    count: u32,
+}
+
+impl Pattern2d {
+    /// Create a `Pattern2d` from a shape, the kind of pattern, and a count
+    pub fn from_shape(shape: Shape2d, kind: PatternKind2d, count: u32) -> Self {
+        Self { shape, kind, count }
+    }
+
+    /// Get the rendering color of the pattern in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.shape.color()
+    }
+
+    /// Access the shape that is repeated
+    pub fn shape(&self) -> &Shape2d {
+        &self.shape
+    }
+
+    /// Access the kind of pattern
+    pub fn kind(&self) -> PatternKind2d {
+        self.kind
+    }
+
+    /// Access the number of instances in the pattern
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+}
+
+impl From<Pattern2d> for Shape {
+    fn from(shape: Pattern2d) -> Self {
+        Self::Shape2d(shape.into())
+    }
+}
+
+impl From<Pattern2d> for Shape2d {
+    fn from(shape: Pattern2d) -> Self {
+        Self::Pattern(Box::new(shape))
+    }
+}
+
+/// The kind of a [`Pattern2d`]
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub enum PatternKind2d {
+    /// Instances are placed along a straight line
+    Linear {
+        /// The offset between neighboring instances
+        offset: [f64; 2],
+    },
+
+    /// Instances are placed around a center point
+    Circular {
+        /// The point that the instances are placed around
+        center: [f64; 2],
+
+        /// The angle between neighboring instances
+        angle: Angle,
+    },
+}
@@ -0,0 +1,387 @@
+use crate::Shape;
+
+/// The tolerance of approximated primitives, relative to their size
+pub(crate) const DEFAULT_RELATIVE_TOLERANCE: f64 = 0.01;
+
+/// A primitive solid
+///
+/// Primitives are complete solids that don't need to be built from a sketch.
+///
+/// # Examples
+///
+/// ``` rust
+/// let cuboid = fj::Cuboid::from_size([3., 2., 1.]);
+/// let sphere = fj::Sphere::from_radius(1.).with_color([0, 0, 255, 255]);
+/// # let _ = (cuboid, sphere);
+/// ```
+///
+/// # Limitations
+///
+/// Cuboids and cylinders are represented exactly. The kernel can't represent
+/// the curved surfaces of cones, spheres, and tori yet. Those are approximated
+/// by flat faces, which deviate from the actual surface no more than a given
+/// tolerance. By default, that tolerance is 1% of the respective radius.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub enum Primitive {
+    /// A cone
+    Cone(Cone),
+
+    /// A cuboid
+    Cuboid(Cuboid),
+
+    /// A cylinder
+    Cylinder(Cylinder),
+
+    /// A sphere
+    Sphere(Sphere),
+
+    /// A torus
+    Torus(Torus),
+}
+
+impl Primitive {
+    /// Get the rendering color of the primitive in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        match self {
+            Self::Cone(cone) => cone.color(),
+            Self::Cuboid(cuboid) => cuboid.color(),
+            Self::Cylinder(cylinder) => cylinder.color(),
+            Self::Sphere(sphere) => sphere.color(),
+            Self::Torus(torus) => torus.color(),
+        }
+    }
+}
+
+impl From<Primitive> for Shape {
+    fn from(shape: Primitive) -> Self {
+        Self::Primitive(shape)
+    }
+}
+
+/// A cuboid, centered on the origin
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Cuboid {
+    size: [f64; 3],
+    color: [u8; 4],
+}
+
+impl Cuboid {
+    /// Create a cuboid with the given size along the x-, y-, and z-axes
+    pub fn from_size(size: [f64; 3]) -> Self {
+        Self {
+            size,
+            color: [255, 0, 0, 255],
+        }
+    }
+
+    /// Set the rendering color of the cuboid in RGBA
+    pub fn with_color(mut self, color: [u8; 4]) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Access the size of the cuboid
+    pub fn size(&self) -> [f64; 3] {
+        self.size
+    }
+
+    /// Get the rendering color of the cuboid in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.color
+    }
+}
+
+impl From<Cuboid> for Primitive {
+    fn from(shape: Cuboid) -> Self {
+        Self::Cuboid(shape)
+    }
+}
+
+impl From<Cuboid> for Shape {
+    fn from(shape: Cuboid) -> Self {
+        Self::Primitive(shape.into())
+    }
+}
+
+/// A cylinder around the z-axis
+///
+/// The bottom of the cylinder is in the xy-plane.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Cylinder {
+    radius: f64,
+    height: f64,
+    color: [u8; 4],
+}
+
+impl Cylinder {
+    /// Create a cylinder with the given radius and height
+    pub fn from_radius_and_height(radius: f64, height: f64) -> Self {
+        Self {
+            radius,
+            height,
+            color: [255, 0, 0, 255],
+        }
+    }
+
+    /// Set the rendering color of the cylinder in RGBA
+    pub fn with_color(mut self, color: [u8; 4]) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Access the radius of the cylinder
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+
+    /// Access the height of the cylinder
+    pub fn height(&self) -> f64 {
+        self.height
+    }
+
+    /// Get the rendering color of the cylinder in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.color
+    }
+}
+
+impl From<Cylinder> for Primitive {
+    fn from(shape: Cylinder) -> Self {
+        Self::Cylinder(shape)
+    }
+}
+
+impl From<Cylinder> for Shape {
+    fn from(shape: Cylinder) -> Self {
+        Self::Primitive(shape.into())
+    }
+}
+
+/// A cone around the z-axis
+///
+/// The base of the cone is in the xy-plane, its apex is on the positive
+/// z-axis.
+///
+/// # Limitations
+///
+/// The side of the cone is not represented exactly, but approximated by flat
+/// triangles that meet at the apex. The tolerance, which can be set using
+/// [`Cone::with_tolerance`], defines how far those may deviate from the actual
+/// cone.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Cone {
+    radius: f64,
+    height: f64,
+    tolerance: f64,
+    color: [u8; 4],
+}
+
+impl Cone {
+    /// Create a cone with the given base radius and height
+    pub fn from_radius_and_height(radius: f64, height: f64) -> Self {
+        Self {
+            radius,
+            height,
+            tolerance: radius * DEFAULT_RELATIVE_TOLERANCE,
+            color: [255, 0, 0, 255],
+        }
+    }
+
+    /// Set the maximum deviation of the approximated cone from the actual one
+    pub fn with_tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Set the rendering color of the cone in RGBA
+    pub fn with_color(mut self, color: [u8; 4]) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Access the radius of the cone's base
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+
+    /// Access the height of the cone
+    pub fn height(&self) -> f64 {
+        self.height
+    }
+
+    /// Access the tolerance of the approximated cone
+    pub fn tolerance(&self) -> f64 {
+        self.tolerance
+    }
+
+    /// Get the rendering color of the cone in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.color
+    }
+}
+
+impl From<Cone> for Primitive {
+    fn from(shape: Cone) -> Self {
+        Self::Cone(shape)
+    }
+}
+
+impl From<Cone> for Shape {
+    fn from(shape: Cone) -> Self {
+        Self::Primitive(shape.into())
+    }
+}
+
+/// A sphere, centered on the origin
+///
+/// # Limitations
+///
+/// The sphere is not represented exactly, but approximated by flat
+/// quadrilaterals between rings of latitude, and triangles around the poles.
+/// The tolerance, which can be set using [`Sphere::with_tolerance`], defines
+/// how far those may deviate from the actual sphere.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Sphere {
+    radius: f64,
+    tolerance: f64,
+    color: [u8; 4],
+}
+
+impl Sphere {
+    /// Create a sphere with the given radius
+    pub fn from_radius(radius: f64) -> Self {
+        Self {
+            radius,
+            tolerance: radius * DEFAULT_RELATIVE_TOLERANCE,
+            color: [255, 0, 0, 255],
+        }
+    }
+
+    /// Set the maximum deviation of the approximated sphere from the actual
+    /// one
+    pub fn with_tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Set the rendering color of the sphere in RGBA
+    pub fn with_color(mut self, color: [u8; 4]) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Access the radius of the sphere
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+
+    /// Access the tolerance of the approximated sphere
+    pub fn tolerance(&self) -> f64 {
+        self.tolerance
+    }
+
+    /// Get the rendering color of the sphere in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.color
+    }
+}
+
+impl From<Sphere> for Primitive {
+    fn from(shape: Sphere) -> Self {
+        Self::Sphere(shape)
+    }
+}
+
+impl From<Sphere> for Shape {
+    fn from(shape: Sphere) -> Self {
+        Self::Primitive(shape.into())
+    }
+}
+
+/// A torus around the z-axis, centered on the origin
+///
+/// # Limitations
+///
+/// The torus is not represented exactly, but approximated by flat
+/// quadrilaterals. The tolerance, which can be set using
+/// [`Torus::with_tolerance`], defines how far those may deviate from the
+/// actual torus.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Torus {
+    major_radius: f64,
+    minor_radius: f64,
+    tolerance: f64,
+    color: [u8; 4],
+}
+
+impl Torus {
+    /// Create a torus from its major and minor radius
+    ///
+    /// The major radius is the distance from the center of the torus to the
+    /// center of its tube. The minor radius is the radius of the tube.
+    pub fn from_radii(major_radius: f64, minor_radius: f64) -> Self {
+        Self {
+            major_radius,
+            minor_radius,
+            tolerance: minor_radius * DEFAULT_RELATIVE_TOLERANCE,
+            color: [255, 0, 0, 255],
+        }
+    }
+
+    /// Set the maximum deviation of the approximated torus from the actual
+    /// one
+    pub fn with_tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Set the rendering color of the torus in RGBA
+    pub fn with_color(mut self, color: [u8; 4]) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Access the major radius of the torus
+    pub fn major_radius(&self) -> f64 {
+        self.major_radius
+    }
+
+    /// Access the minor radius of the torus
+    pub fn minor_radius(&self) -> f64 {
+        self.minor_radius
+    }
+
+    /// Access the tolerance of the approximated torus
+    pub fn tolerance(&self) -> f64 {
+        self.tolerance
+    }
+
+    /// Get the rendering color of the torus in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.color
+    }
+}
+
+impl From<Torus> for Primitive {
+    fn from(shape: Torus) -> Self {
+        Self::Torus(shape)
+    }
+}
+
+impl From<Torus> for Shape {
+    fn from(shape: Torus) -> Self {
+        Self::Primitive(shape.into())
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{abi::ffi_safe, Angle, Shape};
+use crate::{abi::ffi_safe, Angle, Group2d, Hull2d, Pattern2d, Shape};
 
 /// A 2-dimensional shape
 #[derive(Clone, Debug, PartialEq)]
@@ -8,6 +8,18 @@ pub enum Shape2d {
     /// A difference between two shapes
     Difference(Box<Difference2d>),
 
+    /// A group of other shapes
+    Group(Box<Group2d>),
+
+    /// The convex hull of other shapes
+    Hull(Box<Hull2d>),
+
+    /// An offset of another shape
+    Offset(Box<Offset2d>),
+
+    /// A pattern of another shape
+    Pattern(Box<Pattern2d>),
+
     /// A sketch
     Sketch(Sketch),
 }
@@ -18,6 +30,10 @@ impl Shape2d {
         match &self {
             Self::Sketch(s) => s.color(),
             Self::Difference(d) => d.color(),
+            Self::Group(g) => g.color(),
+            Self::Hull(h) => h.color(),
+            Self::Offset(o) => o.color(),
+            Self::Pattern(p) => p.color(),
         }
     }
 }
@@ -72,12 +88,114 @@ impl From<Difference2d> for Shape2d {
     }
 }
 
+/// An offset of a shape
+///
+/// The outline of the shape is moved outward by the offset distance. Negative
+/// distances move the outline inward instead. Holes in the shape are moved in
+/// the opposite direction, so that the wall around them grows with a positive
+/// distance, and shrinks with a negative one.
+///
+/// Arcs and circles stay arcs and circles, with a changed radius. At convex
+/// corners, the gap that opens up between the offset segments is closed
+/// according to the [`OffsetJoin`].
+///
+/// # Examples
+///
+/// Convenient syntax for this operation is available through [`crate::syntax`].
+///
+/// ``` rust
+/// # let shape = fj::Sketch::from_points(vec![[0., 0.], [1., 0.], [0., 1.]]);
+/// use fj::syntax::*;
+///
+/// // `shape` can be anything that converts to `fj::Shape2d`
+/// let clearance = shape.offset(0.2, fj::OffsetJoin::Round);
+/// let wall = shape.offset(-0.1, fj::OffsetJoin::Miter);
+/// ```
+///
+/// # Limitations
+///
+/// The offset distance must be small enough that no segment of the outline
+/// vanishes, and that no arc shrinks to a radius of zero. Otherwise, processing
+/// the shape fails with an error.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Offset2d {
+    shape: Shape2d,
+    distance: f64,
+    join: OffsetJoin,
+}
+
+impl Offset2d {
+    /// Create an `Offset2d` from a shape, a distance, and a join style
+    pub fn from_shape(shape: Shape2d, distance: f64, join: OffsetJoin) -> Self {
+        Self {
+            shape,
+            distance,
+            join,
+        }
+    }
+
+    /// Get the rendering color of the offset shape in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.shape.color()
+    }
+
+    /// Access the shape being offset
+    pub fn shape(&self) -> &Shape2d {
+        &self.shape
+    }
+
+    /// Access the offset distance
+    pub fn distance(&self) -> f64 {
+        self.distance
+    }
+
+    /// Access the join style
+    pub fn join(&self) -> OffsetJoin {
+        self.join
+    }
+}
+
+impl From<Offset2d> for Shape {
+    fn from(shape: Offset2d) -> Self {
+        Self::Shape2d(shape.into())
+    }
+}
+
+impl From<Offset2d> for Shape2d {
+    fn from(shape: Offset2d) -> Self {
+        Self::Offset(Box::new(shape))
+    }
+}
+
+/// The way an [`Offset2d`] joins segments at convex corners
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub enum OffsetJoin {
+    /// Join the segments with an arc around the original corner
+    Round,
+
+    /// Extend the segments until they meet at a sharp corner
+    ///
+    /// If the corner is so sharp, that the segments would meet further than 4
+    /// times the offset distance from the original corner, they are joined by
+    /// a straight line instead (a bevel join).
+    Miter,
+}
+
 /// A sketch
 ///
 /// Sketches are currently limited to a single cycle of straight lines,
 /// represented by a number of points. For example, if the points a, b, and c
 /// are provided, the edges ab, bc, and ca are assumed.
 ///
+/// Sketches created from [`SketchSegment`]s work the same way. The route of
+/// each segment leads from the endpoint of the previous segment to the
+/// segment's own endpoint. The first segment starts at the endpoint of the last
+/// one.
+///
 /// Nothing about these edges is checked right now, but algorithms might assume
 /// that the edges are non-overlapping. If you create a `Sketch` with
 /// overlapping edges, you're on your own.
@@ -171,14 +289,27 @@ pub enum Chain {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
 pub struct Circle {
+    /// The center of the circle
+    center: [f64; 2],
+
     /// The radius of the circle
     radius: f64,
 }
 
 impl Circle {
-    /// Construct a new circle with a specific radius
+    /// Construct a new circle with a specific radius, centered on the origin
     pub fn from_radius(radius: f64) -> Self {
-        Self { radius }
+        Self::from_center_and_radius([0., 0.], radius)
+    }
+
+    /// Construct a new circle with a specific center and radius
+    pub fn from_center_and_radius(center: [f64; 2], radius: f64) -> Self {
+        Self { center, radius }
+    }
+
+    /// Access the circle's center
+    pub fn center(&self) -> [f64; 2] {
+        self.center
     }
 
     /// Access the circle's radius
@@ -223,7 +354,9 @@ impl PolyChain {
 
 /// A segment of a sketch
 ///
-/// Each segment starts at the previous point of the sketch.
+/// Each segment starts at the previous point of the sketch, and its route leads
+/// from there to its endpoint. The first segment of a sketch starts at the last
+/// point.
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
@@ -234,7 +367,7 @@ pub struct SketchSegment {
     pub route: SketchSegmentRoute,
 }
 
-/// Possible paths that a [`SketchSegment`] can take to the next endpoint
+/// Possible paths that a [`SketchSegment`] can take to its endpoint
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
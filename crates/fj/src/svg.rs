@@ -0,0 +1,865 @@
+use std::{
+    error::Error, f64::consts::PI, fmt, fs, io, path::Path, str::FromStr,
+};
+
+use crate::{outline::Outline, Shape, Shape2d};
+
+/// Shapes imported from an SVG file
+///
+/// Paths are imported, as are rectangles, circles, ellipses, polygons, and
+/// polylines. Each becomes a shape with the outline of its fill area. Contours
+/// that are nested within other contours of the same element are holes.
+///
+/// Coordinates are converted into millimeters, using the size and view box of
+/// the document. The bottom-left corner of the document is placed at the
+/// origin, with the y-axis pointing up.
+///
+/// Lines and circular arcs are imported exactly. Bézier curves and elliptical
+/// arcs are approximated by straight lines, which deviate from the actual
+/// curves by no more than a given tolerance. By default, that tolerance is
+/// 0.01 mm.
+///
+/// SVG import is only available with the `svg` feature.
+///
+/// # Examples
+///
+/// ``` rust
+/// use fj::syntax::*;
+///
+/// let svg = fj::Svg::parse(
+///     r#"<svg width="20mm" height="10mm" viewBox="0 0 200 100">
+///         <circle cx="50" cy="50" r="40" />
+///         <rect x="110" y="10" width="80" height="80" rx="10" />
+///     </svg>"#,
+/// )?;
+/// let logo = svg.sweep([0., 0., 1.]);
+/// # let _ = logo;
+/// # Ok::<(), fj::SvgError>(())
+/// ```
+///
+/// # Limitations
+///
+/// Styles are ignored. Every element is imported as if it were filled, using
+/// the even-odd fill rule. Elements that are only referenced, like those in
+/// `<defs>`, are not imported, and neither are `<use>` elements.
+#[derive(Clone, Debug)]
+pub struct Svg {
+    elements: Vec<Vec<Command>>,
+    tolerance: f64,
+    color: [u8; 4],
+}
+
+impl Svg {
+    /// Load an SVG file
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, SvgError> {
+        let data = fs::read_to_string(path)
+            .map_err(|err| SvgError(SvgErrorKind::Io(err)))?;
+        Self::parse(&data)
+    }
+
+    /// Parse the contents of an SVG file
+    pub fn parse(data: &str) -> Result<Self, SvgError> {
+        let document = roxmltree::Document::parse(data)
+            .map_err(|err| SvgError(SvgErrorKind::Xml(err)))?;
+
+        let root = document.root_element();
+        let transform = document_transform(root)?;
+
+        let mut elements = Vec::new();
+        add_elements(root, transform, &mut elements)?;
+
+        Ok(Self {
+            elements,
+            tolerance: DEFAULT_TOLERANCE,
+            color: [255, 0, 0, 255],
+        })
+    }
+
+    /// Set the maximum deviation of approximated curves from the actual ones
+    pub fn with_tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Set the rendering color of the imported shapes in RGBA
+    pub fn with_color(mut self, color: [u8; 4]) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Access the tolerance of approximated curves
+    pub fn tolerance(&self) -> f64 {
+        self.tolerance
+    }
+
+    /// Get the rendering color of the imported shapes in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.color
+    }
+
+    /// Convert the imported elements into shapes
+    ///
+    /// Each element becomes a [`crate::Group2d`] of its outlines. All of them
+    /// are combined into another [`crate::Group2d`].
+    pub fn to_shape(&self) -> Shape2d {
+        let shapes = self
+            .elements
+            .iter()
+            .map(|commands| {
+                let mut outline = Outline::new(self.tolerance);
+                for command in commands {
+                    command.add_to(&mut outline, self.tolerance);
+                }
+                outline.into_shape(self.color)
+            })
+            .collect();
+
+        crate::Group2d::from_shapes(shapes).into()
+    }
+}
+
+impl From<Svg> for Shape {
+    fn from(shape: Svg) -> Self {
+        Self::Shape2d(shape.into())
+    }
+}
+
+impl From<Svg> for Shape2d {
+    fn from(shape: Svg) -> Self {
+        shape.to_shape()
+    }
+}
+
+/// Error importing an SVG file
+#[derive(Debug)]
+pub struct SvgError(SvgErrorKind);
+
+#[derive(Debug)]
+enum SvgErrorKind {
+    Io(io::Error),
+    Xml(roxmltree::Error),
+    Attribute {
+        element: String,
+        attribute: &'static str,
+        err: Box<dyn Error + Send + Sync>,
+    },
+}
+
+impl fmt::Display for SvgError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            SvgErrorKind::Io(err) => {
+                write!(f, "Failed to read SVG file: {err}")
+            }
+            SvgErrorKind::Xml(err) => write!(f, "Failed to parse SVG: {err}"),
+            SvgErrorKind::Attribute {
+                element,
+                attribute,
+                err,
+            } => write!(
+                f,
+                "Invalid attribute `{attribute}` of `<{element}>`: {err}"
+            ),
+        }
+    }
+}
+
+impl Error for SvgError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.0 {
+            SvgErrorKind::Io(err) => Some(err),
+            SvgErrorKind::Xml(err) => Some(err),
+            SvgErrorKind::Attribute { err, .. } => Some(err.as_ref()),
+        }
+    }
+}
+
+/// The default tolerance of approximated curves, in millimeters
+const DEFAULT_TOLERANCE: f64 = 0.01;
+
+/// The size of a CSS pixel, which is the default unit of SVG, in millimeters
+const MM_PER_PX: f64 = 25.4 / 96.;
+
+/// Elements that are not rendered directly, and whose children are skipped
+const NON_RENDERED: &[&str] =
+    &["clipPath", "defs", "marker", "mask", "pattern", "symbol"];
+
+/// A drawing command, in the coordinates of the imported shapes
+#[derive(Clone, Debug)]
+enum Command {
+    MoveTo([f64; 2]),
+    LineTo([f64; 2]),
+    QuadTo([f64; 2], [f64; 2]),
+    CubicTo([f64; 2], [f64; 2], [f64; 2]),
+
+    /// An arc of an ellipse
+    ///
+    /// The points of the ellipse are `center + u * cos(t) + v * sin(t)`. The
+    /// arc goes from `t = 0` to `t = sweep`.
+    EllipseTo {
+        center: [f64; 2],
+        axes: [[f64; 2]; 2],
+        sweep: f64,
+        end: [f64; 2],
+    },
+
+    Close,
+}
+
+impl Command {
+    fn add_to(&self, outline: &mut Outline, tolerance: f64) {
+        match *self {
+            Self::MoveTo(point) => outline.move_to(point),
+            Self::LineTo(point) => outline.line_to(point),
+            Self::QuadTo(control, point) => outline.quad_to(control, point),
+            Self::CubicTo(control_a, control_b, point) => {
+                outline.cubic_to(control_a, control_b, point);
+            }
+            Self::EllipseTo {
+                center,
+                axes: [u, v],
+                sweep,
+                end,
+            } => {
+                let [lu, lv] = [u, v].map(|[x, y]| x.hypot(y));
+                let cross = u[0] * v[1] - u[1] * v[0];
+                let dot = u[0] * v[0] + u[1] * v[1];
+
+                let epsilon = 1e-9 * lu.max(lv);
+                if (lu - lv).abs() <= epsilon && dot.abs() <= epsilon * lu {
+                    // If the ellipse is a circle, the arc can be kept exact.
+                    // Its direction depends on whether it has been mirrored.
+                    outline.arc_to(center, sweep * cross.signum(), end);
+                    return;
+                }
+
+                let radius = lu.max(lv);
+                let max_angle = if tolerance < radius {
+                    2. * (1. - tolerance / radius).acos()
+                } else {
+                    PI / 2.
+                };
+                let n = (sweep.abs() / max_angle).ceil().max(1.) as u32;
+
+                for i in 1..n {
+                    let t = sweep * f64::from(i) / f64::from(n);
+                    let (sin, cos) = t.sin_cos();
+                    outline.line_to([
+                        center[0] + u[0] * cos + v[0] * sin,
+                        center[1] + u[1] * cos + v[1] * sin,
+                    ]);
+                }
+                outline.line_to(end);
+            }
+            Self::Close => outline.close(),
+        }
+    }
+}
+
+/// An affine transform, as defined by SVG
+///
+/// Transforms `[x, y]` into `[a * x + c * y + e, b * x + d * y + f]`.
+#[derive(Clone, Copy, Debug)]
+struct Affine([f64; 6]);
+
+impl Affine {
+    fn then(self, other: Self) -> Self {
+        let [a1, b1, c1, d1, e1, f1] = other.0;
+        let [a2, b2, c2, d2, e2, f2] = self.0;
+
+        Self([
+            a1 * a2 + c1 * b2,
+            b1 * a2 + d1 * b2,
+            a1 * c2 + c1 * d2,
+            b1 * c2 + d1 * d2,
+            a1 * e2 + c1 * f2 + e1,
+            b1 * e2 + d1 * f2 + f1,
+        ])
+    }
+
+    fn point(&self, [x, y]: [f64; 2]) -> [f64; 2] {
+        let [a, b, c, d, e, f] = self.0;
+        [a * x + c * y + e, b * x + d * y + f]
+    }
+
+    fn vector(&self, [x, y]: [f64; 2]) -> [f64; 2] {
+        let [a, b, c, d, _, _] = self.0;
+        [a * x + c * y, b * x + d * y]
+    }
+}
+
+/// Computes the transform from user units into the coordinates of the shapes
+fn document_transform(root: roxmltree::Node) -> Result<Affine, SvgError> {
+    let [width, height] = ["width", "height"]
+        .map(|attribute| parse_attribute::<svgtypes::Length>(root, attribute));
+    let [width, height] = [width?, height?].map(|length| length.map(to_mm));
+    let view_box = parse_attribute::<svgtypes::ViewBox>(root, "viewBox")?;
+
+    let transform = match view_box {
+        Some(svgtypes::ViewBox { x, y, w, h }) => {
+            let sx = width.map(|width| width / w).unwrap_or(MM_PER_PX);
+            let sy = height.map(|height| height / h).unwrap_or(MM_PER_PX);
+
+            Affine([sx, 0., 0., -sy, -x * sx, (y + h) * sy])
+        }
+        None => {
+            let height = height.unwrap_or(0.);
+            Affine([MM_PER_PX, 0., 0., -MM_PER_PX, 0., height])
+        }
+    };
+
+    Ok(transform)
+}
+
+fn to_mm(length: svgtypes::Length) -> f64 {
+    use svgtypes::LengthUnit;
+
+    let factor = match length.unit {
+        LengthUnit::Mm => 1.,
+        LengthUnit::Cm => 10.,
+        LengthUnit::In => 25.4,
+        LengthUnit::Pt => 25.4 / 72.,
+        LengthUnit::Pc => 25.4 / 6.,
+        _ => MM_PER_PX,
+    };
+
+    length.number * factor
+}
+
+fn add_elements(
+    node: roxmltree::Node,
+    parent_transform: Affine,
+    elements: &mut Vec<Vec<Command>>,
+) -> Result<(), SvgError> {
+    let transform =
+        match parse_attribute::<svgtypes::Transform>(node, "transform")? {
+            Some(svgtypes::Transform { a, b, c, d, e, f }) => {
+                Affine([a, b, c, d, e, f]).then(parent_transform)
+            }
+            None => parent_transform,
+        };
+
+    let mut path = PathBuilder::new(transform);
+
+    let number = |attribute| {
+        parse_attribute::<svgtypes::Length>(node, attribute)
+            .map(|length| length.map(|length| length.number).unwrap_or(0.))
+    };
+
+    match node.tag_name().name() {
+        "path" => {
+            let data = node.attribute("d").unwrap_or_default();
+            for segment in svgtypes::PathParser::from(data) {
+                let segment =
+                    segment.map_err(|err| invalid_attribute(node, "d", err))?;
+                path.add_segment(segment);
+            }
+        }
+        "rect" => {
+            let [x, y, width, height] =
+                ["x", "y", "width", "height"].map(number);
+            let [x, y, width, height] = [x?, y?, width?, height?];
+
+            // If only one radius is specified, it's used for both.
+            let [rx, ry] = [number("rx")?, number("ry")?];
+            let [rx, ry] = if rx == 0. {
+                [ry, ry]
+            } else if ry == 0. {
+                [rx, rx]
+            } else {
+                [rx, ry]
+            };
+            let [rx, ry] = [rx.min(width / 2.), ry.min(height / 2.)];
+
+            path.rect([x, y], [width, height], [rx, ry]);
+        }
+        "circle" => {
+            let [cx, cy, r] = ["cx", "cy", "r"].map(number);
+            let [cx, cy, r] = [cx?, cy?, r?];
+            path.ellipse([cx, cy], [r, r]);
+        }
+        "ellipse" => {
+            let [cx, cy, rx, ry] = ["cx", "cy", "rx", "ry"].map(number);
+            let [cx, cy, rx, ry] = [cx?, cy?, rx?, ry?];
+            path.ellipse([cx, cy], [rx, ry]);
+        }
+        "polygon" | "polyline" => {
+            let points = node.attribute("points").unwrap_or_default();
+            for (i, (x, y)) in svgtypes::PointsParser::from(points).enumerate()
+            {
+                if i == 0 {
+                    path.move_to([x, y]);
+                } else {
+                    path.line_to([x, y]);
+                }
+            }
+            path.close();
+        }
+        name if NON_RENDERED.contains(&name) => return Ok(()),
+        _ => {
+            for child in node.children().filter(|node| node.is_element()) {
+                add_elements(child, transform, elements)?;
+            }
+        }
+    }
+
+    if !path.commands.is_empty() {
+        elements.push(path.commands);
+    }
+
+    Ok(())
+}
+
+fn parse_attribute<T>(
+    node: roxmltree::Node,
+    attribute: &'static str,
+) -> Result<Option<T>, SvgError>
+where
+    T: FromStr,
+    T::Err: Error + Send + Sync + 'static,
+{
+    node.attribute(attribute)
+        .map(|value| {
+            value
+                .parse()
+                .map_err(|err| invalid_attribute(node, attribute, err))
+        })
+        .transpose()
+}
+
+fn invalid_attribute(
+    node: roxmltree::Node,
+    attribute: &'static str,
+    err: impl Error + Send + Sync + 'static,
+) -> SvgError {
+    SvgError(SvgErrorKind::Attribute {
+        element: node.tag_name().name().to_string(),
+        attribute,
+        err: Box::new(err),
+    })
+}
+
+/// Converts the elements of an SVG file into drawing commands
+///
+/// Keeps track of the state that is required to interpret SVG path data, and
+/// transforms all coordinates.
+struct PathBuilder {
+    commands: Vec<Command>,
+    transform: Affine,
+
+    /// The current point, before the transform is applied
+    current: [f64; 2],
+
+    /// The start of the current subpath, before the transform is applied
+    start: [f64; 2],
+
+    /// The last control point of the previous segment, if it was a curve
+    ///
+    /// Smooth curves reflect that control point, but only if the previous
+    /// segment was a curve of the same kind.
+    control: Option<(Curve, [f64; 2])>,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Curve {
+    Cubic,
+    Quadratic,
+}
+
+impl PathBuilder {
+    fn new(transform: Affine) -> Self {
+        Self {
+            commands: Vec::new(),
+            transform,
+            current: [0., 0.],
+            start: [0., 0.],
+            control: None,
+        }
+    }
+
+    fn move_to(&mut self, point: [f64; 2]) {
+        self.commands
+            .push(Command::MoveTo(self.transform.point(point)));
+    }
+
+    fn line_to(&mut self, point: [f64; 2]) {
+        self.commands
+            .push(Command::LineTo(self.transform.point(point)));
+    }
+
+    fn close(&mut self) {
+        self.commands.push(Command::Close);
+    }
+
+    /// Add an arc of an ellipse
+    ///
+    /// `axes` are the vectors from the center of the ellipse to its points at
+    /// the start of the arc, and a quarter turn further along the arc.
+    fn ellipse_to(
+        &mut self,
+        center: [f64; 2],
+        axes: [[f64; 2]; 2],
+        sweep: f64,
+        end: [f64; 2],
+    ) {
+        self.commands.push(Command::EllipseTo {
+            center: self.transform.point(center),
+            axes: axes.map(|axis| self.transform.vector(axis)),
+            sweep,
+            end: self.transform.point(end),
+        });
+    }
+
+    fn rect(&mut self, [x, y]: [f64; 2], [w, h]: [f64; 2], [rx, ry]: [f64; 2]) {
+        if w <= 0. || h <= 0. {
+            return;
+        }
+
+        // The corners, starting at the top-left, and going around clockwise,
+        // as seen with the y-axis pointing down. Each is given as the center
+        // of its rounding, and the directions towards the previous and next
+        // side.
+        let corners = [
+            ([x + rx, y + ry], [-rx, 0.], [0., -ry]),
+            ([x + w - rx, y + ry], [0., -ry], [rx, 0.]),
+            ([x + w - rx, y + h - ry], [rx, 0.], [0., ry]),
+            ([x + rx, y + h - ry], [0., ry], [-rx, 0.]),
+        ];
+
+        for (i, (center, a, b)) in corners.into_iter().enumerate() {
+            let start = [center[0] + a[0], center[1] + a[1]];
+            let end = [center[0] + b[0], center[1] + b[1]];
+
+            if i == 0 {
+                self.move_to(start);
+            } else {
+                self.line_to(start);
+            }
+            if rx > 0. && ry > 0. {
+                self.ellipse_to(center, [a, b], PI / 2., end);
+            }
+        }
+
+        self.close();
+    }
+
+    fn ellipse(&mut self, [cx, cy]: [f64; 2], [rx, ry]: [f64; 2]) {
+        if rx <= 0. || ry <= 0. {
+            return;
+        }
+
+        let right = [cx + rx, cy];
+        let left = [cx - rx, cy];
+
+        self.move_to(right);
+        self.ellipse_to([cx, cy], [[rx, 0.], [0., ry]], PI, left);
+        self.ellipse_to([cx, cy], [[-rx, 0.], [0., -ry]], PI, right);
+        self.close();
+    }
+
+    fn add_segment(&mut self, segment: svgtypes::PathSegment) {
+        use svgtypes::PathSegment;
+
+        // Path data may be relative to the current point, and smooth curves
+        // depend on the previous segment. That state is tracked before the
+        // transform is applied.
+        let [x0, y0] = self.current;
+        let absolute = |abs: bool, x: f64, y: f64| {
+            if abs {
+                [x, y]
+            } else {
+                [x0 + x, y0 + y]
+            }
+        };
+        let reflected =
+            |curve: Curve, control: Option<(Curve, [f64; 2])>| match control {
+                Some((previous, [x, y])) if previous == curve => {
+                    [2. * x0 - x, 2. * y0 - y]
+                }
+                _ => [x0, y0],
+            };
+
+        let mut control = None;
+
+        let point = match segment {
+            PathSegment::MoveTo { abs, x, y } => {
+                let point = absolute(abs, x, y);
+                self.move_to(point);
+                self.start = point;
+                point
+            }
+            PathSegment::LineTo { abs, x, y } => {
+                let point = absolute(abs, x, y);
+                self.line_to(point);
+                point
+            }
+            PathSegment::HorizontalLineTo { abs, x } => {
+                let point = [absolute(abs, x, 0.)[0], y0];
+                self.line_to(point);
+                point
+            }
+            PathSegment::VerticalLineTo { abs, y } => {
+                let point = [x0, absolute(abs, 0., y)[1]];
+                self.line_to(point);
+                point
+            }
+            PathSegment::CurveTo {
+                abs,
+                x1,
+                y1,
+                x2,
+                y2,
+                x,
+                y,
+            } => {
+                let control_a = absolute(abs, x1, y1);
+                let control_b = absolute(abs, x2, y2);
+                let point = absolute(abs, x, y);
+
+                self.cubic_to(control_a, control_b, point);
+                control = Some((Curve::Cubic, control_b));
+                point
+            }
+            PathSegment::SmoothCurveTo { abs, x2, y2, x, y } => {
+                let control_a = reflected(Curve::Cubic, self.control);
+                let control_b = absolute(abs, x2, y2);
+                let point = absolute(abs, x, y);
+
+                self.cubic_to(control_a, control_b, point);
+                control = Some((Curve::Cubic, control_b));
+                point
+            }
+            PathSegment::Quadratic { abs, x1, y1, x, y } => {
+                let control_point = absolute(abs, x1, y1);
+                let point = absolute(abs, x, y);
+
+                self.quad_to(control_point, point);
+                control = Some((Curve::Quadratic, control_point));
+                point
+            }
+            PathSegment::SmoothQuadratic { abs, x, y } => {
+                let control_point = reflected(Curve::Quadratic, self.control);
+                let point = absolute(abs, x, y);
+
+                self.quad_to(control_point, point);
+                control = Some((Curve::Quadratic, control_point));
+                point
+            }
+            PathSegment::EllipticalArc {
+                abs,
+                rx,
+                ry,
+                x_axis_rotation,
+                large_arc,
+                sweep,
+                x,
+                y,
+            } => {
+                let point = absolute(abs, x, y);
+                self.arc_to(
+                    [rx, ry],
+                    x_axis_rotation.to_radians(),
+                    large_arc,
+                    sweep,
+                    point,
+                );
+                point
+            }
+            PathSegment::ClosePath { .. } => {
+                // Segments after closing a subpath start at its beginning.
+                self.close();
+                self.move_to(self.start);
+                self.start
+            }
+        };
+
+        self.current = point;
+        self.control = control;
+    }
+
+    fn quad_to(&mut self, control: [f64; 2], point: [f64; 2]) {
+        self.commands.push(Command::QuadTo(
+            self.transform.point(control),
+            self.transform.point(point),
+        ));
+    }
+
+    fn cubic_to(
+        &mut self,
+        control_a: [f64; 2],
+        control_b: [f64; 2],
+        point: [f64; 2],
+    ) {
+        self.commands.push(Command::CubicTo(
+            self.transform.point(control_a),
+            self.transform.point(control_b),
+            self.transform.point(point),
+        ));
+    }
+
+    /// Add an elliptical arc, as specified in SVG path data
+    ///
+    /// Converts the arc from its endpoints into the center of its ellipse, as
+    /// described in the SVG specification, appendix F.6.5.
+    fn arc_to(
+        &mut self,
+        [rx, ry]: [f64; 2],
+        rotation: f64,
+        large_arc: bool,
+        sweep: bool,
+        point: [f64; 2],
+    ) {
+        let [x1, y1] = self.current;
+        let [x2, y2] = point;
+
+        if [x1, y1] == point {
+            return;
+        }
+        let [mut rx, mut ry] = [rx.abs(), ry.abs()];
+        if rx == 0. || ry == 0. {
+            self.line_to(point);
+            return;
+        }
+
+        let (sin, cos) = rotation.sin_cos();
+
+        // The start point, in a coordinate system that is centered between
+        // the endpoints, and aligned with the axes of the ellipse.
+        let [dx, dy] = [(x1 - x2) / 2., (y1 - y2) / 2.];
+        let [px, py] = [cos * dx + sin * dy, -sin * dx + cos * dy];
+
+        // If the radii are too small to connect the endpoints, they are
+        // scaled up.
+        let lambda = (px / rx).powi(2) + (py / ry).powi(2);
+        if lambda > 1. {
+            rx *= lambda.sqrt();
+            ry *= lambda.sqrt();
+        }
+
+        let numerator =
+            (rx * ry).powi(2) - (rx * py).powi(2) - (ry * px).powi(2);
+        let denominator = (rx * py).powi(2) + (ry * px).powi(2);
+        let factor = (numerator / denominator).max(0.).sqrt();
+        let factor = if large_arc == sweep { -factor } else { factor };
+
+        let [cx, cy] = [factor * rx * py / ry, -factor * ry * px / rx];
+        let center = [
+            cos * cx - sin * cy + (x1 + x2) / 2.,
+            sin * cx + cos * cy + (y1 + y2) / 2.,
+        ];
+
+        let angle = |[ux, uy]: [f64; 2]| uy.atan2(ux);
+        let start_angle = angle([(px - cx) / rx, (py - cy) / ry]);
+        let end_angle = angle([(-px - cx) / rx, (-py - cy) / ry]);
+
+        let mut sweep_angle = (end_angle - start_angle).rem_euclid(2. * PI);
+        if !sweep && sweep_angle > 0. {
+            sweep_angle -= 2. * PI;
+        }
+
+        // The axes of the ellipse, rotated such that the arc starts at the
+        // first one.
+        let u = [rx * cos, rx * sin];
+        let v = [-ry * sin, ry * cos];
+        let (sin_start, cos_start) = start_angle.sin_cos();
+        let axes = [
+            [
+                u[0] * cos_start + v[0] * sin_start,
+                u[1] * cos_start + v[1] * sin_start,
+            ],
+            [
+                v[0] * cos_start - u[0] * sin_start,
+                v[1] * cos_start - u[1] * sin_start,
+            ],
+        ];
+
+        self.ellipse_to(center, axes, sweep_angle, point);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Chain, Shape2d, SketchSegment, SketchSegmentRoute};
+
+    use super::Svg;
+
+    fn shapes(svg: &str) -> Vec<Shape2d> {
+        let Shape2d::Group(group) = Svg::parse(svg).unwrap().to_shape() else {
+            panic!("Expected SVG to be converted into a group");
+        };
+        group.shapes().to_vec()
+    }
+
+    fn segments(shape: &Shape2d) -> Vec<SketchSegment> {
+        let Shape2d::Group(group) = shape else {
+            panic!("Expected element to be converted into a group");
+        };
+        let [Shape2d::Sketch(sketch)] = group.shapes() else {
+            panic!("Expected element to be converted into a single sketch");
+        };
+        let Chain::PolyChain(poly_chain) = sketch.chain() else {
+            panic!("Expected sketch to be made up of segments");
+        };
+        poly_chain.to_segments()
+    }
+
+    #[test]
+    fn scale_and_orientation() {
+        let shapes = shapes(
+            r#"<svg width="10mm" height="10mm" viewBox="0 0 100 100">
+                <path d="M 0 0 h 100 v 50 H 0 z" />
+            </svg>"#,
+        );
+
+        let mut endpoints = segments(&shapes[0])
+            .into_iter()
+            .map(|segment| segment.endpoint)
+            .collect::<Vec<_>>();
+        endpoints.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        assert_eq!(endpoints, [[0., 5.], [0., 10.], [10., 5.], [10., 10.]]);
+    }
+
+    #[test]
+    fn circle_is_exact() {
+        let shapes = shapes(r#"<svg><circle cx="10" cy="10" r="5" /></svg>"#);
+
+        let segments = segments(&shapes[0]);
+        assert_eq!(segments.len(), 2);
+        assert!(segments.iter().all(|segment| matches!(
+            segment.route,
+            SketchSegmentRoute::Arc { angle } if angle.rad() > 0.
+        )));
+    }
+
+    #[test]
+    fn arc_in_path() {
+        let shapes =
+            shapes(r#"<svg><path d="M 0 0 A 5 5 0 0 1 10 0 Z" /></svg>"#);
+
+        let arcs = segments(&shapes[0])
+            .into_iter()
+            .filter_map(|segment| match segment.route {
+                SketchSegmentRoute::Arc { angle } => Some(angle.rad()),
+                SketchSegmentRoute::Direct => None,
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(arcs.len(), 1);
+        assert!((arcs[0].abs() - std::f64::consts::PI).abs() < 1e-9);
+    }
+
+    #[test]
+    fn nested_contours_are_holes() {
+        let shapes = shapes(
+            r#"<svg>
+                <path d="M 0 0 L 10 0 L 10 10 L 0 10 Z M 2 2 L 8 2 L 8 8 Z" />
+            </svg>"#,
+        );
+
+        let Shape2d::Group(group) = &shapes[0] else {
+            panic!("Expected element to be converted into a group");
+        };
+        assert!(matches!(group.shapes(), [Shape2d::Difference(_)]));
+    }
+}
@@ -28,6 +28,53 @@ where
     }
 }
 
+/// Convenient syntax to create an [`fj::Fillet`]
+///
+/// [`fj::Fillet`]: crate::Fillet
+pub trait Fillet {
+    /// Round off the selected edges of `self` with the given radius
+    fn fillet(&self, edges: crate::EdgeSelection, radius: f64)
+        -> crate::Fillet;
+
+    /// Cut off the selected edges of `self` at the given distance
+    fn chamfer(
+        &self,
+        edges: crate::EdgeSelection,
+        distance: f64,
+    ) -> crate::Fillet;
+}
+
+impl<T> Fillet for T
+where
+    T: Clone + Into<crate::Sweep>,
+{
+    fn fillet(
+        &self,
+        edges: crate::EdgeSelection,
+        radius: f64,
+    ) -> crate::Fillet {
+        let sweep = self.clone().into();
+        crate::Fillet::from_sweep(
+            sweep,
+            edges,
+            crate::FilletKind::Round { radius },
+        )
+    }
+
+    fn chamfer(
+        &self,
+        edges: crate::EdgeSelection,
+        distance: f64,
+    ) -> crate::Fillet {
+        let sweep = self.clone().into();
+        crate::Fillet::from_sweep(
+            sweep,
+            edges,
+            crate::FilletKind::Chamfer { distance },
+        )
+    }
+}
+
 /// Convenient syntax to create an [`fj::Group`]
 ///
 /// [`fj::Group`]: crate::Group
@@ -53,6 +100,263 @@ where
     }
 }
 
+/// Convenient syntax to create an [`fj::Group2d`]
+///
+/// [`fj::Group2d`]: crate::Group2d
+pub trait Group2d {
+    /// Create a group with `self` and `other`
+    fn group_2d<Other>(&self, other: &Other) -> crate::Group2d
+    where
+        Other: Clone + Into<crate::Shape2d>;
+}
+
+impl<T> Group2d for T
+where
+    T: Clone + Into<crate::Shape2d>,
+{
+    fn group_2d<Other>(&self, other: &Other) -> crate::Group2d
+    where
+        Other: Clone + Into<crate::Shape2d>,
+    {
+        let a = self.clone().into();
+        let b = other.clone().into();
+
+        crate::Group2d::from_shapes(vec![a, b])
+    }
+}
+
+/// Convenient syntax to create an [`fj::HelicalSweep`]
+///
+/// [`fj::HelicalSweep`]: crate::HelicalSweep
+pub trait HelicalSweep {
+    /// Sweep `self` along a helix around the z-axis
+    ///
+    /// See [`fj::HelicalSweep`] for how `self` is placed relative to the
+    /// helix.
+    ///
+    /// [`fj::HelicalSweep`]: crate::HelicalSweep
+    fn helical_sweep(
+        &self,
+        radius: f64,
+        pitch: f64,
+        turns: f64,
+    ) -> crate::HelicalSweep;
+}
+
+impl<T> HelicalSweep for T
+where
+    T: Clone + Into<crate::Shape2d>,
+{
+    fn helical_sweep(
+        &self,
+        radius: f64,
+        pitch: f64,
+        turns: f64,
+    ) -> crate::HelicalSweep {
+        let shape = self.clone().into();
+        crate::HelicalSweep::from_shape(shape, radius, pitch, turns)
+    }
+}
+
+/// Convenient syntax to create an [`fj::Hollow`]
+///
+/// [`fj::Hollow`]: crate::Hollow
+pub trait Hollow {
+    /// Hollow out `self`, leaving walls of the given thickness
+    ///
+    /// The provided caps of the sweep are removed.
+    fn hollow(
+        &self,
+        thickness: f64,
+        open: impl IntoIterator<Item = crate::SweepCap>,
+    ) -> crate::Hollow;
+}
+
+impl<T> Hollow for T
+where
+    T: Clone + Into<crate::Sweep>,
+{
+    fn hollow(
+        &self,
+        thickness: f64,
+        open: impl IntoIterator<Item = crate::SweepCap>,
+    ) -> crate::Hollow {
+        let sweep = self.clone().into();
+        crate::Hollow::from_sweep(sweep, thickness, open)
+    }
+}
+
+/// Convenient syntax to create an [`fj::Offset2d`]
+///
+/// [`fj::Offset2d`]: crate::Offset2d
+pub trait Offset {
+    /// Offset the outline of `self` by `distance`
+    ///
+    /// Positive distances move the outline outward, negative distances move
+    /// it inward.
+    fn offset(&self, distance: f64, join: crate::OffsetJoin)
+        -> crate::Offset2d;
+}
+
+impl<T> Offset for T
+where
+    T: Clone + Into<crate::Shape2d>,
+{
+    fn offset(
+        &self,
+        distance: f64,
+        join: crate::OffsetJoin,
+    ) -> crate::Offset2d {
+        let shape = self.clone().into();
+        crate::Offset2d::from_shape(shape, distance, join)
+    }
+}
+
+/// Convenient syntax to create an [`fj::Hull`]
+///
+/// [`fj::Hull`]: crate::Hull
+pub trait Hull {
+    /// Create the convex hull of `self` and `other`
+    fn hull<Other>(&self, other: &Other) -> crate::Hull
+    where
+        Other: Clone + Into<crate::Shape>;
+}
+
+impl<T> Hull for T
+where
+    T: Clone + Into<crate::Shape>,
+{
+    fn hull<Other>(&self, other: &Other) -> crate::Hull
+    where
+        Other: Clone + Into<crate::Shape>,
+    {
+        let a = self.clone().into();
+        let b = other.clone().into();
+
+        crate::Hull::from_shapes(vec![a, b])
+    }
+}
+
+/// Convenient syntax to create an [`fj::Hull2d`]
+///
+/// [`fj::Hull2d`]: crate::Hull2d
+pub trait Hull2d {
+    /// Create the convex hull of `self` and `other`
+    fn hull_2d<Other>(&self, other: &Other) -> crate::Hull2d
+    where
+        Other: Clone + Into<crate::Shape2d>;
+}
+
+impl<T> Hull2d for T
+where
+    T: Clone + Into<crate::Shape2d>,
+{
+    fn hull_2d<Other>(&self, other: &Other) -> crate::Hull2d
+    where
+        Other: Clone + Into<crate::Shape2d>,
+    {
+        let a = self.clone().into();
+        let b = other.clone().into();
+
+        crate::Hull2d::from_shapes(vec![a, b])
+    }
+}
+
+/// Convenient syntax to create an [`fj::Pattern`]
+///
+/// [`fj::Pattern`]: crate::Pattern
+pub trait Pattern {
+    /// Create a pattern of `count` instances of `self` along a straight line
+    ///
+    /// `offset` is the offset between neighboring instances.
+    fn linear_pattern(&self, offset: [f64; 3], count: u32) -> crate::Pattern;
+
+    /// Create a pattern of `count` instances of `self` around an axis
+    ///
+    /// The axis goes through the origin. The instances are distributed evenly
+    /// over a full revolution.
+    fn circular_pattern(&self, axis: [f64; 3], count: u32) -> crate::Pattern;
+}
+
+impl<T> Pattern for T
+where
+    T: Clone + Into<crate::Shape>,
+{
+    fn linear_pattern(&self, offset: [f64; 3], count: u32) -> crate::Pattern {
+        let shape = self.clone().into();
+        crate::Pattern::from_shape(
+            shape,
+            crate::PatternKind::Linear { offset },
+            count,
+        )
+    }
+
+    fn circular_pattern(&self, axis: [f64; 3], count: u32) -> crate::Pattern {
+        let shape = self.clone().into();
+        let angle = crate::Angle::from_rev(1. / f64::from(count));
+        crate::Pattern::from_shape(
+            shape,
+            crate::PatternKind::Circular { axis, angle },
+            count,
+        )
+    }
+}
+
+/// Convenient syntax to create an [`fj::Pattern2d`]
+///
+/// [`fj::Pattern2d`]: crate::Pattern2d
+pub trait Pattern2d {
+    /// Create a pattern of `count` instances of `self` along a straight line
+    ///
+    /// `offset` is the offset between neighboring instances.
+    fn linear_pattern_2d(
+        &self,
+        offset: [f64; 2],
+        count: u32,
+    ) -> crate::Pattern2d;
+
+    /// Create a pattern of `count` instances of `self` around `center`
+    ///
+    /// The instances are distributed evenly over a full revolution.
+    fn circular_pattern_2d(
+        &self,
+        center: [f64; 2],
+        count: u32,
+    ) -> crate::Pattern2d;
+}
+
+impl<T> Pattern2d for T
+where
+    T: Clone + Into<crate::Shape2d>,
+{
+    fn linear_pattern_2d(
+        &self,
+        offset: [f64; 2],
+        count: u32,
+    ) -> crate::Pattern2d {
+        let shape = self.clone().into();
+        crate::Pattern2d::from_shape(
+            shape,
+            crate::PatternKind2d::Linear { offset },
+            count,
+        )
+    }
+
+    fn circular_pattern_2d(
+        &self,
+        center: [f64; 2],
+        count: u32,
+    ) -> crate::Pattern2d {
+        let shape = self.clone().into();
+        let angle = crate::Angle::from_rev(1. / f64::from(count));
+        crate::Pattern2d::from_shape(
+            shape,
+            crate::PatternKind2d::Circular { center, angle },
+            count,
+        )
+    }
+}
+
 /// Convenient syntax to create an [`fj::Sketch`]
 ///
 /// [`fj::Sketch`]: crate::Sketch
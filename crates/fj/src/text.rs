@@ -0,0 +1,285 @@
+use std::{borrow::Cow, error::Error, fmt};
+
+use crate::{outline::Outline, Shape, Shape2d};
+
+/// Text, converted into the outlines of its glyphs
+///
+/// The text starts at the origin, with the baseline of its first line on the
+/// x-axis. Each further line (as separated by `\n`) is placed below the
+/// previous one.
+///
+/// Glyph outlines are made up of Bézier curves, which are approximated by
+/// straight lines. By default, the approximation deviates from the actual
+/// curves by no more than 0.1% of the font size.
+///
+/// Text is only available with the `text` feature, as the default font that
+/// comes with it significantly increases the size of a model.
+///
+/// # Examples
+///
+/// ``` rust
+/// use fj::syntax::*;
+///
+/// let label = fj::Text::new("M6x20", 5.);
+/// let engraving = label.sweep([0., 0., -0.5]);
+/// # let _ = engraving;
+/// ```
+#[derive(Clone, Debug)]
+pub struct Text {
+    text: String,
+    size: f64,
+    font: Font,
+    tolerance: f64,
+    color: [u8; 4],
+}
+
+impl Text {
+    /// Create text with the given font size, using the default font
+    ///
+    /// The font size is the size of the font's em square. Depending on the
+    /// font, actual glyphs are somewhat smaller than that.
+    pub fn new(text: impl Into<String>, size: f64) -> Self {
+        Self {
+            text: text.into(),
+            size,
+            font: Font::default(),
+            tolerance: size * DEFAULT_RELATIVE_TOLERANCE,
+            color: [255, 0, 0, 255],
+        }
+    }
+
+    /// Set the font that the text is rendered in
+    pub fn with_font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Set the maximum deviation of approximated curves from the actual ones
+    pub fn with_tolerance(mut self, tolerance: f64) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Set the rendering color of the text in RGBA
+    pub fn with_color(mut self, color: [u8; 4]) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Access the text
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Access the font size
+    pub fn size(&self) -> f64 {
+        self.size
+    }
+
+    /// Access the font
+    pub fn font(&self) -> &Font {
+        &self.font
+    }
+
+    /// Access the tolerance of approximated curves
+    pub fn tolerance(&self) -> f64 {
+        self.tolerance
+    }
+
+    /// Get the rendering color of the text in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.color
+    }
+
+    /// Convert the text into the outlines of its glyphs
+    ///
+    /// Each glyph outline becomes a [`crate::Sketch`]. Holes in glyphs, like
+    /// in "o" or "a", are subtracted from their outline using a
+    /// [`crate::Difference2d`]. All of them are combined into a
+    /// [`crate::Group2d`].
+    pub fn to_shape(&self) -> Shape2d {
+        let face = self.font.face();
+
+        let scale = self.size / f64::from(face.units_per_em());
+        let line_height = f64::from(face.ascender())
+            - f64::from(face.descender())
+            + f64::from(face.line_gap());
+
+        let mut outline = Outline::new(self.tolerance);
+        let mut offset = [0., 0.];
+
+        for c in self.text.chars() {
+            if c == '\n' {
+                offset = [0., offset[1] - line_height];
+                continue;
+            }
+
+            // Characters that the font doesn't cover are replaced by the
+            // glyph for missing characters.
+            let glyph = face.glyph_index(c).unwrap_or(ttf_parser::GlyphId(0));
+
+            face.outline_glyph(
+                glyph,
+                &mut GlyphOutline {
+                    outline: &mut outline,
+                    offset,
+                    scale,
+                },
+            );
+            outline.close();
+
+            let advance = face.glyph_hor_advance(glyph).unwrap_or_default();
+            offset[0] += f64::from(advance);
+        }
+
+        outline.into_shape(self.color)
+    }
+}
+
+impl From<Text> for Shape {
+    fn from(shape: Text) -> Self {
+        Self::Shape2d(shape.into())
+    }
+}
+
+impl From<Text> for Shape2d {
+    fn from(shape: Text) -> Self {
+        shape.to_shape()
+    }
+}
+
+/// A TrueType or OpenType font
+#[derive(Clone, Debug)]
+pub struct Font {
+    data: Cow<'static, [u8]>,
+}
+
+impl Font {
+    /// Load a font from the contents of a font file
+    ///
+    /// If the file is a font collection, the first font is used.
+    pub fn from_bytes(data: Vec<u8>) -> Result<Self, FontError> {
+        ttf_parser::Face::parse(&data, 0).map_err(FontError)?;
+        Ok(Self { data: data.into() })
+    }
+
+    fn face(&self) -> ttf_parser::Face<'_> {
+        ttf_parser::Face::parse(&self.data, 0)
+            .expect("Font has been validated on construction")
+    }
+}
+
+impl Default for Font {
+    /// The default font, B612 Regular
+    ///
+    /// B612 is distributed under the SIL Open Font License. See
+    /// `fonts/OFL.txt`.
+    fn default() -> Self {
+        Self {
+            data: Cow::Borrowed(include_bytes!("../fonts/B612-Regular.ttf")),
+        }
+    }
+}
+
+/// Error parsing a font file
+#[derive(Debug)]
+pub struct FontError(ttf_parser::FaceParsingError);
+
+impl fmt::Display for FontError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Failed to parse font: {}", self.0)
+    }
+}
+
+impl Error for FontError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+/// The tolerance of approximated glyphs, relative to the font size
+const DEFAULT_RELATIVE_TOLERANCE: f64 = 0.001;
+
+/// Adds the outline of a glyph to an [`Outline`]
+///
+/// Moves the glyph to its position within the text, and scales it from font
+/// units to the font size.
+struct GlyphOutline<'r> {
+    outline: &'r mut Outline,
+    offset: [f64; 2],
+    scale: f64,
+}
+
+impl GlyphOutline<'_> {
+    fn point(&self, x: f32, y: f32) -> [f64; 2] {
+        let [dx, dy] = self.offset;
+        [
+            (f64::from(x) + dx) * self.scale,
+            (f64::from(y) + dy) * self.scale,
+        ]
+    }
+}
+
+impl ttf_parser::OutlineBuilder for GlyphOutline<'_> {
+    fn move_to(&mut self, x: f32, y: f32) {
+        let point = self.point(x, y);
+        self.outline.move_to(point);
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        let point = self.point(x, y);
+        self.outline.line_to(point);
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let [control, point] = [self.point(x1, y1), self.point(x, y)];
+        self.outline.quad_to(control, point);
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let [control_a, control_b, point] =
+            [self.point(x1, y1), self.point(x2, y2), self.point(x, y)];
+        self.outline.cubic_to(control_a, control_b, point);
+    }
+
+    fn close(&mut self) {
+        self.outline.close();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Shape2d;
+
+    use super::Text;
+
+    fn glyphs(text: &str) -> Vec<Shape2d> {
+        let Shape2d::Group(group) = Text::new(text, 10.).to_shape() else {
+            panic!("Expected text to be converted into a group");
+        };
+        group.shapes().to_vec()
+    }
+
+    #[test]
+    fn glyph_with_hole() {
+        let glyphs = glyphs("o");
+
+        assert_eq!(glyphs.len(), 1);
+        assert!(matches!(glyphs[0], Shape2d::Difference(_)));
+    }
+
+    #[test]
+    fn glyph_with_multiple_outlines() {
+        let glyphs = glyphs("i");
+
+        assert_eq!(glyphs.len(), 2);
+        assert!(glyphs
+            .iter()
+            .all(|glyph| matches!(glyph, Shape2d::Sketch(_))));
+    }
+
+    #[test]
+    fn whitespace() {
+        assert!(glyphs(" \n ").is_empty());
+    }
+}
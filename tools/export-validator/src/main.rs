@@ -22,6 +22,7 @@ fn main() -> anyhow::Result<()> {
             .arg("--")
             .arg(&model)
             .args(["--export", export_file_path_str])
+            .arg("--check-mesh")
             .status()?;
 
         if !exit_status.success() {